@@ -0,0 +1,24 @@
+#![feature(test)]
+
+extern crate test;
+
+use test::Bencher;
+
+use stringcase::{all_cases, convert, Case, Options};
+
+#[bench]
+fn bench_all_cases(b: &mut Bencher) {
+    let opts = Options::default();
+    b.iter(|| all_cases("foo_bar100%BAZQux", &opts));
+}
+
+#[bench]
+fn bench_convert_to_each_case(b: &mut Bencher) {
+    let opts = Options::default();
+    b.iter(|| {
+        Case::ALL
+            .iter()
+            .map(|case| convert("foo_bar100%BAZQux", *case, &opts))
+            .collect::<Vec<String>>()
+    });
+}
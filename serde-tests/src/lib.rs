@@ -0,0 +1,8 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+//! The tests of the `serde` feature of stringcase.
+//!
+//! These tests are in this separate crate, because serde_json and serde_derive, which they depend
+//! on, require a newer Rust than the minimum supported Rust version of stringcase.
@@ -0,0 +1,66 @@
+// The examples in the documentation of the module `stringcase::serde`.
+
+use serde::{Deserialize, Serialize};
+use stringcase::serde::{AnyCase, CaseKeys};
+use stringcase::Case;
+
+#[test]
+fn example_of_case_keys() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Config {
+        max_retry_count: u32,
+        base_url: String,
+    }
+
+    let config = Config {
+        max_retry_count: 3,
+        base_url: "http://x".to_string(),
+    };
+
+    let mut buf = Vec::new();
+    let mut ser = serde_json::Serializer::new(&mut buf);
+    config
+        .serialize(CaseKeys::new(&mut ser, Case::Camel))
+        .unwrap();
+    let json = String::from_utf8(buf).unwrap();
+    assert_eq!(json, r#"{"maxRetryCount":3,"baseUrl":"http://x"}"#);
+
+    let mut de = serde_json::Deserializer::from_str(&json);
+    let back = Config::deserialize(CaseKeys::new(&mut de, Case::Camel)).unwrap();
+    assert_eq!(back, config);
+}
+
+#[derive(Deserialize, Debug, PartialEq)]
+enum Status {
+    InProgress,
+    Done,
+}
+
+#[test]
+fn example_of_any_case() {
+    for s in [r#""in_progress""#, r#""IN-PROGRESS""#, r#""in progress""#] {
+        let status: AnyCase<Status> = serde_json::from_str(s).unwrap();
+        assert_eq!(status.0, Status::InProgress);
+    }
+}
+
+#[test]
+fn example_of_any_case_variant() {
+    #[derive(Deserialize)]
+    struct Task {
+        #[serde(deserialize_with = "stringcase::serde::any_case_variant")]
+        status: Status,
+    }
+
+    let task: Task = serde_json::from_str(r#"{"status":"InProgress"}"#).unwrap();
+    assert_eq!(task.status, Status::InProgress);
+    let task: Task = serde_json::from_str(r#"{"status":"DONE"}"#).unwrap();
+    assert_eq!(task.status, Status::Done);
+
+    let err = serde_json::from_str::<Task>(r#"{"status":"todo"}"#)
+        .err()
+        .unwrap();
+    assert!(err.to_string().starts_with(
+        "unknown variant `todo`, expected one of `InProgress`, `Done` in any case style"
+    ));
+}
@@ -0,0 +1,241 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use stringcase::serde::{any_case_variant, AnyCase, CaseKeys};
+use stringcase::{Case, Options};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Server {
+    host_name: String,
+    port_number: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tls_config: Option<Tls>,
+    extra_headers: BTreeMap<String, String>,
+    backends: Vec<Backend>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Tls {
+    cert_file: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Backend {
+    Local { root_dir: String },
+    Remote { base_url: String, retry_count: u8 },
+}
+
+fn server() -> Server {
+    let mut extra_headers = BTreeMap::new();
+    extra_headers.insert("x_request_id".to_string(), "abc".to_string());
+    Server {
+        host_name: "example.com".to_string(),
+        port_number: 8080,
+        tls_config: Some(Tls {
+            cert_file: "cert.pem".to_string(),
+        }),
+        extra_headers,
+        backends: vec![
+            Backend::Local {
+                root_dir: "/var/www".to_string(),
+            },
+            Backend::Remote {
+                base_url: "http://b".to_string(),
+                retry_count: 3,
+            },
+        ],
+    }
+}
+
+fn to_json<T: Serialize>(value: &T, case: Case, opts: &Options) -> String {
+    let mut buf = Vec::new();
+    let mut ser = serde_json::Serializer::new(&mut buf);
+    value
+        .serialize(CaseKeys::with_options(&mut ser, case, opts))
+        .unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+fn from_json<'de, T: Deserialize<'de>>(
+    json: &'de str,
+    case: Case,
+    opts: &Options,
+) -> Result<T, serde_json::Error> {
+    let mut de = serde_json::Deserializer::from_str(json);
+    T::deserialize(CaseKeys::with_options(&mut de, case, opts))
+}
+
+#[test]
+fn it_should_serialize_keys_in_camel_case() {
+    let opts = Options::default();
+    let json = to_json(&server(), Case::Camel, &opts);
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"hostName":"example.com","portNumber":8080,"tlsConfig":{"certFile":"cert.pem"},"#,
+            r#""extraHeaders":{"xRequestId":"abc"},"#,
+            r#""backends":[{"Local":{"rootDir":"/var/www"}},"#,
+            r#"{"Remote":{"baseUrl":"http://b","retryCount":3}}]}"#,
+        )
+    );
+}
+
+#[test]
+fn it_should_serialize_keys_in_case_chosen_at_runtime() {
+    let opts = Options::default();
+    let tls = Tls {
+        cert_file: "a".to_string(),
+    };
+    let case: Case = "cobol".parse().unwrap();
+    assert_eq!(to_json(&tls, case, &opts), r#"{"CERT-FILE":"a"}"#);
+    assert_eq!(to_json(&tls, Case::Title, &opts), r#"{"Cert File":"a"}"#);
+    assert_eq!(to_json(&tls, Case::Snake, &opts), r#"{"cert_file":"a"}"#);
+}
+
+#[test]
+fn it_should_serialize_keys_with_options() {
+    #[derive(Serialize)]
+    struct Version {
+        api_v2: bool,
+    }
+
+    let opts = Options {
+        separate_before_non_alphabets: true,
+        ..Default::default()
+    };
+    let json = to_json(&Version { api_v2: true }, Case::Kebab, &opts);
+    assert_eq!(json, r#"{"api-v-2":true}"#);
+}
+
+#[test]
+fn it_should_leave_values_and_non_string_keys_as_they_are() {
+    let mut map = BTreeMap::new();
+    map.insert(1, "foo_bar".to_string());
+    let opts = Options::default();
+    assert_eq!(to_json(&map, Case::Macro, &opts), r#"{"1":"foo_bar"}"#);
+    assert_eq!(
+        to_json(&vec!["foo_bar"], Case::Macro, &opts),
+        r#"["foo_bar"]"#
+    );
+}
+
+#[test]
+fn it_should_deserialize_keys_in_camel_case() {
+    let opts = Options::default();
+    let json = to_json(&server(), Case::Camel, &opts);
+    let mut expected = server();
+    expected.extra_headers = BTreeMap::new();
+    expected
+        .extra_headers
+        .insert("xRequestId".to_string(), "abc".to_string());
+    assert_eq!(
+        from_json::<Server>(&json, Case::Camel, &opts).unwrap(),
+        expected
+    );
+}
+
+#[test]
+fn it_should_round_trip_in_every_case() {
+    let opts = Options::default();
+    let tls = Tls {
+        cert_file: "cert.pem".to_string(),
+    };
+    for &case in Case::ALL {
+        let json = to_json(&tls, case, &opts);
+        assert_eq!(from_json::<Tls>(&json, case, &opts).unwrap(), tls);
+    }
+}
+
+#[test]
+fn it_should_accept_field_names_as_they_are_on_deserialization() {
+    let opts = Options::default();
+    let tls: Tls = from_json(r#"{"cert_file":"a"}"#, Case::Pascal, &opts).unwrap();
+    assert_eq!(tls.cert_file, "a");
+}
+
+#[test]
+fn it_should_fail_to_deserialize_unknown_keys() {
+    #[derive(Deserialize, Debug)]
+    #[serde(deny_unknown_fields)]
+    struct Strict {
+        #[allow(dead_code)]
+        cert_file: String,
+    }
+
+    let opts = Options::default();
+    assert!(from_json::<Strict>(r#"{"certFile":"a"}"#, Case::Camel, &opts).is_ok());
+    let err = from_json::<Strict>(r#"{"CertFile":"a"}"#, Case::Camel, &opts).unwrap_err();
+    assert!(err.to_string().starts_with("unknown field `CertFile`"));
+}
+
+#[test]
+fn it_should_return_inner_value() {
+    let keys = CaseKeys::new(vec![1u8], Case::Snake);
+    assert_eq!(keys.case(), Case::Snake);
+    assert_eq!(keys.into_inner(), vec![1u8]);
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Status {
+    InProgress,
+    Done,
+    RetryAfter(u32),
+    FailedWith { error_code: u16 },
+}
+
+#[test]
+fn it_should_deserialize_variants_in_any_case() {
+    for json in [
+        r#""InProgress""#,
+        r#""in_progress""#,
+        r#""IN-PROGRESS""#,
+        r#""inProgress""#,
+        r#""in progress""#,
+        r#""In_Progress""#,
+    ] {
+        let status: AnyCase<Status> = serde_json::from_str(json).unwrap();
+        assert_eq!(status.0, Status::InProgress);
+    }
+
+    let status: AnyCase<Status> = serde_json::from_str(r#"{"retry-after":3}"#).unwrap();
+    assert_eq!(status.0, Status::RetryAfter(3));
+
+    let status: AnyCase<Status> =
+        serde_json::from_str(r#"{"FAILED_WITH":{"error_code":5}}"#).unwrap();
+    assert_eq!(status.0, Status::FailedWith { error_code: 5 });
+}
+
+#[test]
+fn it_should_deserialize_variants_of_fields_in_any_case() {
+    #[derive(Deserialize)]
+    struct Task {
+        #[serde(deserialize_with = "any_case_variant")]
+        status: Status,
+        next: Option<AnyCase<Status>>,
+        history: Vec<AnyCase<Status>>,
+    }
+
+    let task: Task =
+        serde_json::from_str(r#"{"status":"done","next":"in-progress","history":["DONE"]}"#)
+            .unwrap();
+    assert_eq!(task.status, Status::Done);
+    assert_eq!(task.next, Some(AnyCase(Status::InProgress)));
+    assert_eq!(task.history, [AnyCase(Status::Done)]);
+}
+
+#[test]
+fn it_should_fail_to_deserialize_unknown_variants() {
+    let err = serde_json::from_str::<AnyCase<Status>>(r#""in_progres""#).unwrap_err();
+    assert!(err.to_string().starts_with(
+        "unknown variant `in_progres`, expected one of `InProgress`, `Done`, `RetryAfter`, \
+         `FailedWith` in any case style, like `in_progress` or `IN-PROGRESS`"
+    ));
+
+    let err = serde_json::from_str::<AnyCase<Status>>(r#""inprogress""#).unwrap_err();
+    assert!(err.to_string().starts_with("unknown variant `inprogress`"));
+}
+
+#[test]
+fn it_should_serialize_variants_as_they_are() {
+    let json = serde_json::to_string(&AnyCase(Status::InProgress)).unwrap();
+    assert_eq!(json, r#""InProgress""#);
+}
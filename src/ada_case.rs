@@ -2,8 +2,14 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
-use crate::capitalize::capitalize;
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, ADA};
+#[cfg(feature = "alloc")]
+use crate::render::{render_cow, render_string};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
 /// Converts the input string to Ada case with the specified options.
 ///
@@ -13,13 +19,15 @@ use crate::options::Options;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let ada = stringcase::ada_case_with_options("fooBar123Baz", &opts);
 ///     assert_eq!(ada, "Foo_Bar_123_Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn ada_case_with_options(input: &str, opts: &Options) -> String {
-    capitalize::<'_'>(input, opts)
+    render_string(input, opts, ADA)
 }
 
 /// Converts the input string to Ada case.
@@ -31,6 +39,7 @@ pub fn ada_case_with_options(input: &str, opts: &Options) -> String {
 ///     let ada = stringcase::ada_case("fooBar123Baz");
 ///     assert_eq!(ada, "Foo_Bar123_Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn ada_case(input: &str) -> String {
     let opts = Options {
@@ -38,11 +47,172 @@ pub fn ada_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
-    capitalize::<'_'>(input, &opts)
+    render_string(input, &opts, ADA)
 }
 
-#[cfg(test)]
+/// Converts the input string to Ada case with the specified options, without allocating a new
+/// string if the input string is already in Ada case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`ada_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     let ada = stringcase::ada_case_cow_with_options("Foo_Bar_100_Baz", &opts);
+///     assert!(matches!(ada, Cow::Borrowed("Foo_Bar_100_Baz")));
+///
+///     let ada = stringcase::ada_case_cow_with_options("Foo_Bar100_Baz", &opts);
+///     assert!(matches!(ada, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn ada_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, ADA)
+}
+
+/// Converts the input string to Ada case, without allocating a new string if the input string
+/// is already in Ada case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`ada_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let ada = stringcase::ada_case_cow("Foo_Bar100_Baz");
+///     assert!(matches!(ada, Cow::Borrowed("Foo_Bar100_Baz")));
+///
+///     let ada = stringcase::ada_case_cow("foo_bar100_baz");
+///     assert_eq!(ada, stringcase::ada_case("foo_bar100_baz"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn ada_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, ADA)
+}
+
+/// Converts the input string to Ada case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`ada_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::ada_case_into("foo_bar100_baz", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: Foo_Bar100_Baz");
+/// ```
+#[inline(always)]
+pub fn ada_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, ADA)
+}
+
+/// Converts the input string to Ada case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`ada_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::ada_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+///     assert_eq!(result, Ok("Foo_Bar100_Baz"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::ada_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 14);
+/// ```
+#[inline(always)]
+pub fn ada_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, ADA)
+}
+
+/// A wrapper of a string slice which is displayed in Ada case.
+///
+/// It converts the string while formatting it in the same way as [`ada_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::AdaCase;
+///
+///     let s = format!("name: {}", AdaCase("foo_bar100_baz"));
+///     assert_eq!(s, "name: Foo_Bar100_Baz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdaCase<'a>(pub &'a str);
+
+impl fmt::Display for AdaCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, ADA)
+    }
+}
+
+/// Checks whether the input string is in Ada case with the specified options.
+///
+/// It returns `true` if converting the input string with [`ada_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_ada_case_with_options("Foo_Bar_100_Baz", &opts));
+///     assert!(!stringcase::is_ada_case_with_options("Foo_Bar100_Baz", &opts));
+/// ```
+#[inline(always)]
+pub fn is_ada_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, ADA)
+}
+
+/// Checks whether the input string is in Ada case.
+///
+/// It returns `true` if converting the input string with [`ada_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_ada_case("Foo_Bar100_Baz"));
+///     assert!(!stringcase::is_ada_case("foo_bar100_baz"));
+/// ```
+#[inline(always)]
+pub fn is_ada_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, ADA)
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_ada_case {
     use super::*;
 
@@ -131,7 +301,154 @@ mod tests_of_ada_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_ada_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_ada_case() {
+        assert!(matches!(
+            ada_case_cow("Foo_Bar100_Baz"),
+            Cow::Borrowed("Foo_Bar100_Baz")
+        ));
+        assert!(matches!(ada_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_ada_case() {
+        let result = ada_case_cow("fooBar100Baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "Foo_Bar100_Baz");
+
+        let result = ada_case_cow("Foo_Bar100_Baz_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, ada_case("Foo_Bar100_Baz_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["Foo_Bar100_Baz", "fooBar100Baz", "Foo_Bar100_Baz_", ""].iter() {
+            let result = ada_case_cow_with_options(input, &opts);
+            assert_eq!(result, ada_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_ada_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        ada_case_into("foo_bar100_baz", &opts, &mut out).unwrap();
+        ada_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "Foo_Bar100_Baz");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        ada_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, ada_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(AdaCase("foo_bar100_baz").to_string(), "Foo_Bar100_Baz");
+        assert_eq!(format!("<{}>", AdaCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_ada_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = ada_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Ok("Foo_Bar100_Baz"));
+
+        let result = ada_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 14];
+        let result = ada_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Ok("Foo_Bar100_Baz"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 13];
+        let result = ada_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 14 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = ada_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(ada_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_ada_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_ada_case() {
+        assert!(is_ada_case("Foo_Bar100_Baz"));
+        assert!(is_ada_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_ada_case() {
+        assert!(!is_ada_case("foo_bar100_baz"));
+        assert!(!is_ada_case("Foo_bar"));
+        assert!(!is_ada_case("FooBar"));
+        assert!(!is_ada_case("_Foo_Bar100_Baz"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_ada_case_with_options("Foo_Bar_100_Baz", &opts));
+        assert!(is_ada_case_with_options("", &opts));
+        assert!(!is_ada_case_with_options("Foo_Bar100_Baz", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_ada_case_with_options {
     use super::*;
 
@@ -145,6 +462,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -157,6 +475,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -169,6 +488,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -181,6 +501,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -193,6 +514,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -205,6 +527,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -217,6 +540,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -229,6 +553,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -241,6 +566,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -253,6 +579,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc_123_456def_G_89hi_Jkl_Mn_12");
@@ -265,6 +592,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc_Def_Ghi_Jk_Lm_No");
@@ -277,6 +605,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc_456def");
@@ -295,6 +624,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -311,6 +641,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -323,6 +654,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -335,6 +667,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -347,6 +680,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -359,6 +693,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -371,6 +706,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -383,6 +719,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -395,6 +732,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -407,6 +745,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -419,6 +758,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123_456_Def_G89_Hi_Jkl_Mn12");
@@ -431,6 +771,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc_Def_Ghi_Jk_Lm_No");
@@ -443,6 +784,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_Abc456_Def");
@@ -461,6 +803,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -477,6 +820,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -489,6 +833,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -501,6 +846,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -513,6 +859,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -525,6 +872,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -537,6 +885,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -549,6 +898,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -561,6 +911,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -573,6 +924,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -585,6 +937,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc_123_456_Def_G_89_Hi_Jkl_Mn_12");
@@ -597,6 +950,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc_Def_Ghi_Jk_Lm_No");
@@ -609,6 +963,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_Abc_456_Def");
@@ -627,6 +982,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -643,6 +999,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -655,6 +1012,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -667,6 +1025,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -679,6 +1038,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -691,6 +1051,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -703,6 +1064,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -715,6 +1077,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -727,6 +1090,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -739,6 +1103,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -751,6 +1116,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123_456def_G89hi_Jkl_Mn12");
@@ -763,6 +1129,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc_Def_Ghi_Jk_Lm_No");
@@ -775,6 +1142,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -793,6 +1161,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -809,6 +1178,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -821,6 +1191,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -833,6 +1204,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -849,6 +1221,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -865,6 +1238,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -881,6 +1255,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -897,6 +1272,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -913,6 +1289,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc_123_456def_G_89hi_Jkl_Mn_12");
@@ -929,6 +1306,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc_~!_Def_#_Ghi_%_Jk_Lm_No_?");
@@ -941,6 +1319,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc_456def");
@@ -959,6 +1338,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -971,6 +1351,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc_123def");
@@ -987,6 +1368,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -999,6 +1381,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1011,6 +1394,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1027,6 +1411,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1043,6 +1428,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1059,6 +1445,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: " ",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1075,6 +1462,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1091,6 +1479,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1107,6 +1496,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1123,6 +1513,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123_456_Def_G89_Hi_Jkl_Mn12");
@@ -1139,6 +1530,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._Abc~!_Def#_Ghi%_Jk_Lm_No_?");
@@ -1151,6 +1543,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_Abc456_Def");
@@ -1169,6 +1562,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1181,6 +1575,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123_Def");
@@ -1197,6 +1592,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1209,6 +1605,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1221,6 +1618,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1237,6 +1635,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1253,6 +1652,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1269,6 +1669,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: " ",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1285,6 +1686,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1301,6 +1703,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1317,6 +1720,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1333,6 +1737,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc_123_456_Def_G_89_Hi_Jkl_Mn_12");
@@ -1349,6 +1754,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._Abc_~!_Def_#_Ghi_%_Jk_Lm_No_?");
@@ -1361,6 +1767,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_Abc_456_Def");
@@ -1379,6 +1786,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1391,6 +1799,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc_123_Def");
@@ -1407,6 +1816,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1419,6 +1829,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1431,6 +1842,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1447,6 +1859,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1463,6 +1876,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1479,6 +1893,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: " ",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1495,6 +1910,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1511,6 +1927,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1527,6 +1944,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123_456def_G89hi_Jkl_Mn12");
@@ -1543,6 +1961,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!_Def#_Ghi%_Jk_Lm_No_?");
@@ -1555,6 +1974,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1573,6 +1993,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1585,6 +2006,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123def");
@@ -1601,6 +2023,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1613,6 +2036,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1625,6 +2049,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1641,6 +2066,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1657,6 +2083,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1673,6 +2100,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1689,6 +2117,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1705,6 +2134,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1721,6 +2151,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1737,6 +2168,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc_123_456def_G_89hi_Jkl_Mn_12");
@@ -1753,6 +2185,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc_~!_Def_#_Ghi_%_Jk_Lm_No_?");
@@ -1765,6 +2198,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc_456def");
@@ -1783,6 +2217,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1795,6 +2230,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-b2",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc_123def");
@@ -1811,6 +2247,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1823,6 +2260,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -1835,6 +2273,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1851,6 +2290,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1867,6 +2307,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1883,6 +2324,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1899,6 +2341,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1915,6 +2358,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1931,6 +2375,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -1947,6 +2392,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123_456_Def_G89_Hi_Jkl_Mn12");
@@ -1963,6 +2409,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._Abc~!_Def#_Ghi%_Jk_Lm_No_?");
@@ -1975,6 +2422,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_Abc456_Def");
@@ -1993,6 +2441,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -2009,6 +2458,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -2021,6 +2471,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -2033,6 +2484,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2049,6 +2501,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2065,6 +2518,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2081,6 +2535,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2097,6 +2552,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2113,6 +2569,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2129,6 +2586,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2145,6 +2603,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc_123_456_Def_G_89_Hi_Jkl_Mn_12");
@@ -2161,6 +2620,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._Abc_~!_Def_#_Ghi_%_Jk_Lm_No_?");
@@ -2173,6 +2633,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_Abc_456_Def");
@@ -2191,6 +2652,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -2207,6 +2669,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -2219,6 +2682,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc_Def_Gh_Ijk");
@@ -2231,6 +2695,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2247,6 +2712,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2263,6 +2729,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2279,6 +2746,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2295,6 +2763,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2311,6 +2780,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2327,6 +2797,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc_Def_Ghi");
@@ -2343,6 +2814,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = ada_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123_456def_G89hi_Jkl_Mn12");
@@ -2359,6 +2831,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = ada_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!_Def#_Ghi%_Jk_Lm_No_?");
@@ -2371,6 +2844,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -2389,6 +2863,7 @@ mod tests_of_ada_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = ada_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -0,0 +1,323 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::case::{Case, CASE_COUNT};
+use crate::options::Options;
+use crate::render::WordCase;
+use crate::words::{push_capitalized_word, push_lower_word, push_upper_word, words};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::array;
+use core::iter::{self, FusedIterator, Zip};
+use core::slice;
+
+/// A struct that holds a string converted into all case styles, which is created by
+/// [`all_cases`].
+///
+/// The converted strings are got by the methods named after the case styles, like
+/// [`snake_case`](AllCases::snake_case), or by [`get`](AllCases::get) with a [`Case`].
+/// This struct can also be iterated over pairs of [`Case`] and the converted string in the order
+/// of [`Case::ALL`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AllCases {
+    strings: [String; CASE_COUNT],
+}
+
+impl AllCases {
+    /// Returns the string converted into the specified case style.
+    pub fn get(&self, case: Case) -> &str {
+        &self.strings[case as usize]
+    }
+
+    /// Returns the string converted into Ada case.
+    pub fn ada_case(&self) -> &str {
+        self.get(Case::Ada)
+    }
+
+    /// Returns the string converted into camel case.
+    pub fn camel_case(&self) -> &str {
+        self.get(Case::Camel)
+    }
+
+    /// Returns the string converted into COBOL case.
+    pub fn cobol_case(&self) -> &str {
+        self.get(Case::Cobol)
+    }
+
+    /// Returns the string converted into kebab case.
+    pub fn kebab_case(&self) -> &str {
+        self.get(Case::Kebab)
+    }
+
+    /// Returns the string converted into macro case.
+    pub fn macro_case(&self) -> &str {
+        self.get(Case::Macro)
+    }
+
+    /// Returns the string converted into pascal case.
+    pub fn pascal_case(&self) -> &str {
+        self.get(Case::Pascal)
+    }
+
+    /// Returns the string converted into snake case.
+    pub fn snake_case(&self) -> &str {
+        self.get(Case::Snake)
+    }
+
+    /// Returns the string converted into title case.
+    pub fn title_case(&self) -> &str {
+        self.get(Case::Title)
+    }
+
+    /// Returns the string converted into train case.
+    pub fn train_case(&self) -> &str {
+        self.get(Case::Train)
+    }
+
+    /// Returns an iterator over pairs of [`Case`] and the converted string in the order of
+    /// [`Case::ALL`].
+    pub fn iter(&self) -> AllCasesIter<'_> {
+        AllCasesIter {
+            inner: Case::ALL.iter().zip(self.strings.iter()),
+        }
+    }
+}
+
+/// An iterator over pairs of [`Case`] and a converted string slice, which is created by
+/// [`AllCases::iter`].
+#[derive(Clone)]
+pub struct AllCasesIter<'a> {
+    inner: Zip<slice::Iter<'static, Case>, slice::Iter<'a, String>>,
+}
+
+impl<'a> Iterator for AllCasesIter<'a> {
+    type Item = (Case, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(case, s)| (*case, s.as_str()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for AllCasesIter<'_> {}
+impl FusedIterator for AllCasesIter<'_> {}
+
+/// An iterator over pairs of [`Case`] and a converted string, which is created by the
+/// `into_iter` method of [`AllCases`].
+pub struct AllCasesIntoIter {
+    inner: Zip<iter::Copied<slice::Iter<'static, Case>>, array::IntoIter<String, CASE_COUNT>>,
+}
+
+impl Iterator for AllCasesIntoIter {
+    type Item = (Case, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl ExactSizeIterator for AllCasesIntoIter {}
+impl FusedIterator for AllCasesIntoIter {}
+
+impl IntoIterator for AllCases {
+    type Item = (Case, String);
+    type IntoIter = AllCasesIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        AllCasesIntoIter {
+            inner: Case::ALL
+                .iter()
+                .copied()
+                .zip(IntoIterator::into_iter(self.strings)),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a AllCases {
+    type Item = (Case, &'a str);
+    type IntoIter = AllCasesIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Converts the input string into all case styles with the specified options.
+///
+/// This function splits the input string into words only once, and converts each word into
+/// lowercase, uppercase, and capitalized forms only once, then joins them into all case styles.
+/// So this is faster than calling the nine `〜_case_with_options` functions, and each converted
+/// string is the same as the string which the `〜_case_with_options` function of the case style,
+/// or [`convert`](crate::convert), returns with the same options.
+///
+/// # Parameters
+///
+/// - `input`: The string slice (`&str`) to be converted.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - An [`AllCases`] holding the converted strings.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{all_cases, Case, Options};
+///
+/// let opts = Options::default();
+/// let cases = all_cases("fooBar100Baz", &opts);
+/// assert_eq!(cases.snake_case(), "foo_bar100_baz");
+/// assert_eq!(cases.get(Case::Title), "Foo Bar100 Baz");
+///
+/// let mut iter = cases.into_iter();
+/// assert_eq!(iter.next(), Some((Case::Ada, String::from("Foo_Bar100_Baz"))));
+/// assert_eq!(iter.next(), Some((Case::Camel, String::from("fooBar100Baz"))));
+/// ```
+pub fn all_cases(input: &str, opts: &Options) -> AllCases {
+    // The lowercase, uppercase, capitalized, and capitalized with acronyms uppercased forms of
+    // all words are concatenated in `forms`, and `ends` holds the end of each form of each word.
+    let mut forms = [String::new(), String::new(), String::new(), String::new()];
+    let mut ends: Vec<[usize; 4]> = Vec::new();
+    for word in words(input, opts) {
+        push_lower_word(&mut forms[0], word.text, opts.unicode);
+        push_upper_word(&mut forms[1], word.text, opts.unicode);
+        push_capitalized_word(&mut forms[2], word.text, opts, false);
+        push_capitalized_word(&mut forms[3], word.text, opts, opts.uppercase_acronyms);
+        ends.push([
+            forms[0].len(),
+            forms[1].len(),
+            forms[2].len(),
+            forms[3].len(),
+        ]);
+    }
+
+    let form = |i: usize, word_case: WordCase, uppercase_acronyms: bool| -> &str {
+        let k = match word_case {
+            WordCase::Lower => 0,
+            WordCase::Upper => 1,
+            WordCase::Capitalized if uppercase_acronyms => 3,
+            WordCase::Capitalized => 2,
+        };
+        let start = if i == 0 { 0 } else { ends[i - 1][k] };
+        &forms[k][start..ends[i][k]]
+    };
+
+    let mut strings: [String; CASE_COUNT] = Default::default();
+    for case in Case::ALL.iter() {
+        let style = case.style();
+        let uppercase_acronyms = opts.uppercases_acronyms(Some(*case));
+        let s = &mut strings[*case as usize];
+        s.reserve(forms[0].len() + ends.len());
+        for i in 0..ends.len() {
+            if i == 0 {
+                s.push_str(form(i, style.head, uppercase_acronyms));
+            } else {
+                if let Some(joiner) = style.joiner {
+                    s.push(joiner);
+                }
+                s.push_str(form(i, style.tail, uppercase_acronyms));
+            }
+        }
+    }
+    AllCases { strings }
+}
+
+#[cfg(test)]
+mod tests_of_all_cases {
+    use super::*;
+    use crate::case::convert;
+
+    fn assert_same_as_convert(input: &str, opts: &Options) {
+        let cases = all_cases(input, opts);
+        for case in Case::ALL.iter() {
+            assert_eq!(cases.get(*case), convert(input, *case, opts), "{}", case);
+        }
+    }
+
+    #[test]
+    fn convert_to_all_cases() {
+        let cases = all_cases("fooBar100%BAZQux", &Options::default());
+        assert_eq!(cases.ada_case(), "Foo_Bar100_Baz_Qux");
+        assert_eq!(cases.camel_case(), "fooBar100BazQux");
+        assert_eq!(cases.cobol_case(), "FOO-BAR100-BAZ-QUX");
+        assert_eq!(cases.kebab_case(), "foo-bar100-baz-qux");
+        assert_eq!(cases.macro_case(), "FOO_BAR100_BAZ_QUX");
+        assert_eq!(cases.pascal_case(), "FooBar100BazQux");
+        assert_eq!(cases.snake_case(), "foo_bar100_baz_qux");
+        assert_eq!(cases.title_case(), "Foo Bar100 Baz Qux");
+        assert_eq!(cases.train_case(), "Foo-Bar100-Baz-Qux");
+    }
+
+    #[test]
+    fn convert_an_empty_string() {
+        let cases = all_cases("", &Options::default());
+        assert!(cases.iter().all(|(_, s)| s.is_empty()));
+    }
+
+    #[test]
+    fn same_as_convert() {
+        let inputs = [
+            "",
+            "foo",
+            "fooBar100%BAZQux",
+            "  foo__bar-baz  ",
+            "HTTPServerID",
+            "xml_http_request",
+            "abc123def456",
+            "straße_ÉtéÀ",
+        ];
+        let acronyms = ["HTTP", "ID", "XML"];
+        for input in inputs.iter() {
+            assert_same_as_convert(input, &Options::default());
+            assert_same_as_convert(input, &Options::new(true, true, "", "%"));
+            assert_same_as_convert(input, &Options::new(true, false, "_", ""));
+            assert_same_as_convert(
+                input,
+                &Options {
+                    unicode: true,
+                    acronyms: &acronyms,
+                    uppercase_acronyms: true,
+                    ..Default::default()
+                },
+            );
+            assert_same_as_convert(
+                input,
+                &Options {
+                    acronyms: &acronyms,
+                    uppercase_acronyms: true,
+                    uppercase_acronyms_in: &[Case::Pascal, Case::Title],
+                    ..Default::default()
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn iterate_over_all_cases() {
+        let cases = all_cases("foo_bar", &Options::default());
+        let iter = cases.iter();
+        assert_eq!(iter.len(), 9);
+        let v: Vec<(Case, &str)> = iter.collect();
+        assert_eq!(v[0], (Case::Ada, "Foo_Bar"));
+        assert_eq!(v[8], (Case::Train, "Foo-Bar"));
+        for ((case, s), expected) in (&cases).into_iter().zip(Case::ALL.iter()) {
+            assert_eq!(case, *expected);
+            assert_eq!(s, cases.get(case));
+        }
+
+        let v: Vec<(Case, String)> = cases.clone().into_iter().collect();
+        assert_eq!(v.len(), 9);
+        assert_eq!(v[6], (Case::Snake, String::from("foo_bar")));
+        assert_eq!(v[7], (Case::Title, String::from("Foo Bar")));
+    }
+}
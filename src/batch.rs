@@ -0,0 +1,427 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::case::Case;
+use crate::options::Options;
+use crate::render::{render_string, Style, WordCase};
+use crate::words::{push_capitalized_word, push_lower_word, push_upper_word};
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A struct that represents a group of distinct inputs which are converted into the same string
+/// by [`convert_batch`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision<'a> {
+    /// The string into which all `inputs` are converted.
+    pub output: String,
+
+    /// The distinct inputs which are converted into `output`, in the order of their first
+    /// appearance.
+    pub inputs: Vec<&'a str>,
+
+    /// The indices of all inputs which are converted into `output`, including duplicated inputs.
+    pub indices: Vec<usize>,
+}
+
+impl fmt::Display for Collision<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is converted from ", self.output)?;
+        for (i, input) in self.inputs.iter().enumerate() {
+            if i == 0 {
+                write!(f, "{:?}", input)?;
+            } else if i == self.inputs.len() - 1 {
+                write!(f, " and {:?}", input)?;
+            } else {
+                write!(f, ", {:?}", input)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An enum that represents the suffixes which [`BatchConversion::resolve`] appends to colliding
+/// strings to make them unique.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Suffix {
+    /// Sequential numbers from 2, e.g. `user_id_2` and `user_id_3`.
+    Number,
+
+    /// Short hashes of the inputs, e.g. `user_id_3c1e0a`.
+    Hash,
+}
+
+/// A struct that holds the results of [`convert_batch`], which are the converted strings and the
+/// collisions among them.
+#[derive(Debug, Clone)]
+pub struct BatchConversion<'a> {
+    case: Case,
+    unicode: bool,
+    inputs: Vec<&'a str>,
+    outputs: Vec<String>,
+    collisions: Vec<Collision<'a>>,
+}
+
+impl<'a> BatchConversion<'a> {
+    /// Returns the case style into which the inputs are converted.
+    pub fn case(&self) -> Case {
+        self.case
+    }
+
+    /// Returns the inputs.
+    pub fn inputs(&self) -> &[&'a str] {
+        &self.inputs
+    }
+
+    /// Returns the converted strings, in the same order as the inputs.
+    pub fn outputs(&self) -> &[String] {
+        &self.outputs
+    }
+
+    /// Returns the groups of distinct inputs which are converted into the same string, in the
+    /// order of the converted strings.
+    pub fn collisions(&self) -> &[Collision<'a>] {
+        &self.collisions
+    }
+
+    /// Returns `true` if some distinct inputs are converted into the same string.
+    pub fn has_collisions(&self) -> bool {
+        !self.collisions.is_empty()
+    }
+
+    /// Returns the converted strings which are made unique by appending suffixes to colliding
+    /// strings, in the same order as the inputs.
+    ///
+    /// In each collision, the input which is already in the case style, or the smallest input if
+    /// none is, keeps the converted string.
+    /// The other inputs get suffixes in the lexicographic order of the inputs, joined and cased in
+    /// the same way as a word in the case style (e.g. `user_id_2`, `userId2`, or `USER_ID_2`).
+    /// A suffix is skipped if the suffixed string is equal to another converted string.
+    /// So the results depend only on the set of inputs, and are the same if the inputs are
+    /// reordered.
+    /// Duplicated inputs get the same string.
+    ///
+    /// ```rust
+    /// use stringcase::{convert_batch, Case, Options, Suffix};
+    ///
+    /// let opts = Options::default();
+    /// let batch = convert_batch(&["userID", "user_id", "User-Id", "name"], Case::Snake, &opts);
+    /// assert_eq!(
+    ///     batch.resolve(Suffix::Number),
+    ///     ["user_id_3", "user_id", "user_id_2", "name"]
+    /// );
+    /// ```
+    pub fn resolve(&self, suffix: Suffix) -> Vec<String> {
+        let mut resolved = self.outputs.clone();
+        let mut used: BTreeSet<String> = self.outputs.iter().cloned().collect();
+        let style = self.case.style();
+
+        for collision in self.collisions.iter() {
+            let base = &collision.output;
+            let mut inputs = collision.inputs.clone();
+            inputs.sort_unstable();
+            if let Some(i) = inputs.iter().position(|input| *input == base) {
+                let input = inputs.remove(i);
+                inputs.insert(0, input);
+            }
+
+            let mut names: BTreeMap<&str, String> = BTreeMap::new();
+            let mut n = 1;
+            for input in inputs.iter().skip(1) {
+                let name = match suffix {
+                    Suffix::Number => loop {
+                        n += 1;
+                        let name = suffixed(base, &n.to_string(), style, self.unicode);
+                        if !used.contains(&name) {
+                            break name;
+                        }
+                    },
+                    Suffix::Hash => {
+                        let hash = short_hash(input);
+                        let name = suffixed(base, &hash, style, self.unicode);
+                        let mut m = 1;
+                        let mut unique = name.clone();
+                        while used.contains(&unique) {
+                            m += 1;
+                            unique = suffixed(&name, &m.to_string(), style, self.unicode);
+                        }
+                        unique
+                    }
+                };
+                used.insert(name.clone());
+                names.insert(input, name);
+            }
+
+            for i in collision.indices.iter() {
+                if let Some(name) = names.get(self.inputs[*i]) {
+                    resolved[*i] = name.clone();
+                }
+            }
+        }
+        resolved
+    }
+}
+
+// Returns `base` followed by `suffix`, which is joined and cased as a word after the head of the
+// case style.
+fn suffixed(base: &str, suffix: &str, style: Style, unicode: bool) -> String {
+    let mut s = String::with_capacity(base.len() + suffix.len() + 1);
+    s.push_str(base);
+    if !base.is_empty() {
+        if let Some(joiner) = style.joiner {
+            s.push(joiner);
+        }
+    }
+    match style.tail {
+        WordCase::Lower => push_lower_word(&mut s, suffix, unicode),
+        WordCase::Upper => push_upper_word(&mut s, suffix, unicode),
+        WordCase::Capitalized => {
+            let opts = Options {
+                unicode,
+                ..Default::default()
+            };
+            push_capitalized_word(&mut s, suffix, &opts, false)
+        }
+    }
+    s
+}
+
+// Returns the first 6 hexadecimal digits of the 32-bit FNV-1a hash of a string, which is the same
+// on all platforms and in all versions of this crate.
+pub(crate) fn short_hash(s: &str) -> String {
+    let mut h: u32 = 0x811c_9dc5;
+    for b in s.bytes() {
+        h ^= b as u32;
+        h = h.wrapping_mul(0x0100_0193);
+    }
+    let mut hex = alloc::format!("{:08x}", h);
+    hex.truncate(6);
+    hex
+}
+
+/// Converts a list of strings into the specified case style with the specified options, and
+/// detects the distinct strings which are converted into the same string.
+///
+/// Distinct strings can be converted into the same string, like `userID` and `user_id` into
+/// `user_id`, or `a-b` and `a_b` into `a_b`, which breaks the names generated from them.
+/// The returned [`BatchConversion`] holds the converted strings and such collisions, and its
+/// [`resolve`](BatchConversion::resolve) method makes the converted strings unique with
+/// deterministic suffixes.
+///
+/// # Parameters
+///
+/// - `inputs`: The slice of the strings to be converted.
+/// - `case`: The [`Case`] into which the strings are converted.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - A [`BatchConversion`] holding the converted strings and the collisions among them.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{convert_batch, Case, Options, Suffix};
+///
+/// let opts = Options::default();
+/// let batch = convert_batch(&["userID", "user_id", "a-b", "a_b", "c"], Case::Snake, &opts);
+/// assert_eq!(batch.outputs(), ["user_id", "user_id", "a_b", "a_b", "c"]);
+/// assert_eq!(batch.collisions().len(), 2);
+/// assert_eq!(batch.collisions()[0].output, "a_b");
+/// assert_eq!(batch.collisions()[0].inputs, ["a-b", "a_b"]);
+/// assert_eq!(
+///     batch.collisions()[1].to_string(),
+///     "\"user_id\" is converted from \"userID\" and \"user_id\""
+/// );
+/// assert_eq!(
+///     batch.resolve(Suffix::Number),
+///     ["user_id_2", "user_id", "a_b_2", "a_b", "c"]
+/// );
+/// ```
+pub fn convert_batch<'a, S: AsRef<str>>(
+    inputs: &'a [S],
+    case: Case,
+    opts: &Options,
+) -> BatchConversion<'a> {
+    let inputs: Vec<&'a str> = inputs.iter().map(|s| s.as_ref()).collect();
+    let outputs: Vec<String> = inputs
+        .iter()
+        .map(|input| render_string(input, opts, case.style()))
+        .collect();
+
+    let mut groups: BTreeMap<&str, Collision<'a>> = BTreeMap::new();
+    for (i, output) in outputs.iter().enumerate() {
+        let group = groups.entry(output.as_str()).or_insert_with(|| Collision {
+            output: output.clone(),
+            inputs: Vec::new(),
+            indices: Vec::new(),
+        });
+        if !group.inputs.contains(&inputs[i]) {
+            group.inputs.push(inputs[i]);
+        }
+        group.indices.push(i);
+    }
+    let collisions = groups
+        .into_values()
+        .filter(|group| group.inputs.len() > 1)
+        .collect();
+
+    BatchConversion {
+        case,
+        unicode: opts.unicode,
+        inputs,
+        outputs,
+        collisions,
+    }
+}
+
+#[cfg(test)]
+mod tests_of_convert_batch {
+    use super::*;
+
+    #[test]
+    fn convert_without_collisions() {
+        let opts = Options::default();
+        let batch = convert_batch(&["fooBar", "baz", "fooBar"], Case::Kebab, &opts);
+        assert_eq!(batch.case(), Case::Kebab);
+        assert_eq!(batch.inputs(), ["fooBar", "baz", "fooBar"]);
+        assert_eq!(batch.outputs(), ["foo-bar", "baz", "foo-bar"]);
+        assert!(!batch.has_collisions());
+        assert_eq!(batch.resolve(Suffix::Number), ["foo-bar", "baz", "foo-bar"]);
+        assert_eq!(batch.resolve(Suffix::Hash), ["foo-bar", "baz", "foo-bar"]);
+    }
+
+    #[test]
+    fn detect_collisions() {
+        let opts = Options::default();
+        let inputs = vec![
+            String::from("a-b"),
+            String::from("userID"),
+            String::from("a_b"),
+            String::from("user_id"),
+            String::from("a-b"),
+            String::from("A B"),
+        ];
+        let batch = convert_batch(&inputs, Case::Snake, &opts);
+        assert!(batch.has_collisions());
+        assert_eq!(
+            batch.collisions(),
+            [
+                Collision {
+                    output: String::from("a_b"),
+                    inputs: vec!["a-b", "a_b", "A B"],
+                    indices: vec![0, 2, 4, 5],
+                },
+                Collision {
+                    output: String::from("user_id"),
+                    inputs: vec!["userID", "user_id"],
+                    indices: vec![1, 3],
+                },
+            ]
+        );
+        assert_eq!(
+            batch.collisions()[0].to_string(),
+            "\"a_b\" is converted from \"a-b\", \"a_b\" and \"A B\""
+        );
+    }
+
+    #[test]
+    fn resolve_with_numbers() {
+        let opts = Options::default();
+        let batch = convert_batch(&["a-b", "userID", "a_b", "a-b", "A B"], Case::Snake, &opts);
+        assert_eq!(
+            batch.resolve(Suffix::Number),
+            ["a_b_3", "user_id", "a_b", "a_b_3", "a_b_2"]
+        );
+
+        let batch = convert_batch(&["a-b", "A B"], Case::Snake, &opts);
+        assert_eq!(batch.resolve(Suffix::Number), ["a_b_2", "a_b"]);
+    }
+
+    #[test]
+    fn resolve_independently_of_order() {
+        let opts = Options::default();
+        let inputs = ["userID", "user-id", "UserId", "user_id"];
+        let batch = convert_batch(&inputs, Case::Camel, &opts);
+        let resolved = batch.resolve(Suffix::Number);
+        assert_eq!(resolved, ["userId3", "userId2", "userId", "userId4"]);
+
+        let reversed: Vec<&str> = inputs.iter().rev().copied().collect();
+        let batch = convert_batch(&reversed, Case::Camel, &opts);
+        let mut expected = resolved.clone();
+        expected.reverse();
+        assert_eq!(batch.resolve(Suffix::Number), expected);
+    }
+
+    #[test]
+    fn skip_suffixes_used_by_other_strings() {
+        let opts = Options::default();
+        let batch = convert_batch(&["userId", "user_id", "user_id_2"], Case::Snake, &opts);
+        assert_eq!(
+            batch.resolve(Suffix::Number),
+            ["user_id_3", "user_id", "user_id_2"]
+        );
+    }
+
+    #[test]
+    fn resolve_with_hashes() {
+        let opts = Options::default();
+        let batch = convert_batch(&["userID", "user_id", "User-Id"], Case::Snake, &opts);
+        let resolved = batch.resolve(Suffix::Hash);
+        assert_eq!(
+            resolved[0],
+            alloc::format!("user_id_{}", short_hash("userID"))
+        );
+        assert_eq!(resolved[1], "user_id");
+        assert_eq!(
+            resolved[2],
+            alloc::format!("user_id_{}", short_hash("User-Id"))
+        );
+
+        let batch = convert_batch(&["user_id", "userID"], Case::Snake, &opts);
+        assert_eq!(batch.resolve(Suffix::Hash)[1], resolved[0]);
+
+        let batch = convert_batch(&["userID", "USER_ID"], Case::Macro, &opts);
+        let expected = alloc::format!("USER_ID_{}", short_hash("userID").to_uppercase());
+        assert_eq!(batch.resolve(Suffix::Hash), [expected.as_str(), "USER_ID"]);
+    }
+
+    #[test]
+    fn suffix_in_each_case_style() {
+        let opts = Options::default();
+        for (case, expected) in [
+            (Case::Ada, "A_B_2"),
+            (Case::Camel, "aB2"),
+            (Case::Cobol, "A-B-2"),
+            (Case::Kebab, "a-b-2"),
+            (Case::Macro, "A_B_2"),
+            (Case::Pascal, "AB2"),
+            (Case::Snake, "a_b_2"),
+            (Case::Title, "A B 2"),
+            (Case::Train, "A-B-2"),
+        ] {
+            let batch = convert_batch(&["a-b", "a_b", "a b", "aB", "AB"], case, &opts);
+            assert!(
+                batch
+                    .resolve(Suffix::Number)
+                    .contains(&String::from(expected)),
+                "{}",
+                case
+            );
+        }
+
+        let batch = convert_batch(&["--", "__"], Case::Snake, &opts);
+        assert_eq!(batch.resolve(Suffix::Number), ["", "2"]);
+    }
+
+    #[test]
+    fn test_of_short_hash() {
+        assert_eq!(short_hash(""), "811c9d");
+        assert_eq!(short_hash("a"), "e40c29");
+        assert_eq!(short_hash("userID").len(), 6);
+    }
+}
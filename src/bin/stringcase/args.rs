@@ -0,0 +1,547 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use std::fmt;
+use stringcase::codemod::Language;
+use stringcase::{Case, Options};
+
+pub(crate) const USAGE: &str = "\
+Converts strings into case styles, like camelCase, kebab-case, and snake_case.
+
+Usage:
+  stringcase convert <CASE> [OPTIONS] [INPUT]...
+  stringcase variants [--json] [OPTIONS] [INPUT]...
+  stringcase detect [--json] [OPTIONS] [INPUT]...
+  stringcase rename <CASE> [RENAME OPTIONS] [OPTIONS] <DIR>...
+  stringcase codemod <CASE> [CODEMOD OPTIONS] [OPTIONS] <FILE>...
+
+Commands:
+  convert   Converts each input into <CASE>.
+  variants  Prints each input in all case styles as a table, or as JSON with --json.
+  detect    Prints the case style of each input, or `unknown` if it is in no case style.
+            Exits with status 1 if some input is in no case style.
+  rename    Renames the files and directories in the trees of <DIR>s into <CASE>, keeping
+            the extensions of files. Nothing is renamed if some new names collide with each
+            other or with existing names.
+  codemod   Renames the identifiers in source files of Rust, JavaScript, TypeScript, Python,
+            or Go into <CASE>, and prints the changes as a unified diff.
+
+Each input is an argument, or a line of the standard input if no argument is given.
+<CASE> is one of Ada_Case, camelCase, COBOL-CASE, kebab-case, MACRO_CASE, PascalCase,
+snake_case, Title Case, and Train-Case. Letter cases, separators, and the trailing word
+`case` are ignored, so that `snake` and `SNAKE-CASE` mean snake_case.
+
+Options:
+  --separate-before-non-alphabets     Treats the beginning of a sequence of non-alphabetic
+                                      characters as a word boundary.
+  --no-separate-after-non-alphabets   Does not treat the end of a sequence of non-alphabetic
+                                      characters as a word boundary.
+  --separators <CHARS>                Treats only <CHARS> as word separators.
+  --keep <CHARS>                      Keeps <CHARS> in the result instead of separating words.
+  --unicode                           Treats Unicode letters as letters.
+  --no-unicode                        Treats only ASCII letters as letters.
+  --acronyms <WORDS>                  Treats the comma-separated <WORDS> as acronyms.
+  --uppercase-acronyms                Uppercases acronyms in case styles which capitalize words.
+  --uppercase-acronyms-in <CASES>     Uppercases acronyms only in the comma-separated <CASES>.
+  --json                              Prints the results as JSON.
+  -h, --help                          Prints this help.
+  -V, --version                       Prints the version.
+
+Rename options:
+  --files                             Renames only files, unless --dirs is also given.
+  --dirs                              Renames only directories, unless --files is also given.
+  --hidden                            Renames also hidden entries, whose names start with `.`.
+  -n, --dry-run                       Prints the renames without renaming anything.
+  --undo <FILE>                       Writes a shell script which reverts the renames.
+
+Codemod options:
+  --lang <LANG>                       Treats the files as source code of <LANG>, instead of
+                                      detecting it from their extensions.
+  --pattern <GLOB>                    Renames only the identifiers which match <GLOB>, in which
+                                      `*` matches any characters and `?` matches a character.
+  --from <CASE>                       Renames only the identifiers which are in <CASE>.
+  --strings                           Renames also the matching words in string literals.
+  --comments                          Renames also the matching words in comments.
+  --write                             Writes the changes into the files.
+";
+
+/// The command which is specified with command-line arguments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Command {
+    Convert(Case),
+    Variants,
+    Detect,
+    Rename(Case),
+    Codemod(Case),
+    Help,
+    Version,
+}
+
+/// The fields of `Options` which are specified with command-line flags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct OptionArgs {
+    pub(crate) separate_before_non_alphabets: bool,
+    pub(crate) separate_after_non_alphabets: bool,
+    pub(crate) separators: String,
+    pub(crate) keep: String,
+    pub(crate) unicode: bool,
+    pub(crate) acronyms: Vec<String>,
+    pub(crate) uppercase_acronyms: bool,
+    pub(crate) uppercase_acronyms_in: Vec<Case>,
+}
+
+impl Default for OptionArgs {
+    fn default() -> Self {
+        let opts = Options::default();
+        Self {
+            separate_before_non_alphabets: opts.separate_before_non_alphabets,
+            separate_after_non_alphabets: opts.separate_after_non_alphabets,
+            separators: opts.separators.to_string(),
+            keep: opts.keep.to_string(),
+            unicode: opts.unicode,
+            acronyms: Vec::new(),
+            uppercase_acronyms: opts.uppercase_acronyms,
+            uppercase_acronyms_in: opts.uppercase_acronyms_in.to_vec(),
+        }
+    }
+}
+
+impl OptionArgs {
+    /// Creates `Options` which borrow these fields, and the acronyms collected from `acronyms`.
+    pub(crate) fn to_options<'a>(&'a self, acronyms: &'a [&'a str]) -> Options<'a> {
+        Options {
+            separate_before_non_alphabets: self.separate_before_non_alphabets,
+            separate_after_non_alphabets: self.separate_after_non_alphabets,
+            separators: &self.separators,
+            keep: &self.keep,
+            unicode: self.unicode,
+            acronyms,
+            uppercase_acronyms: self.uppercase_acronyms,
+            uppercase_acronyms_in: &self.uppercase_acronyms_in,
+        }
+    }
+}
+
+/// The flags which are specified only for the command `rename`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct RenameArgs {
+    pub(crate) files: bool,
+    pub(crate) dirs: bool,
+    pub(crate) hidden: bool,
+    pub(crate) dry_run: bool,
+    pub(crate) undo: Option<String>,
+}
+
+impl RenameArgs {
+    // Returns the first flag which is given, to report it for other commands.
+    fn first_flag(&self) -> Option<&'static str> {
+        if self.files {
+            Some("--files")
+        } else if self.dirs {
+            Some("--dirs")
+        } else if self.hidden {
+            Some("--hidden")
+        } else if self.dry_run {
+            Some("--dry-run")
+        } else if self.undo.is_some() {
+            Some("--undo")
+        } else {
+            None
+        }
+    }
+}
+
+/// The flags which are specified only for the command `codemod`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct CodemodArgs {
+    pub(crate) language: Option<Language>,
+    pub(crate) pattern: Option<String>,
+    pub(crate) from: Option<Case>,
+    pub(crate) strings: bool,
+    pub(crate) comments: bool,
+    pub(crate) write: bool,
+}
+
+impl CodemodArgs {
+    // Returns the first flag which is given, to report it for other commands.
+    fn first_flag(&self) -> Option<&'static str> {
+        if self.language.is_some() {
+            Some("--lang")
+        } else if self.pattern.is_some() {
+            Some("--pattern")
+        } else if self.from.is_some() {
+            Some("--from")
+        } else if self.strings {
+            Some("--strings")
+        } else if self.comments {
+            Some("--comments")
+        } else if self.write {
+            Some("--write")
+        } else {
+            None
+        }
+    }
+}
+
+/// The parsed command-line arguments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Args {
+    pub(crate) command: Command,
+    pub(crate) opts: OptionArgs,
+    pub(crate) json: bool,
+    pub(crate) rename: RenameArgs,
+    pub(crate) codemod: CodemodArgs,
+    pub(crate) inputs: Vec<String>,
+}
+
+/// An error which is returned when command-line arguments are invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ArgError(String);
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn error<T>(msg: String) -> Result<T, ArgError> {
+    Err(ArgError(msg))
+}
+
+impl Args {
+    /// Parses command-line arguments, excluding the program name.
+    pub(crate) fn parse<I>(args: I) -> Result<Self, ArgError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        let mut parsed = Args {
+            command: Command::Help,
+            opts: OptionArgs::default(),
+            json: false,
+            rename: RenameArgs::default(),
+            codemod: CodemodArgs::default(),
+            inputs: Vec::new(),
+        };
+
+        parsed.command = match args.next().as_deref() {
+            None | Some("-h") | Some("--help") | Some("help") => return Ok(parsed),
+            Some("-V") | Some("--version") => {
+                parsed.command = Command::Version;
+                return Ok(parsed);
+            }
+            Some("convert") => Command::Convert(parse_case("convert", args.next())?),
+            Some("variants") => Command::Variants,
+            Some("detect") => Command::Detect,
+            Some("rename") => Command::Rename(parse_case("rename", args.next())?),
+            Some("codemod") => Command::Codemod(parse_case("codemod", args.next())?),
+            Some(cmd) => return error(format!("unknown command: {:?}", cmd)),
+        };
+
+        let mut only_inputs = false;
+        while let Some(arg) = args.next() {
+            if only_inputs || !arg.starts_with('-') || arg == "-" {
+                parsed.inputs.push(arg);
+                continue;
+            }
+            let (flag, inline) = match arg.find('=') {
+                Some(i) if arg.starts_with("--") => (&arg[..i], Some(&arg[i + 1..])),
+                _ => (&arg[..], None),
+            };
+            let value = |args: &mut I::IntoIter| match inline {
+                Some(v) => Ok(v.to_string()),
+                None => match args.next() {
+                    Some(v) => Ok(v),
+                    None => error(format!("`{}` requires a value", flag)),
+                },
+            };
+            let opts = &mut parsed.opts;
+            match flag {
+                "--" => only_inputs = true,
+                "--separators" => opts.separators = value(&mut args)?,
+                "--keep" => opts.keep = value(&mut args)?,
+                "--undo" => parsed.rename.undo = Some(value(&mut args)?),
+                "--lang" => match value(&mut args)?.parse() {
+                    Ok(language) => parsed.codemod.language = Some(language),
+                    Err(err) => return error(format!("{}", err)),
+                },
+                "--pattern" => parsed.codemod.pattern = Some(value(&mut args)?),
+                "--from" => {
+                    parsed.codemod.from = Some(parse_case("--from", Some(value(&mut args)?))?)
+                }
+                "--acronyms" => opts.acronyms.extend(
+                    value(&mut args)?
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string),
+                ),
+                "--uppercase-acronyms-in" => {
+                    for name in value(&mut args)?.split(',').map(str::trim) {
+                        if !name.is_empty() {
+                            let case = parse_case(flag, Some(name.to_string()))?;
+                            opts.uppercase_acronyms_in.push(case);
+                        }
+                    }
+                    opts.uppercase_acronyms = true;
+                }
+                _ if inline.is_some() => {
+                    return error(format!("`{}` does not take a value", flag));
+                }
+                "--separate-before-non-alphabets" => opts.separate_before_non_alphabets = true,
+                "--no-separate-before-non-alphabets" => opts.separate_before_non_alphabets = false,
+                "--separate-after-non-alphabets" => opts.separate_after_non_alphabets = true,
+                "--no-separate-after-non-alphabets" => opts.separate_after_non_alphabets = false,
+                "--unicode" => opts.unicode = true,
+                "--no-unicode" => opts.unicode = false,
+                "--uppercase-acronyms" => opts.uppercase_acronyms = true,
+                "--json" => parsed.json = true,
+                "--files" => parsed.rename.files = true,
+                "--dirs" => parsed.rename.dirs = true,
+                "--hidden" => parsed.rename.hidden = true,
+                "-n" | "--dry-run" => parsed.rename.dry_run = true,
+                "--strings" => parsed.codemod.strings = true,
+                "--comments" => parsed.codemod.comments = true,
+                "--write" => parsed.codemod.write = true,
+                "-h" | "--help" => parsed.command = Command::Help,
+                "-V" | "--version" => parsed.command = Command::Version,
+                _ => return error(format!("unknown option: {}", flag)),
+            }
+        }
+
+        let (cmd, json, rename, codemod) = match parsed.command {
+            Command::Help | Command::Version => return Ok(parsed),
+            Command::Convert(_) => ("convert", false, false, false),
+            Command::Variants => ("variants", true, false, false),
+            Command::Detect => ("detect", true, false, false),
+            Command::Rename(_) => ("rename", false, true, false),
+            Command::Codemod(_) => ("codemod", false, false, true),
+        };
+        if parsed.json && !json {
+            return error(format!("`--json` is not available for `{}`", cmd));
+        }
+        if let Some(flag) = parsed.rename.first_flag().filter(|_| !rename) {
+            return error(format!("`{}` is only available for `rename`", flag));
+        }
+        if let Some(flag) = parsed.codemod.first_flag().filter(|_| !codemod) {
+            return error(format!("`{}` is only available for `codemod`", flag));
+        }
+        if parsed.inputs.is_empty() && rename {
+            return error("`rename` requires <DIR>".to_string());
+        }
+        if parsed.inputs.is_empty() && codemod {
+            return error("`codemod` requires <FILE>".to_string());
+        }
+        Ok(parsed)
+    }
+}
+
+// Parses the argument <CASE> of a command or a flag.
+fn parse_case(cmd: &str, arg: Option<String>) -> Result<Case, ArgError> {
+    match arg {
+        Some(name) => name.parse().or_else(|err| error(format!("{}", err))),
+        None => error(format!("`{}` requires <CASE>", cmd)),
+    }
+}
+
+#[cfg(test)]
+mod tests_of_args {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Args, ArgError> {
+        Args::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_of_help_and_version() {
+        assert_eq!(parse(&[]).unwrap().command, Command::Help);
+        assert_eq!(parse(&["--help"]).unwrap().command, Command::Help);
+        assert_eq!(parse(&["help"]).unwrap().command, Command::Help);
+        assert_eq!(parse(&["-V"]).unwrap().command, Command::Version);
+        assert_eq!(parse(&["detect", "--help"]).unwrap().command, Command::Help);
+    }
+
+    #[test]
+    fn test_of_convert() {
+        let args = parse(&["convert", "snake", "fooBar", "bazQux"]).unwrap();
+        assert_eq!(args.command, Command::Convert(Case::Snake));
+        assert_eq!(args.inputs, ["fooBar", "bazQux"]);
+        assert_eq!(args.opts, OptionArgs::default());
+        assert!(!args.json);
+
+        let args = parse(&["convert", "Title Case"]).unwrap();
+        assert_eq!(args.command, Command::Convert(Case::Title));
+        assert!(args.inputs.is_empty());
+    }
+
+    #[test]
+    fn test_of_options() {
+        let args = parse(&[
+            "variants",
+            "--separate-before-non-alphabets",
+            "--no-separate-after-non-alphabets",
+            "--separators",
+            "-",
+            "--keep=.",
+            "--unicode",
+            "--acronyms",
+            "HTTP, ID",
+            "--acronyms=XML",
+            "--uppercase-acronyms",
+            "--json",
+            "fooBar",
+        ])
+        .unwrap();
+        assert_eq!(args.command, Command::Variants);
+        assert!(args.json);
+        assert_eq!(args.inputs, ["fooBar"]);
+
+        let opts = &args.opts;
+        assert!(opts.separate_before_non_alphabets);
+        assert!(!opts.separate_after_non_alphabets);
+        assert_eq!(opts.separators, "-");
+        assert_eq!(opts.keep, ".");
+        assert!(opts.unicode);
+        assert_eq!(opts.acronyms, ["HTTP", "ID", "XML"]);
+        assert!(opts.uppercase_acronyms);
+        assert!(opts.uppercase_acronyms_in.is_empty());
+
+        let acronyms: Vec<&str> = opts.acronyms.iter().map(String::as_str).collect();
+        let options = opts.to_options(&acronyms);
+        assert_eq!(options.separators, "-");
+        assert_eq!(options.acronyms, ["HTTP", "ID", "XML"]);
+    }
+
+    #[test]
+    fn test_of_uppercase_acronyms_in() {
+        let args = parse(&["variants", "--uppercase-acronyms-in", "pascal, camel"]).unwrap();
+        assert!(args.opts.uppercase_acronyms);
+        assert_eq!(args.opts.uppercase_acronyms_in, [Case::Pascal, Case::Camel]);
+
+        let err = parse(&["variants", "--uppercase-acronyms-in=pascal,foo"]).unwrap_err();
+        assert_eq!(err.to_string(), "unknown case style: \"foo\"");
+    }
+
+    #[test]
+    fn test_of_inputs_after_double_hyphen() {
+        let args = parse(&["detect", "--", "--json", "-x"]).unwrap();
+        assert_eq!(args.command, Command::Detect);
+        assert!(!args.json);
+        assert_eq!(args.inputs, ["--json", "-x"]);
+    }
+
+    #[test]
+    fn fail_to_parse_invalid_args() {
+        let msg = |args: &[&str]| parse(args).unwrap_err().to_string();
+        assert_eq!(msg(&["move"]), "unknown command: \"move\"");
+        assert_eq!(msg(&["convert"]), "`convert` requires <CASE>");
+        assert_eq!(msg(&["convert", "foo"]), "unknown case style: \"foo\"");
+        assert_eq!(msg(&["detect", "--foo"]), "unknown option: --foo");
+        assert_eq!(msg(&["detect", "--keep"]), "`--keep` requires a value");
+        assert_eq!(
+            msg(&["detect", "--json=yes"]),
+            "`--json` does not take a value"
+        );
+        assert_eq!(
+            msg(&["convert", "snake", "--json"]),
+            "`--json` is not available for `convert`"
+        );
+        assert_eq!(
+            msg(&["rename", "kebab", "--json", "."]),
+            "`--json` is not available for `rename`"
+        );
+        assert_eq!(msg(&["rename"]), "`rename` requires <CASE>");
+        assert_eq!(msg(&["rename", "kebab"]), "`rename` requires <DIR>");
+        assert_eq!(
+            msg(&["detect", "--dry-run"]),
+            "`--dry-run` is only available for `rename`"
+        );
+        assert_eq!(
+            msg(&["convert", "snake", "--undo=undo.sh"]),
+            "`--undo` is only available for `rename`"
+        );
+    }
+
+    #[test]
+    fn test_of_codemod() {
+        let args = parse(&["codemod", "snake", "src/main.rs"]).unwrap();
+        assert_eq!(args.command, Command::Codemod(Case::Snake));
+        assert_eq!(args.codemod, CodemodArgs::default());
+        assert_eq!(args.inputs, ["src/main.rs"]);
+
+        let args = parse(&[
+            "codemod",
+            "snake",
+            "--lang=ts",
+            "--pattern",
+            "user*",
+            "--from",
+            "camel",
+            "--strings",
+            "--comments",
+            "--write",
+            "a.js",
+            "b.js",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.codemod,
+            CodemodArgs {
+                language: Some(Language::TypeScript),
+                pattern: Some("user*".to_string()),
+                from: Some(Case::Camel),
+                strings: true,
+                comments: true,
+                write: true,
+            }
+        );
+        assert_eq!(args.inputs, ["a.js", "b.js"]);
+
+        let msg = |args: &[&str]| parse(args).unwrap_err().to_string();
+        assert_eq!(msg(&["codemod", "snake"]), "`codemod` requires <FILE>");
+        assert_eq!(
+            msg(&["codemod", "snake", "--lang", "c", "a.c"]),
+            "unknown language: \"c\""
+        );
+        assert_eq!(
+            msg(&["codemod", "snake", "--from", "upper", "a.rs"]),
+            "unknown case style: \"upper\""
+        );
+        assert_eq!(
+            msg(&["rename", "snake", "--write", "."]),
+            "`--write` is only available for `codemod`"
+        );
+        assert_eq!(
+            msg(&["codemod", "snake", "--undo", "u.sh", "a.rs"]),
+            "`--undo` is only available for `rename`"
+        );
+    }
+
+    #[test]
+    fn test_of_rename() {
+        let args = parse(&["rename", "kebab", "assets", "images"]).unwrap();
+        assert_eq!(args.command, Command::Rename(Case::Kebab));
+        assert_eq!(args.rename, RenameArgs::default());
+        assert_eq!(args.inputs, ["assets", "images"]);
+
+        let args = parse(&[
+            "rename", "snake", "--files", "--dirs", "--hidden", "-n", "--undo", "undo.sh",
+            "--keep", ".", "assets",
+        ])
+        .unwrap();
+        assert_eq!(args.command, Command::Rename(Case::Snake));
+        assert_eq!(
+            args.rename,
+            RenameArgs {
+                files: true,
+                dirs: true,
+                hidden: true,
+                dry_run: true,
+                undo: Some("undo.sh".to_string()),
+            }
+        );
+        assert_eq!(args.opts.keep, ".");
+        assert_eq!(args.inputs, ["assets"]);
+    }
+}
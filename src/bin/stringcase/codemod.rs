@@ -0,0 +1,94 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::args::CodemodArgs;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use stringcase::codemod::{Codemod, Language};
+use stringcase::{Case, Options};
+
+// Returns the language of a file, which is specified with `--lang` or detected from its extension.
+fn language_of(file: &str, args: &CodemodArgs) -> io::Result<Language> {
+    let ext = Path::new(file).extension().and_then(|ext| ext.to_str());
+    match args
+        .language
+        .or_else(|| ext.and_then(Language::from_extension))
+    {
+        Some(language) => Ok(language),
+        None => Err(io::Error::new(
+            io::ErrorKind::Other,
+            format!(
+                "cannot detect the language of {:?}, so specify it with --lang",
+                file
+            ),
+        )),
+    }
+}
+
+// Adds the path of a file to the message of an I/O error.
+fn with_path(file: &str) -> impl Fn(io::Error) -> io::Error + '_ {
+    move |err| io::Error::new(err.kind(), format!("{}: {}", file, err))
+}
+
+/// Runs the command `codemod`, and returns the exit status.
+pub(crate) fn run<W: Write>(
+    case: Case,
+    opts: &Options,
+    args: &CodemodArgs,
+    files: &[String],
+    out: &mut W,
+) -> io::Result<i32> {
+    // The languages of all files are checked before any file is changed.
+    let languages = files
+        .iter()
+        .map(|file| language_of(file, args))
+        .collect::<io::Result<Vec<Language>>>()?;
+
+    for (file, language) in files.iter().zip(languages) {
+        let source = fs::read_to_string(file).map_err(with_path(file))?;
+        let mut codemod = Codemod::with_options(language, case, opts)
+            .strings(args.strings)
+            .comments(args.comments);
+        if let Some(pattern) = &args.pattern {
+            codemod = codemod.pattern(pattern);
+        }
+        if let Some(from) = args.from {
+            codemod = codemod.from_case(from);
+        }
+
+        let rewrite = codemod.rewrite(&source);
+        if !rewrite.is_changed() {
+            continue;
+        }
+        out.write_all(rewrite.unified_diff(file).as_bytes())?;
+        if args.write {
+            fs::write(file, rewrite.output()).map_err(with_path(file))?;
+        }
+    }
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests_of_codemod {
+    use super::*;
+
+    #[test]
+    fn test_of_language_of() {
+        let args = CodemodArgs::default();
+        assert_eq!(language_of("src/main.rs", &args).unwrap(), Language::Rust);
+        assert_eq!(language_of("app.tsx", &args).unwrap(), Language::TypeScript);
+        assert_eq!(
+            language_of("Makefile", &args).unwrap_err().to_string(),
+            "cannot detect the language of \"Makefile\", so specify it with --lang"
+        );
+
+        let args = CodemodArgs {
+            language: Some(Language::Python),
+            ..Default::default()
+        };
+        assert_eq!(language_of("script", &args).unwrap(), Language::Python);
+        assert_eq!(language_of("main.rs", &args).unwrap(), Language::Python);
+    }
+}
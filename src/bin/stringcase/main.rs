@@ -0,0 +1,105 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+//! The command-line tool `stringcase`, which converts arguments or lines of the standard input
+//! into case styles, prints them in all case styles, detects their case styles, renames files and
+//! directories into case styles, and renames identifiers in source files into case styles.
+
+mod args;
+mod codemod;
+mod output;
+mod rename;
+
+use args::{Args, Command, USAGE};
+use output::Printer;
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+use stringcase::{convert, detect_case_with_options};
+
+fn main() {
+    let args: Result<Vec<String>, _> = env::args_os().skip(1).map(|a| a.into_string()).collect();
+    let args = match args {
+        Ok(args) => Args::parse(args),
+        Err(arg) => {
+            eprintln!("stringcase: invalid UTF-8 argument: {:?}", arg);
+            process::exit(2);
+        }
+    };
+    let args = match args {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("stringcase: {}", err);
+            eprintln!("Try `stringcase --help` for more information.");
+            process::exit(2);
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    let code = match run(&args, &mut out).and_then(|code| out.flush().map(|_| code)) {
+        Ok(code) => code,
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => 0,
+        Err(err) => {
+            eprintln!("stringcase: {}", err);
+            1
+        }
+    };
+    process::exit(code);
+}
+
+// Runs the command, and returns the exit status.
+fn run<W: Write>(args: &Args, out: &mut W) -> io::Result<i32> {
+    let acronyms: Vec<&str> = args.opts.acronyms.iter().map(String::as_str).collect();
+    let opts = args.opts.to_options(&acronyms);
+    let mut printer = Printer::new(args.json);
+    let mut code = 0;
+
+    match args.command {
+        Command::Help => out.write_all(USAGE.as_bytes())?,
+        Command::Version => writeln!(out, "stringcase {}", env!("CARGO_PKG_VERSION"))?,
+        Command::Convert(case) => for_each_input(&args.inputs, |input| {
+            writeln!(out, "{}", convert(input, case, &opts))
+        })?,
+        Command::Variants => {
+            for_each_input(&args.inputs, |input| printer.variants(out, input, &opts))?
+        }
+        Command::Detect => for_each_input(&args.inputs, |input| {
+            let case = detect_case_with_options(input, &opts);
+            if case.is_none() {
+                code = 1;
+            }
+            printer.detection(out, input, case)
+        })?,
+        Command::Rename(case) => {
+            code = rename::run(case, &opts, &args.rename, &args.inputs, out)?;
+        }
+        Command::Codemod(case) => {
+            code = codemod::run(case, &opts, &args.codemod, &args.inputs, out)?;
+        }
+    }
+    printer.finish(out)?;
+    Ok(code)
+}
+
+// Calls `f` with each argument, or each line of the standard input if no argument is given.
+fn for_each_input<F>(inputs: &[String], mut f: F) -> io::Result<()>
+where
+    F: FnMut(&str) -> io::Result<()>,
+{
+    if !inputs.is_empty() {
+        return inputs.iter().try_for_each(|input| f(input));
+    }
+    let stdin = io::stdin();
+    let mut reader = stdin.lock();
+    let mut line = String::new();
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(());
+        }
+        let input = line.strip_suffix('\n').unwrap_or(&line);
+        f(input.strip_suffix('\r').unwrap_or(input))?;
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use std::io::{self, Write};
+use stringcase::{all_cases, Case, Options};
+
+// The width of the column of case names in a table, which fits the longest name `COBOL-CASE`.
+const NAME_WIDTH: usize = 12;
+
+/// Writes the results for inputs as plain text or as a JSON array.
+///
+/// Since inputs may be read from the standard input, the results are written one by one, and
+/// `finish` must be called after the last result to close a JSON array.
+pub(crate) struct Printer {
+    json: bool,
+    count: usize,
+}
+
+impl Printer {
+    pub(crate) fn new(json: bool) -> Self {
+        Self { json, count: 0 }
+    }
+
+    /// Writes an input in all case styles.
+    pub(crate) fn variants<W: Write>(
+        &mut self,
+        out: &mut W,
+        input: &str,
+        opts: &Options,
+    ) -> io::Result<()> {
+        let cases = all_cases(input, opts);
+        if self.json {
+            self.begin_item(out)?;
+            out.write_all(b"{\"input\": ")?;
+            write_json_string(out, input)?;
+            out.write_all(b", \"variants\": {")?;
+            for (i, (case, converted)) in cases.iter().enumerate() {
+                if i > 0 {
+                    out.write_all(b", ")?;
+                }
+                write_json_string(out, case.name())?;
+                out.write_all(b": ")?;
+                write_json_string(out, converted)?;
+            }
+            out.write_all(b"}}")
+        } else {
+            if self.count > 0 {
+                writeln!(out)?;
+            }
+            self.count += 1;
+            for (case, converted) in cases.iter() {
+                writeln!(
+                    out,
+                    "{:<width$}{}",
+                    case.name(),
+                    converted,
+                    width = NAME_WIDTH
+                )?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Writes the detected case style of an input.
+    pub(crate) fn detection<W: Write>(
+        &mut self,
+        out: &mut W,
+        input: &str,
+        case: Option<Case>,
+    ) -> io::Result<()> {
+        if self.json {
+            self.begin_item(out)?;
+            out.write_all(b"{\"input\": ")?;
+            write_json_string(out, input)?;
+            out.write_all(b", \"case\": ")?;
+            match case {
+                Some(case) => write_json_string(out, case.name())?,
+                None => out.write_all(b"null")?,
+            }
+            out.write_all(b"}")
+        } else {
+            self.count += 1;
+            match case {
+                Some(case) => writeln!(out, "{}", case.name()),
+                None => writeln!(out, "unknown"),
+            }
+        }
+    }
+
+    /// Finishes writing the results.
+    pub(crate) fn finish<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        if !self.json {
+            return Ok(());
+        }
+        if self.count == 0 {
+            out.write_all(b"[]\n")
+        } else {
+            out.write_all(b"\n]\n")
+        }
+    }
+
+    fn begin_item<W: Write>(&mut self, out: &mut W) -> io::Result<()> {
+        let sep: &[u8] = if self.count == 0 { b"[\n  " } else { b",\n  " };
+        self.count += 1;
+        out.write_all(sep)
+    }
+}
+
+/// Writes a string as a JSON string literal.
+pub(crate) fn write_json_string<W: Write>(out: &mut W, s: &str) -> io::Result<()> {
+    out.write_all(b"\"")?;
+    let mut start = 0;
+    for (i, ch) in s.char_indices() {
+        let escaped = match ch {
+            '"' => "\\\"",
+            '\\' => "\\\\",
+            '\n' => "\\n",
+            '\r' => "\\r",
+            '\t' => "\\t",
+            ch if (ch as u32) < 0x20 => "",
+            _ => continue,
+        };
+        out.write_all(&s.as_bytes()[start..i])?;
+        if escaped.is_empty() {
+            write!(out, "\\u{:04x}", ch as u32)?;
+        } else {
+            out.write_all(escaped.as_bytes())?;
+        }
+        start = i + ch.len_utf8();
+    }
+    out.write_all(&s.as_bytes()[start..])?;
+    out.write_all(b"\"")
+}
+
+#[cfg(test)]
+mod tests_of_output {
+    use super::*;
+
+    fn json_string(s: &str) -> String {
+        let mut out = Vec::new();
+        write_json_string(&mut out, s).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_of_write_json_string() {
+        assert_eq!(json_string(""), "\"\"");
+        assert_eq!(json_string("fooBar"), "\"fooBar\"");
+        assert_eq!(json_string("a\"b\\c"), "\"a\\\"b\\\\c\"");
+        assert_eq!(json_string("a\tb\nc\r"), "\"a\\tb\\nc\\r\"");
+        assert_eq!(json_string("\u{1}é"), "\"\\u0001é\"");
+    }
+
+    #[test]
+    fn test_of_variants_as_table() {
+        let opts = Options::default();
+        let mut out = Vec::new();
+        let mut printer = Printer::new(false);
+        printer.variants(&mut out, "fooBar", &opts).unwrap();
+        printer.variants(&mut out, "baz", &opts).unwrap();
+        printer.finish(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\
+Ada_Case    Foo_Bar
+camelCase   fooBar
+COBOL-CASE  FOO-BAR
+kebab-case  foo-bar
+MACRO_CASE  FOO_BAR
+PascalCase  FooBar
+snake_case  foo_bar
+Title Case  Foo Bar
+Train-Case  Foo-Bar
+
+Ada_Case    Baz
+camelCase   baz
+COBOL-CASE  BAZ
+kebab-case  baz
+MACRO_CASE  BAZ
+PascalCase  Baz
+snake_case  baz
+Title Case  Baz
+Train-Case  Baz
+"
+        );
+    }
+
+    #[test]
+    fn test_of_variants_as_json() {
+        let opts = Options::default();
+        let mut out = Vec::new();
+        let mut printer = Printer::new(true);
+        printer.variants(&mut out, "a\"b", &opts).unwrap();
+        printer.finish(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[\n  {\"input\": \"a\\\"b\", \"variants\": {\
+\"Ada_Case\": \"A_B\", \"camelCase\": \"aB\", \"COBOL-CASE\": \"A-B\", \
+\"kebab-case\": \"a-b\", \"MACRO_CASE\": \"A_B\", \"PascalCase\": \"AB\", \
+\"snake_case\": \"a_b\", \"Title Case\": \"A B\", \"Train-Case\": \"A-B\"}}\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_of_detection() {
+        let mut out = Vec::new();
+        let mut printer = Printer::new(false);
+        printer
+            .detection(&mut out, "foo_bar", Some(Case::Snake))
+            .unwrap();
+        printer.detection(&mut out, "foo_Bar", None).unwrap();
+        printer.finish(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "snake_case\nunknown\n");
+
+        let mut out = Vec::new();
+        let mut printer = Printer::new(true);
+        printer
+            .detection(&mut out, "foo_bar", Some(Case::Snake))
+            .unwrap();
+        printer.detection(&mut out, "foo_Bar", None).unwrap();
+        printer.finish(&mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "[\n  {\"input\": \"foo_bar\", \"case\": \"snake_case\"},\n  \
+{\"input\": \"foo_Bar\", \"case\": null}\n]\n"
+        );
+
+        let mut out = Vec::new();
+        Printer::new(true).finish(&mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "[]\n");
+    }
+}
@@ -0,0 +1,474 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::args::RenameArgs;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use stringcase::{convert, Case, Options};
+
+/// A rename of a file or a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Rename {
+    pub(crate) from: PathBuf,
+    pub(crate) to: PathBuf,
+}
+
+/// A new name which collides with other new names or an existing name in the same directory.
+///
+/// Names are compared ignoring letter cases, because they collide on case-insensitive file
+/// systems.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Collision {
+    pub(crate) to: PathBuf,
+    pub(crate) from: Vec<PathBuf>,
+    pub(crate) existing: Option<PathBuf>,
+}
+
+impl fmt::Display for Collision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} would be the new name of ", self.to)?;
+        for (i, from) in self.from.iter().enumerate() {
+            if i > 0 {
+                f.write_str(if i + 1 == self.from.len() {
+                    " and "
+                } else {
+                    ", "
+                })?;
+            }
+            write!(f, "{:?}", from)?;
+        }
+        if let Some(existing) = &self.existing {
+            write!(f, ", but {:?} exists", existing)?;
+        }
+        Ok(())
+    }
+}
+
+/// The renames in directory trees, which are ordered so that the entries in a directory are
+/// renamed before the directory itself.
+#[derive(Debug, Default)]
+pub(crate) struct Plan {
+    pub(crate) renames: Vec<Rename>,
+    pub(crate) collisions: Vec<Collision>,
+    pub(crate) skipped: Vec<PathBuf>,
+}
+
+/// Plans the renames of files and directories into a case style.
+pub(crate) struct Planner<'a> {
+    case: Case,
+    opts: &'a Options<'a>,
+    files: bool,
+    dirs: bool,
+    hidden: bool,
+}
+
+// An entry of a directory, and its new name if it is renamed.
+struct Entry {
+    name: String,
+    new_name: Option<String>,
+}
+
+impl<'a> Planner<'a> {
+    pub(crate) fn new(case: Case, opts: &'a Options<'a>, args: &RenameArgs) -> Self {
+        let both = args.files == args.dirs;
+        Self {
+            case,
+            opts,
+            files: both || args.files,
+            dirs: both || args.dirs,
+            hidden: args.hidden,
+        }
+    }
+
+    /// Returns the new name of a file or a directory.
+    ///
+    /// The leading `.` of a hidden name, and the extension of a file, which is the part after the
+    /// last `.` of its name, are kept as they are. If the name would be empty, it is not changed.
+    pub(crate) fn new_name(&self, name: &str, is_dir: bool) -> String {
+        let (dot, rest) = name.split_at(if name.starts_with('.') { 1 } else { 0 });
+        let (stem, ext) = match rest.rfind('.') {
+            Some(i) if !is_dir && i > 0 => rest.split_at(i),
+            _ => (rest, ""),
+        };
+        let stem = convert(stem, self.case, self.opts);
+        if stem.is_empty() {
+            return name.to_string();
+        }
+        format!("{}{}{}", dot, stem, ext)
+    }
+
+    /// Plans the renames of the entries in the trees of the specified directories, excluding
+    /// these directories themselves.
+    pub(crate) fn plan(&self, roots: &[PathBuf]) -> io::Result<Plan> {
+        let mut plan = Plan::default();
+        for root in roots {
+            if !fs::metadata(root)?.is_dir() {
+                return Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("{:?} is not a directory", root),
+                ));
+            }
+            self.walk(root, &mut plan)?;
+        }
+        Ok(plan)
+    }
+
+    fn walk(&self, dir: &Path, plan: &mut Plan) -> io::Result<()> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            // Symbolic links are renamed as files, and are not followed.
+            let is_dir = entry.file_type()?.is_dir();
+            let name = match entry.file_name().into_string() {
+                Ok(name) => name,
+                Err(_) => {
+                    plan.skipped.push(entry.path());
+                    continue;
+                }
+            };
+            if name.starts_with('.') && !self.hidden {
+                entries.push(Entry {
+                    name,
+                    new_name: None,
+                });
+                continue;
+            }
+            if is_dir {
+                self.walk(&entry.path(), plan)?;
+            }
+            let new_name = if (is_dir && self.dirs) || (!is_dir && self.files) {
+                Some(self.new_name(&name, is_dir)).filter(|n| *n != name)
+            } else {
+                None
+            };
+            entries.push(Entry { name, new_name });
+        }
+        entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut names: BTreeMap<String, Vec<&Entry>> = BTreeMap::new();
+        for entry in &entries {
+            let name = entry.new_name.as_ref().unwrap_or(&entry.name);
+            names.entry(name.to_lowercase()).or_default().push(entry);
+        }
+        for group in names.values() {
+            if group.len() < 2 || group.iter().all(|e| e.new_name.is_none()) {
+                continue;
+            }
+            let renamed = group.iter().find_map(|e| e.new_name.as_ref()).unwrap();
+            plan.collisions.push(Collision {
+                to: dir.join(renamed),
+                from: group
+                    .iter()
+                    .filter(|e| e.new_name.is_some())
+                    .map(|e| dir.join(&e.name))
+                    .collect(),
+                existing: group
+                    .iter()
+                    .find(|e| e.new_name.is_none())
+                    .map(|e| dir.join(&e.name)),
+            });
+        }
+
+        for entry in &entries {
+            if let Some(new_name) = &entry.new_name {
+                plan.renames.push(Rename {
+                    from: dir.join(&entry.name),
+                    to: dir.join(new_name),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renames files and directories in the order of the plan, and returns the number of the renames
+/// which are done.
+///
+/// An existing file or directory is never overwritten, except that a name which differs only in
+/// letter cases is renamed in place.
+pub(crate) fn execute(renames: &[Rename]) -> (usize, io::Result<()>) {
+    for (i, rename) in renames.iter().enumerate() {
+        let case_only = match (rename.from.file_name(), rename.to.file_name()) {
+            (Some(from), Some(to)) => {
+                from.to_string_lossy().to_lowercase() == to.to_string_lossy().to_lowercase()
+            }
+            _ => false,
+        };
+        if !case_only && fs::symlink_metadata(&rename.to).is_ok() {
+            let msg = format!("{:?} already exists", rename.to);
+            return (i, Err(io::Error::new(io::ErrorKind::AlreadyExists, msg)));
+        }
+        if let Err(err) = fs::rename(&rename.from, &rename.to) {
+            let msg = format!("failed to rename {:?}: {}", rename.from, err);
+            return (i, Err(io::Error::new(err.kind(), msg)));
+        }
+    }
+    (renames.len(), Ok(()))
+}
+
+/// Writes a POSIX shell script which reverts the renames.
+pub(crate) fn write_undo_script<W: Write>(out: &mut W, renames: &[Rename]) -> io::Result<()> {
+    out.write_all(b"#!/bin/sh\n")?;
+    out.write_all(
+        b"# Reverts the renames by `stringcase rename` in the same working directory.\n",
+    )?;
+    out.write_all(b"set -e\n")?;
+    for rename in renames.iter().rev() {
+        writeln!(
+            out,
+            "mv -- {} {}",
+            shell_quote(&rename.to),
+            shell_quote(&rename.from)
+        )?;
+    }
+    Ok(())
+}
+
+// Quotes a path with single quotes for a POSIX shell.
+fn shell_quote(path: &Path) -> String {
+    format!("'{}'", path.to_string_lossy().replace('\'', "'\\''"))
+}
+
+/// Runs the command `rename`, and returns the exit status.
+pub(crate) fn run<W: Write>(
+    case: Case,
+    opts: &Options,
+    args: &RenameArgs,
+    dirs: &[String],
+    out: &mut W,
+) -> io::Result<i32> {
+    let roots: Vec<PathBuf> = dirs.iter().map(PathBuf::from).collect();
+    let plan = Planner::new(case, opts, args).plan(&roots)?;
+
+    for path in &plan.skipped {
+        eprintln!("stringcase: skipped a name which is not UTF-8: {:?}", path);
+    }
+    if !plan.collisions.is_empty() {
+        for collision in &plan.collisions {
+            eprintln!("stringcase: {}", collision);
+        }
+        eprintln!("stringcase: nothing is renamed because of the collisions");
+        return Ok(1);
+    }
+
+    for rename in &plan.renames {
+        writeln!(out, "{} -> {}", rename.from.display(), rename.to.display())?;
+    }
+    if args.dry_run {
+        return Ok(0);
+    }
+
+    // The undo script is created before renaming, so that nothing is renamed if it cannot be
+    // written, and it is written even if renaming fails on the way.
+    let mut undo_file = match &args.undo {
+        Some(undo) => Some(io::BufWriter::new(fs::File::create(undo)?)),
+        None => None,
+    };
+    let (done, result) = execute(&plan.renames);
+    let undo_result = match &mut undo_file {
+        Some(file) => write_undo_script(file, &plan.renames[..done]).and_then(|_| file.flush()),
+        None => Ok(()),
+    };
+    result?;
+    undo_result.map(|_| 0)
+}
+
+#[cfg(test)]
+mod tests_of_rename {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Creates an empty temporary directory which is unique in the test process.
+    fn temp_dir(name: &str) -> PathBuf {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "stringcase-rename-{}-{}-{}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::SeqCst),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn touch(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, b"").unwrap();
+    }
+
+    fn planner<'a>(case: Case, opts: &'a Options<'a>, args: &RenameArgs) -> Planner<'a> {
+        Planner::new(case, opts, args)
+    }
+
+    #[test]
+    fn test_of_new_name() {
+        let opts = Options::default();
+        let p = planner(Case::Kebab, &opts, &RenameArgs::default());
+        assert_eq!(p.new_name("My Image File.PNG", false), "my-image-file.PNG");
+        assert_eq!(p.new_name("archive.tar.gz", false), "archive-tar.gz");
+        assert_eq!(p.new_name("README", false), "readme");
+        assert_eq!(p.new_name(".env", false), ".env");
+        assert_eq!(p.new_name(".My Config.json", false), ".my-config.json");
+        assert_eq!(p.new_name("My Photos.2024", true), "my-photos-2024");
+        assert_eq!(p.new_name("!!!.txt", false), "!!!.txt");
+    }
+
+    #[test]
+    fn test_of_plan() {
+        let dir = temp_dir("plan");
+        touch(&dir.join("My Images/Blue Sky.PNG"));
+        touch(&dir.join("My Images/red-car.jpg"));
+        touch(&dir.join("Read Me.md"));
+        touch(&dir.join(".My Cache/Data File"));
+
+        let opts = Options::default();
+        let p = planner(Case::Kebab, &opts, &RenameArgs::default());
+        let plan = p.plan(std::slice::from_ref(&dir)).unwrap();
+        assert!(plan.collisions.is_empty());
+        assert_eq!(
+            plan.renames,
+            [
+                Rename {
+                    from: dir.join("My Images/Blue Sky.PNG"),
+                    to: dir.join("My Images/blue-sky.PNG"),
+                },
+                Rename {
+                    from: dir.join("My Images"),
+                    to: dir.join("my-images"),
+                },
+                Rename {
+                    from: dir.join("Read Me.md"),
+                    to: dir.join("read-me.md"),
+                },
+            ]
+        );
+
+        let args = RenameArgs {
+            dirs: true,
+            hidden: true,
+            ..Default::default()
+        };
+        let plan = planner(Case::Kebab, &opts, &args)
+            .plan(std::slice::from_ref(&dir))
+            .unwrap();
+        assert_eq!(
+            plan.renames,
+            [
+                Rename {
+                    from: dir.join(".My Cache"),
+                    to: dir.join(".my-cache"),
+                },
+                Rename {
+                    from: dir.join("My Images"),
+                    to: dir.join("my-images"),
+                },
+            ]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_of_collisions() {
+        let dir = temp_dir("collisions");
+        touch(&dir.join("Foo Bar.txt"));
+        touch(&dir.join("foo_bar.txt"));
+        touch(&dir.join("foo-bar.TXT"));
+        touch(&dir.join("Baz.txt"));
+        touch(&dir.join("QUX.txt"));
+        touch(&dir.join("Qux.txt"));
+
+        let opts = Options::default();
+        let p = planner(Case::Kebab, &opts, &RenameArgs::default());
+        let plan = p.plan(std::slice::from_ref(&dir)).unwrap();
+        assert_eq!(
+            plan.collisions,
+            [
+                Collision {
+                    to: dir.join("foo-bar.txt"),
+                    from: vec![dir.join("Foo Bar.txt"), dir.join("foo_bar.txt")],
+                    existing: Some(dir.join("foo-bar.TXT")),
+                },
+                Collision {
+                    to: dir.join("qux.txt"),
+                    from: vec![dir.join("QUX.txt"), dir.join("Qux.txt")],
+                    existing: None,
+                },
+            ]
+        );
+        assert_eq!(
+            plan.collisions[0].to_string(),
+            format!(
+                "{:?} would be the new name of {:?} and {:?}, but {:?} exists",
+                dir.join("foo-bar.txt"),
+                dir.join("Foo Bar.txt"),
+                dir.join("foo_bar.txt"),
+                dir.join("foo-bar.TXT"),
+            )
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_of_execute() {
+        let dir = temp_dir("execute");
+        touch(&dir.join("A Dir/B File.txt"));
+        touch(&dir.join("exists.txt"));
+
+        let renames = [
+            Rename {
+                from: dir.join("A Dir/B File.txt"),
+                to: dir.join("A Dir/b-file.txt"),
+            },
+            Rename {
+                from: dir.join("A Dir"),
+                to: dir.join("a-dir"),
+            },
+        ];
+        let (done, result) = execute(&renames);
+        assert_eq!(done, 2);
+        assert!(result.is_ok());
+        assert!(dir.join("a-dir/b-file.txt").is_file());
+
+        let renames = [Rename {
+            from: dir.join("a-dir/b-file.txt"),
+            to: dir.join("exists.txt"),
+        }];
+        let (done, result) = execute(&renames);
+        assert_eq!(done, 0);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        assert!(dir.join("a-dir/b-file.txt").is_file());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_of_write_undo_script() {
+        let renames = [
+            Rename {
+                from: PathBuf::from("a/Bob's File.txt"),
+                to: PathBuf::from("a/bob-s-file.txt"),
+            },
+            Rename {
+                from: PathBuf::from("a"),
+                to: PathBuf::from("A"),
+            },
+        ];
+        let mut out = Vec::new();
+        write_undo_script(&mut out, &renames).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "#!/bin/sh\n\
+# Reverts the renames by `stringcase rename` in the same working directory.\n\
+set -e\n\
+mv -- 'A' 'a'\n\
+mv -- 'a/bob-s-file.txt' 'a/Bob'\\''s File.txt'\n"
+        );
+    }
+}
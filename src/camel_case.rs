@@ -2,7 +2,14 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, CAMEL};
+#[cfg(feature = "alloc")]
+use crate::render::{render_cow, render_string};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
 /// Converts the input string to camel case with the specified options.
 ///
@@ -12,78 +19,14 @@ use crate::options::Options;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let camel = stringcase::camel_case_with_options("foo_bar_100_baz", &opts);
 ///     assert_eq!(camel, "fooBar100Baz");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn camel_case_with_options(input: &str, opts: &Options) -> String {
-    let mut result = String::with_capacity(input.len());
-    // .len returns byte count but ok in this case!
-
-    #[derive(PartialEq)]
-    enum ChIs {
-        FirstOfStr,
-        NextOfUpper,
-        NextOfContdUpper,
-        NextOfSepMark,
-        NextOfKeptMark,
-        Other,
-    }
-
-    let mut flag = ChIs::FirstOfStr;
-
-    for ch in input.chars() {
-        if ch.is_ascii_uppercase() {
-            if flag == ChIs::FirstOfStr {
-                result.push(ch.to_ascii_lowercase());
-                flag = ChIs::NextOfUpper;
-            } else if flag == ChIs::NextOfUpper
-                || flag == ChIs::NextOfContdUpper
-                || (!opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(ch.to_ascii_lowercase());
-                flag = ChIs::NextOfContdUpper;
-            } else {
-                result.push(ch);
-                flag = ChIs::NextOfUpper;
-            }
-        } else if ch.is_ascii_lowercase() {
-            if flag == ChIs::NextOfContdUpper {
-                if let Some(prev) = result.pop() {
-                    result.push(prev.to_ascii_uppercase());
-                    result.push(ch);
-                }
-            } else if flag == ChIs::NextOfSepMark
-                || (opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(ch.to_ascii_uppercase());
-            } else {
-                result.push(ch);
-            }
-            flag = ChIs::Other;
-        } else if ch.is_ascii_digit() {
-            result.push(ch);
-            flag = ChIs::NextOfKeptMark;
-        } else if !opts.separators.is_empty() {
-            if !opts.separators.contains(ch) {
-                result.push(ch);
-                flag = ChIs::NextOfKeptMark;
-            } else if flag != ChIs::FirstOfStr {
-                flag = ChIs::NextOfSepMark;
-            }
-        } else if !opts.keep.is_empty() {
-            if opts.keep.contains(ch) {
-                result.push(ch);
-                flag = ChIs::NextOfKeptMark;
-            } else if flag != ChIs::FirstOfStr {
-                flag = ChIs::NextOfSepMark;
-            }
-        } else if flag != ChIs::FirstOfStr {
-            flag = ChIs::NextOfSepMark;
-        }
-    }
-
-    result
+    render_string(input, opts, CAMEL)
 }
 
 /// Converts the input string to camel case.
@@ -95,6 +38,7 @@ pub fn camel_case_with_options(input: &str, opts: &Options) -> String {
 ///     let camel = stringcase::camel_case("foo_bar100_baz");
 ///     assert_eq!(camel, "fooBar100Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn camel_case(input: &str) -> String {
     let opts = Options {
@@ -102,11 +46,173 @@ pub fn camel_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     camel_case_with_options(input, &opts)
 }
 
+/// Converts the input string to camel case with the specified options, without allocating a new
+/// string if the input string is already in camel case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`camel_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       keep: "_",
+///       ..Default::default()
+///     };
+///     let camel = stringcase::camel_case_cow_with_options("foo_Bar100Baz", &opts);
+///     assert!(matches!(camel, Cow::Borrowed("foo_Bar100Baz")));
+///
+///     let camel = stringcase::camel_case_cow_with_options("foo_bar100_baz", &opts);
+///     assert!(matches!(camel, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn camel_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, CAMEL)
+}
+
+/// Converts the input string to camel case, without allocating a new string if the input string
+/// is already in camel case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`camel_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let camel = stringcase::camel_case_cow("fooBar100Baz");
+///     assert!(matches!(camel, Cow::Borrowed("fooBar100Baz")));
+///
+///     let camel = stringcase::camel_case_cow("foo_bar100_baz");
+///     assert_eq!(camel, stringcase::camel_case("foo_bar100_baz"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn camel_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, CAMEL)
+}
+
+/// Converts the input string to camel case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`camel_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::camel_case_into("foo_bar100_baz", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: fooBar100Baz");
+/// ```
+#[inline(always)]
+pub fn camel_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, CAMEL)
+}
+
+/// Converts the input string to camel case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`camel_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::camel_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+///     assert_eq!(result, Ok("fooBar100Baz"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::camel_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 12);
+/// ```
+#[inline(always)]
+pub fn camel_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, CAMEL)
+}
+
+/// A wrapper of a string slice which is displayed in camel case.
+///
+/// It converts the string while formatting it in the same way as [`camel_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::CamelCase;
+///
+///     let s = format!("name: {}", CamelCase("foo_bar100_baz"));
+///     assert_eq!(s, "name: fooBar100Baz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CamelCase<'a>(pub &'a str);
+
+impl fmt::Display for CamelCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, CAMEL)
+    }
+}
+
+/// Checks whether the input string is in camel case with the specified options.
+///
+/// It returns `true` if converting the input string with [`camel_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       keep: "_",
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_camel_case_with_options("foo_Bar100Baz", &opts));
+///     assert!(!stringcase::is_camel_case_with_options("foo_bar100_baz", &opts));
+/// ```
+#[inline(always)]
+pub fn is_camel_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, CAMEL)
+}
+
+/// Checks whether the input string is in camel case.
+///
+/// It returns `true` if converting the input string with [`camel_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_camel_case("fooBar100Baz"));
+///     assert!(!stringcase::is_camel_case("foo_bar100_baz"));
+/// ```
+#[inline(always)]
+pub fn is_camel_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, CAMEL)
+}
+
 /// Converts the input string to camel case with the specified separator characters.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use camel_case_with_options instead")]
 #[inline(always)]
@@ -116,11 +222,13 @@ pub fn camel_case_with_sep(input: &str, seps: &str) -> String {
         separate_after_non_alphabets: true,
         separators: seps,
         keep: "",
+        ..Default::default()
     };
     camel_case_with_options(input, &opts)
 }
 
 /// Converts the input string to camel case with the specified characters to be kept.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use camel_case_with_options instead")]
 #[inline(always)]
@@ -130,11 +238,12 @@ pub fn camel_case_with_keep(input: &str, kept: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: kept,
+        ..Default::default()
     };
     camel_case_with_options(input, &opts)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_camel_case {
     use super::*;
 
@@ -202,7 +311,153 @@ mod tests_of_camel_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_camel_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_camel_case() {
+        assert!(matches!(
+            camel_case_cow("fooBar100Baz"),
+            Cow::Borrowed("fooBar100Baz")
+        ));
+        assert!(matches!(camel_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_camel_case() {
+        let result = camel_case_cow("foo_bar100_baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "fooBar100Baz");
+
+        let result = camel_case_cow("fooBar100Baz_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, camel_case("fooBar100Baz_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["fooBar100Baz", "foo_bar100_baz", "fooBar100Baz_", ""].iter() {
+            let result = camel_case_cow_with_options(input, &opts);
+            assert_eq!(result, camel_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_camel_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        camel_case_into("foo_bar100_baz", &opts, &mut out).unwrap();
+        camel_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "fooBar100Baz");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        camel_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, camel_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(CamelCase("foo_bar100_baz").to_string(), "fooBar100Baz");
+        assert_eq!(format!("<{}>", CamelCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_camel_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = camel_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Ok("fooBar100Baz"));
+
+        let result = camel_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 12];
+        let result = camel_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Ok("fooBar100Baz"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 11];
+        let result = camel_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 12 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = camel_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(camel_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_camel_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_camel_case() {
+        assert!(is_camel_case("fooBar100Baz"));
+        assert!(is_camel_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_camel_case() {
+        assert!(!is_camel_case("FooBar"));
+        assert!(!is_camel_case("foo_bar"));
+        assert!(!is_camel_case("fooBAR"));
+        assert!(!is_camel_case("_fooBar100Baz"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_camel_case_with_options("fooBar100Baz", &opts));
+        assert!(is_camel_case_with_options("", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_camel_case_with_options {
     use super::*;
 
@@ -216,6 +471,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("AbcDefGHIjk", &opts), "abcDefGhIjk");
@@ -228,6 +484,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc_def_ghi", &opts), "abcDefGhi");
@@ -240,6 +497,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc-def-ghi", &opts), "abcDefGhi");
@@ -252,6 +510,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("Abc-Def-Ghi", &opts), "abcDefGhi");
@@ -264,6 +523,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC_DEF_GHI", &opts), "abcDefGhi");
@@ -276,6 +536,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC-DEF-GHI", &opts), "abcDefGhi");
@@ -288,6 +549,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -303,6 +565,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -318,6 +581,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -341,6 +605,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("", &opts), "");
@@ -357,6 +622,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abcDefGHIjk", &opts), "abcDefGhIjk");
@@ -369,6 +635,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("AbcDefGHIjk", &opts), "abcDefGhIjk");
@@ -381,6 +648,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc_def_ghi", &opts), "abcDefGhi");
@@ -393,6 +661,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc-def-ghi", &opts), "abcDefGhi");
@@ -405,6 +674,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("Abc-Def-Ghi", &opts), "abcDefGhi");
@@ -417,6 +687,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC_DEF_GHI", &opts), "abcDefGhi");
@@ -429,6 +700,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC-DEF-GHI", &opts), "abcDefGhi");
@@ -441,6 +713,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -456,6 +729,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -471,6 +745,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -494,6 +769,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("", &opts), "");
@@ -510,6 +786,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abcDefGHIjk", &opts), "abcDefGhIjk");
@@ -522,6 +799,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("AbcDefGHIjk", &opts), "abcDefGhIjk");
@@ -534,6 +812,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc_def_ghi", &opts), "abcDefGhi");
@@ -546,6 +825,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc-def-ghi", &opts), "abcDefGhi");
@@ -558,6 +838,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("Abc-Def-Ghi", &opts), "abcDefGhi");
@@ -570,6 +851,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC_DEF_GHI", &opts), "abcDefGhi");
@@ -582,6 +864,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC-DEF-GHI", &opts), "abcDefGhi");
@@ -594,6 +877,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -609,6 +893,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -624,6 +909,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -647,6 +933,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("", &opts), "");
@@ -663,6 +950,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abcDefGHIjk", &opts), "abcDefGhIjk");
@@ -675,6 +963,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("AbcDefGHIjk", &opts), "abcDefGhIjk");
@@ -687,6 +976,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc_def_ghi", &opts), "abcDefGhi");
@@ -699,6 +989,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc-def-ghi", &opts), "abcDefGhi");
@@ -711,6 +1002,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("Abc-Def-Ghi", &opts), "abcDefGhi");
@@ -723,6 +1015,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC_DEF_GHI", &opts), "abcDefGhi");
@@ -735,6 +1028,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC-DEF-GHI", &opts), "abcDefGhi");
@@ -747,6 +1041,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -762,6 +1057,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -777,6 +1073,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -800,6 +1097,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("", &opts), "");
@@ -816,6 +1114,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             assert_eq!(camel_case_with_options("abcDefGHIjk", &opts), "abcDefGhIjk");
         }
@@ -827,6 +1126,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             assert_eq!(camel_case_with_options("AbcDefGHIjk", &opts), "abcDefGhIjk");
         }
@@ -838,6 +1138,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc_def_ghi", &opts), "abcDefGhi");
@@ -853,6 +1154,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc-def-ghi", &opts), "abcDefGhi");
@@ -868,6 +1170,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("Abc-Def-Ghi", &opts), "abcDefGhi");
@@ -883,6 +1186,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC_DEF_GHI", &opts), "abcDefGhi");
@@ -898,6 +1202,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC-DEF-GHI", &opts), "abcDefGhi");
@@ -913,6 +1218,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -934,6 +1240,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -949,6 +1256,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -972,6 +1280,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("", &opts), "");
@@ -984,6 +1293,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc123def", &opts), "abc123def");
@@ -1000,6 +1310,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             assert_eq!(camel_case_with_options("abcDefGHIjk", &opts), "abcDefGhIjk");
         }
@@ -1011,6 +1322,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             assert_eq!(camel_case_with_options("AbcDefGHIjk", &opts), "abcDefGhIjk");
         }
@@ -1022,6 +1334,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc_def_ghi", &opts), "abcDefGhi");
@@ -1037,6 +1350,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc-def-ghi", &opts), "abcDefGhi");
@@ -1052,6 +1366,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("Abc-Def-Ghi", &opts), "abcDefGhi");
@@ -1067,6 +1382,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC_DEF_GHI", &opts), "abcDefGhi");
@@ -1082,6 +1398,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("ABC-DEF-GHI", &opts), "abcDefGhi");
@@ -1097,6 +1414,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -1118,6 +1436,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -1133,6 +1452,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(
@@ -1156,6 +1476,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("", &opts), "");
@@ -1168,6 +1489,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             assert_eq!(camel_case_with_options("abc123def", &opts), "abc123Def");
@@ -1184,6 +1506,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1199,6 +1522,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1214,6 +1538,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1233,6 +1558,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1252,6 +1578,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1271,6 +1598,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1291,6 +1619,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1310,6 +1639,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1329,6 +1659,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1344,6 +1675,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1365,6 +1697,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1380,6 +1713,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1399,6 +1733,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1414,6 +1749,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1429,6 +1765,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1448,6 +1785,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1467,6 +1805,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1486,6 +1825,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1505,6 +1845,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1524,6 +1865,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1543,6 +1885,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1558,6 +1901,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1579,6 +1923,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1594,6 +1939,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let mut opts = orig_opts;
@@ -1613,6 +1959,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -1625,6 +1972,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -1637,6 +1985,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1653,6 +2002,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1669,6 +2019,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1685,6 +2036,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1701,6 +2053,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1717,6 +2070,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123456defG89hiJklMn12");
@@ -1733,6 +2087,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = camel_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!Def#Ghi%JkLmNo?");
@@ -1745,6 +2100,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1763,6 +2119,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1779,6 +2136,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -1791,6 +2149,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -1803,6 +2162,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1819,6 +2179,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1835,6 +2196,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1851,6 +2213,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1867,6 +2230,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1883,6 +2247,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123456DefG89HiJklMn12");
@@ -1899,6 +2264,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = camel_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".Abc~!Def#Ghi%JkLmNo?");
@@ -1911,6 +2277,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123Abc456Def");
@@ -1929,6 +2296,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1945,6 +2313,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -1957,6 +2326,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -1969,6 +2339,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -1985,6 +2356,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2001,6 +2373,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2017,6 +2390,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2033,6 +2407,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2049,6 +2424,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123456DefG89HiJklMn12");
@@ -2065,6 +2441,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = camel_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".Abc~!Def#Ghi%JkLmNo?");
@@ -2077,6 +2454,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123Abc456Def");
@@ -2095,6 +2473,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -2111,6 +2490,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -2123,6 +2503,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abcDefGhIjk");
@@ -2135,6 +2516,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2151,6 +2533,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2167,6 +2550,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2183,6 +2567,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2199,6 +2584,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abcDefGhi");
@@ -2215,6 +2601,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = camel_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123456defG89hiJklMn12");
@@ -2231,6 +2618,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = camel_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!Def#Ghi%JkLmNo?");
@@ -2243,6 +2631,7 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -2261,9 +2650,94 @@ mod tests_of_camel_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = camel_case_with_options("", &opts);
             assert_eq!(result, "");
         }
     }
+
+    mod unicode_letters {
+        use super::*;
+
+        #[test]
+        fn convert_non_ascii_letters_as_separators_by_default() {
+            let opts = Options {
+                unicode: false,
+                ..Default::default()
+            };
+            let result = camel_case_with_options("straßeÜberweisung", &opts);
+            assert_eq!(result, "straEBerweisung");
+        }
+
+        #[test]
+        fn convert_non_ascii_letters() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = camel_case_with_options("straßeÜberweisung", &opts);
+            assert_eq!(result, "straßeÜberweisung");
+
+            let result = camel_case_with_options("ÉtatCivil", &opts);
+            assert_eq!(result, "étatCivil");
+
+            let result = camel_case_with_options("ÉTATCivil", &opts);
+            assert_eq!(result, "étatCivil");
+        }
+
+        #[test]
+        fn convert_with_one_to_many_case_mappings() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = camel_case_with_options("İSTANBULİçin", &opts);
+            assert_eq!(result, "i̇stanbulİçin");
+        }
+
+        #[test]
+        fn convert_letters_without_case_and_non_ascii_digits() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = camel_case_with_options("日本語_fooBar٣baz", &opts);
+            assert_eq!(result, "日本語FooBar٣Baz");
+        }
+    }
+
+    mod acronyms {
+        use super::*;
+
+        #[test]
+        fn convert_with_acronyms_capitalized() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                ..Default::default()
+            };
+            let result = camel_case_with_options("XMLHTTPRequest", &opts);
+            assert_eq!(result, "xmlHttpRequest");
+
+            let result = camel_case_with_options("http_server_id", &opts);
+            assert_eq!(result, "httpServerId");
+        }
+
+        #[test]
+        fn convert_with_acronyms_uppercased() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                uppercase_acronyms: true,
+                ..Default::default()
+            };
+            let result = camel_case_with_options("XMLHTTPRequest", &opts);
+            assert_eq!(result, "xmlHTTPRequest");
+
+            let result = camel_case_with_options("http_server_id", &opts);
+            assert_eq!(result, "httpServerID");
+
+            let result = camel_case_with_options("Identity_Idea", &opts);
+            assert_eq!(result, "identityIdea");
+        }
+    }
 }
@@ -3,6 +3,9 @@
 // See the file LICENSE in this distribution for more details.
 
 use crate::options::Options;
+use crate::render::{render_string, Style, WordCase};
+
+use alloc::string::String;
 
 /// A generic function that converts string cases into a capitalized format joined by a specified
 /// joiner character.
@@ -12,13 +15,17 @@ use crate::options::Options;
 /// lowercases subsequent letters, and joins the words using the const generic character
 /// `JOINER`.
 ///
-/// It operates by iterating character by character through `input` using an internal state
-/// machine. It handles ASCII uppercase, ASCII lowercase, and non-alphabetic characters (digits and
+/// It splits `input` into words in the same way as the [`words`](crate::words) function.
+/// It handles ASCII uppercase, ASCII lowercase, and non-alphabetic characters (digits and
 /// symbols) according to options such as `opts.separators`, `opts.keep`,
 /// `opts.separate_before_non_alphabets`, and `opts.separate_after_non_alphabets` to determine
 /// word boundaries, capitalize initial letters, and insert the `JOINER` character.
 /// If a character is specified in both `opts.separators` and `opts.keep`, the character in
 /// `opts.separators` takes precedence and the character in `opts.keep` is ignored.
+/// If `opts.unicode` is `true`, it handles Unicode uppercase and lowercase letters instead of
+/// ASCII ones and converts them with full Unicode case mappings.
+/// If `opts.uppercase_acronyms` is `true` and `opts.uppercase_acronyms_in` is empty, words equal
+/// to one of `opts.acronyms` are uppercased instead of capitalized.
 ///
 /// # Parameters
 ///
@@ -42,101 +49,13 @@ use crate::options::Options;
 ///     separate_after_non_alphabets: true,
 ///     separators: "",
 ///     keep: "",
+///     ..Default::default()
 /// };
 /// let result = capitalize::<'.'>("foo_bar_100_baz", &opts);
 /// assert_eq!(result, "Foo.Bar.100.Baz");
 /// ```
 pub fn capitalize<const JOINER: char>(input: &str, opts: &Options) -> String {
-    let mut result = String::with_capacity(input.len() + input.len() / 2);
-    // .len returns byte count but ok in this case!
-
-    #[derive(PartialEq)]
-    enum ChIs {
-        FirstOfStr,
-        NextOfUpper,
-        NextOfContdUpper,
-        NextOfSepMark,
-        NextOfKeptMark,
-        Other,
-    }
-
-    let mut flag = ChIs::FirstOfStr;
-
-    for ch in input.chars() {
-        if ch.is_ascii_uppercase() {
-            if flag == ChIs::FirstOfStr {
-                result.push(ch);
-                flag = ChIs::NextOfUpper;
-            } else if flag == ChIs::NextOfUpper
-                || flag == ChIs::NextOfContdUpper
-                || (!opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(ch.to_ascii_lowercase());
-                flag = ChIs::NextOfContdUpper;
-            } else {
-                result.push(JOINER);
-                result.push(ch);
-                flag = ChIs::NextOfUpper;
-            }
-        } else if ch.is_ascii_lowercase() {
-            if flag == ChIs::FirstOfStr {
-                result.push(ch.to_ascii_uppercase());
-            } else if flag == ChIs::NextOfContdUpper {
-                if let Some(prev) = result.pop() {
-                    result.push(JOINER);
-                    result.push(prev.to_ascii_uppercase());
-                    result.push(ch);
-                }
-            } else if flag == ChIs::NextOfSepMark
-                || (opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(JOINER);
-                result.push(ch.to_ascii_uppercase());
-            } else {
-                result.push(ch);
-            }
-            flag = ChIs::Other;
-        } else {
-            let mut is_kept_char = false;
-            if ch.is_ascii_digit() {
-                is_kept_char = true;
-            } else if !opts.separators.is_empty() {
-                if !opts.separators.contains(ch) {
-                    is_kept_char = true;
-                }
-            } else if !opts.keep.is_empty() {
-                #[allow(clippy::collapsible_if)]
-                if opts.keep.contains(ch) {
-                    is_kept_char = true;
-                }
-            }
-
-            if is_kept_char {
-                if opts.separate_before_non_alphabets {
-                    if flag == ChIs::FirstOfStr || flag == ChIs::NextOfKeptMark {
-                        result.push(ch);
-                    } else {
-                        result.push(JOINER);
-                        result.push(ch);
-                    }
-                } else {
-                    if flag != ChIs::NextOfSepMark {
-                        result.push(ch);
-                    } else {
-                        result.push(JOINER);
-                        result.push(ch);
-                    }
-                }
-                flag = ChIs::NextOfKeptMark;
-            } else {
-                if flag != ChIs::FirstOfStr {
-                    flag = ChIs::NextOfSepMark;
-                }
-            }
-        }
-    }
-
-    result
+    render_string(input, opts, Style::joined(WordCase::Capitalized, JOINER))
 }
 
 #[cfg(test)]
@@ -153,6 +72,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -165,6 +85,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -177,6 +98,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -189,6 +111,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -201,6 +124,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -213,6 +137,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -225,6 +150,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -237,6 +163,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc.123.456def.G.89hi.Jkl.Mn.12");
@@ -249,6 +176,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc.Def.Ghi.Jk.Lm.No");
@@ -261,6 +189,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123abc.456def");
@@ -279,6 +208,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -295,6 +225,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -307,6 +238,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -319,6 +251,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -331,6 +264,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -343,6 +277,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -355,6 +290,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -367,6 +303,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -379,6 +316,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123.456.Def.G89.Hi.Jkl.Mn12");
@@ -391,6 +329,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc.Def.Ghi.Jk.Lm.No");
@@ -403,6 +342,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.Abc456.Def");
@@ -421,6 +361,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -437,6 +378,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -449,6 +391,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -461,6 +404,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -473,6 +417,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -485,6 +430,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -497,6 +443,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -509,6 +456,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -521,6 +469,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc.123.456.Def.G.89.Hi.Jkl.Mn.12");
@@ -533,6 +482,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc.Def.Ghi.Jk.Lm.No");
@@ -545,6 +495,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.Abc.456.Def");
@@ -563,6 +514,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -579,6 +531,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -591,6 +544,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -603,6 +557,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -615,6 +570,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -627,6 +583,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -639,6 +596,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -651,6 +609,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -663,6 +622,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123.456def.G89hi.Jkl.Mn12");
@@ -675,6 +635,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc.Def.Ghi.Jk.Lm.No");
@@ -687,6 +648,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -705,6 +667,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -721,6 +684,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -733,6 +697,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -745,6 +710,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -761,6 +727,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -777,6 +744,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -793,6 +761,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -809,6 +778,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -825,6 +795,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc.123.456def.G.89hi.Jkl.Mn.12");
@@ -841,6 +812,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc.~!.Def.#.Ghi.%.Jk.Lm.No.?");
@@ -853,6 +825,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123abc.456def");
@@ -871,6 +844,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -883,6 +857,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123def", &opts);
             assert_eq!(result, "Abc.123def");
@@ -899,6 +874,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -911,6 +887,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -923,6 +900,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -939,6 +917,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -955,6 +934,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -971,6 +951,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -987,6 +968,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1003,6 +985,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123.456.Def.G89.Hi.Jkl.Mn12");
@@ -1019,6 +1002,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..Abc~!.Def#.Ghi%.Jk.Lm.No.?");
@@ -1031,6 +1015,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.Abc456.Def");
@@ -1049,6 +1034,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1061,6 +1047,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123def", &opts);
             assert_eq!(result, "Abc123.Def");
@@ -1077,6 +1064,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1089,6 +1077,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1101,6 +1090,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1117,6 +1107,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1133,6 +1124,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1149,6 +1141,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1165,6 +1158,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1181,6 +1175,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc.123.456.Def.G.89.Hi.Jkl.Mn.12");
@@ -1197,6 +1192,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..Abc.~!.Def.#.Ghi.%.Jk.Lm.No.?");
@@ -1209,6 +1205,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.Abc.456.Def");
@@ -1227,6 +1224,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1239,6 +1237,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123def", &opts);
             assert_eq!(result, "Abc.123.Def");
@@ -1255,6 +1254,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1267,6 +1267,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1279,6 +1280,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1295,6 +1297,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1311,6 +1314,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1327,6 +1331,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1343,6 +1348,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123.456def.G89hi.Jkl.Mn12");
@@ -1359,6 +1365,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!.Def#.Ghi%.Jk.Lm.No.?");
@@ -1371,6 +1378,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1389,6 +1397,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1401,6 +1410,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123def", &opts);
             assert_eq!(result, "Abc123def");
@@ -1417,6 +1427,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1429,6 +1440,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1441,6 +1453,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1457,6 +1470,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1473,6 +1487,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1489,6 +1504,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1505,6 +1521,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1521,6 +1538,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc.123.456def.G.89hi.Jkl.Mn.12");
@@ -1537,6 +1555,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc.~!.Def.#.Ghi.%.Jk.Lm.No.?");
@@ -1549,6 +1568,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123abc.456def");
@@ -1567,6 +1587,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1579,6 +1600,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-b2",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123def", &opts);
             assert_eq!(result, "Abc.123def");
@@ -1595,6 +1617,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1607,6 +1630,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1619,6 +1643,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1635,6 +1660,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1651,6 +1677,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1667,6 +1694,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1683,6 +1711,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1699,6 +1728,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123.456.Def.G89.Hi.Jkl.Mn12");
@@ -1715,6 +1745,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..Abc~!.Def#.Ghi%.Jk.Lm.No.?");
@@ -1727,6 +1758,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.Abc456.Def");
@@ -1745,6 +1777,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1761,6 +1794,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1773,6 +1807,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1785,6 +1820,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1801,6 +1837,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1817,6 +1854,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1833,6 +1871,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1849,6 +1888,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1865,6 +1905,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc.123.456.Def.G.89.Hi.Jkl.Mn.12");
@@ -1881,6 +1922,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..Abc.~!.Def.#.Ghi.%.Jk.Lm.No.?");
@@ -1893,6 +1935,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.Abc.456.Def");
@@ -1911,6 +1954,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1927,6 +1971,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1939,6 +1984,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc.Def.Gh.Ijk");
@@ -1951,6 +1997,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1967,6 +2014,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1983,6 +2031,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -1999,6 +2048,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -2015,6 +2065,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc.Def.Ghi");
@@ -2031,6 +2082,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123.456def.G89hi.Jkl.Mn12");
@@ -2047,6 +2099,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = capitalize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!.Def#.Ghi%.Jk.Lm.No.?");
@@ -2059,6 +2112,7 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -2077,9 +2131,94 @@ mod tests_of_capitalize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = capitalize::<'.'>("", &opts);
             assert_eq!(result, "");
         }
     }
+
+    mod unicode_letters {
+        use super::*;
+
+        #[test]
+        fn convert_non_ascii_letters_as_separators_by_default() {
+            let opts = Options {
+                unicode: false,
+                ..Default::default()
+            };
+            let result = capitalize::<'.'>("straßeÜberweisung", &opts);
+            assert_eq!(result, "Stra.E.Berweisung");
+        }
+
+        #[test]
+        fn convert_non_ascii_letters() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = capitalize::<'.'>("straßeÜberweisung", &opts);
+            assert_eq!(result, "Straße.Überweisung");
+
+            let result = capitalize::<'.'>("ÉtatCivil", &opts);
+            assert_eq!(result, "État.Civil");
+
+            let result = capitalize::<'.'>("ÉTATCivil", &opts);
+            assert_eq!(result, "État.Civil");
+        }
+
+        #[test]
+        fn convert_with_one_to_many_case_mappings() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = capitalize::<'.'>("İSTANBULİçin", &opts);
+            assert_eq!(result, "İstanbul.İçin");
+        }
+
+        #[test]
+        fn convert_letters_without_case_and_non_ascii_digits() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = capitalize::<'.'>("日本語_fooBar٣baz", &opts);
+            assert_eq!(result, "日本語.Foo.Bar٣.Baz");
+        }
+    }
+
+    mod acronyms {
+        use super::*;
+
+        #[test]
+        fn convert_with_acronyms_capitalized() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                ..Default::default()
+            };
+            let result = capitalize::<'.'>("XMLHTTPRequest", &opts);
+            assert_eq!(result, "Xml.Http.Request");
+
+            let result = capitalize::<'.'>("http_server_id", &opts);
+            assert_eq!(result, "Http.Server.Id");
+        }
+
+        #[test]
+        fn convert_with_acronyms_uppercased() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                uppercase_acronyms: true,
+                ..Default::default()
+            };
+            let result = capitalize::<'.'>("XMLHTTPRequest", &opts);
+            assert_eq!(result, "XML.HTTP.Request");
+
+            let result = capitalize::<'.'>("http_server_id", &opts);
+            assert_eq!(result, "HTTP.Server.ID");
+
+            let result = capitalize::<'.'>("Identity_Idea", &opts);
+            assert_eq!(result, "Identity.Idea");
+        }
+    }
 }
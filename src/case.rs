@@ -0,0 +1,698 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::options::Options;
+#[cfg(feature = "alloc")]
+use crate::render::render_string;
+use crate::render::{
+    is_rendered, render_display, render_fmt, render_len, render_slice, BufferTooSmall, Style, ADA,
+    CAMEL, COBOL, KEBAB, MACRO, PASCAL, SNAKE, TITLE, TRAIN,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+
+/// An enum that represents the case styles supported by this crate.
+///
+/// Case styles may be added in future versions, so a `match` on this enum outside this crate needs
+/// a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Case {
+    /// Ada case, e.g. `Foo_Bar_Baz`.
+    Ada,
+
+    /// Camel case, e.g. `fooBarBaz`.
+    Camel,
+
+    /// COBOL case, e.g. `FOO-BAR-BAZ`.
+    Cobol,
+
+    /// Kebab case, e.g. `foo-bar-baz`.
+    Kebab,
+
+    /// Macro case, e.g. `FOO_BAR_BAZ`.
+    Macro,
+
+    /// Pascal case, e.g. `FooBarBaz`.
+    Pascal,
+
+    /// Snake case, e.g. `foo_bar_baz`.
+    Snake,
+
+    /// Title case, e.g. `Foo Bar Baz`.
+    Title,
+
+    /// Train case, e.g. `Foo-Bar-Baz`.
+    Train,
+}
+
+impl Case {
+    /// All case styles supported by this crate.
+    ///
+    /// This is a slice, since more case styles can be added in later versions.
+    pub const ALL: &'static [Case] = &[
+        Case::Ada,
+        Case::Camel,
+        Case::Cobol,
+        Case::Kebab,
+        Case::Macro,
+        Case::Pascal,
+        Case::Snake,
+        Case::Title,
+        Case::Train,
+    ];
+
+    /// Returns the name of this case style, which is written in this case style itself, e.g.
+    /// `snake_case` or `Title Case`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Case::Ada => "Ada_Case",
+            Case::Camel => "camelCase",
+            Case::Cobol => "COBOL-CASE",
+            Case::Kebab => "kebab-case",
+            Case::Macro => "MACRO_CASE",
+            Case::Pascal => "PascalCase",
+            Case::Snake => "snake_case",
+            Case::Title => "Title Case",
+            Case::Train => "Train-Case",
+        }
+    }
+
+    pub(crate) fn style(&self) -> Style {
+        match self {
+            Case::Ada => ADA,
+            Case::Camel => CAMEL,
+            Case::Cobol => COBOL,
+            Case::Kebab => KEBAB,
+            Case::Macro => MACRO,
+            Case::Pascal => PASCAL,
+            Case::Snake => SNAKE,
+            Case::Title => TITLE,
+            Case::Train => TRAIN,
+        }
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An error which is returned when parsing a string into a [`Case`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCaseError {
+    #[cfg(feature = "alloc")]
+    name: String,
+}
+
+impl ParseCaseError {
+    #[allow(unused_variables)]
+    fn new(name: &str) -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            name: name.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseCaseError {
+    #[cfg(feature = "alloc")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown case style: {:?}", self.name)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("unknown case style")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCaseError {}
+
+/// An error which is returned when parsing a string into a variant of an enum fails.
+///
+/// This error is used by the `FromStr` implementations generated by `#[derive(StringCase)]` of
+/// the crate `stringcase-macros`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVariantError {
+    type_name: &'static str,
+    #[cfg(feature = "alloc")]
+    name: String,
+}
+
+impl ParseVariantError {
+    /// Creates an error which represents that `name` is not a variant name of the enum
+    /// `type_name`.
+    #[allow(unused_variables)]
+    pub fn new(type_name: &'static str, name: &str) -> Self {
+        Self {
+            type_name,
+            #[cfg(feature = "alloc")]
+            name: name.to_string(),
+        }
+    }
+
+    /// Returns the name of the enum.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Display for ParseVariantError {
+    #[cfg(feature = "alloc")]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown variant of {}: {:?}", self.type_name, self.name)
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown variant of {}", self.type_name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseVariantError {}
+
+impl FromStr for Case {
+    type Err = ParseCaseError;
+
+    /// Parses the name of a case style.
+    ///
+    /// The name is compared ignoring letter cases, separators, and the trailing word `case`, so
+    /// that `snake_case`, `snake`, `Snake Case`, and `SNAKE-CASE` are all parsed into
+    /// [`Case::Snake`].
+    /// In addition to the names of [`Case::name`], some common aliases are accepted: `lowerCamel`,
+    /// `UpperCamel`, `SCREAMING_SNAKE`, `CONSTANT`, and `SCREAMING-KEBAB`.
+    ///
+    /// ```rust
+    /// use stringcase::Case;
+    ///
+    /// assert_eq!("snake_case".parse(), Ok(Case::Snake));
+    /// assert_eq!("SCREAMING_SNAKE".parse(), Ok(Case::Macro));
+    /// assert_eq!("kebab".parse(), Ok(Case::Kebab));
+    /// assert_eq!("Title Case".parse(), Ok(Case::Title));
+    /// assert!("unknown".parse::<Case>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The key is built in a fixed buffer which is longer than all known names.
+        let mut buf = [0u8; 24];
+        let mut n = 0;
+        for ch in s.chars().filter(|ch| ch.is_ascii_alphanumeric()) {
+            if n == buf.len() {
+                return Err(ParseCaseError::new(s));
+            }
+            buf[n] = ch.to_ascii_lowercase() as u8;
+            n += 1;
+        }
+        let mut key = &buf[..n];
+        if key.len() > 4 && key.ends_with(b"case") {
+            key = &key[..key.len() - 4];
+        }
+        match key {
+            b"ada" => Ok(Case::Ada),
+            b"camel" | b"lowercamel" => Ok(Case::Camel),
+            b"cobol" | b"screamingkebab" => Ok(Case::Cobol),
+            b"kebab" => Ok(Case::Kebab),
+            b"macro" | b"screamingsnake" | b"constant" => Ok(Case::Macro),
+            b"pascal" | b"uppercamel" => Ok(Case::Pascal),
+            b"snake" => Ok(Case::Snake),
+            b"title" => Ok(Case::Title),
+            b"train" => Ok(Case::Train),
+            _ => Err(ParseCaseError::new(s)),
+        }
+    }
+}
+
+/// Converts the input string to the specified case style with the specified options.
+///
+/// This function returns the same string as the `〜_case_with_options` function of the case style,
+/// for example [`snake_case_with_options`](crate::snake_case_with_options) for [`Case::Snake`].
+///
+/// # Parameters
+///
+/// - `input`: The string slice (`&str`) to be converted.
+/// - `case`: The [`Case`] into which the string is converted.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - A `String` containing the converted string.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{convert, Case, Options};
+///
+/// let opts = Options::default();
+/// let case: Case = "kebab".parse().unwrap();
+/// assert_eq!(convert("fooBar100Baz", case, &opts), "foo-bar100-baz");
+/// assert_eq!(convert("fooBar100Baz", Case::Title, &opts), "Foo Bar100 Baz");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn convert(input: &str, case: Case, opts: &Options) -> String {
+    render_string(input, opts, case.style())
+}
+
+/// Converts the input string to the specified case style with the specified options, and writes
+/// the result into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`convert`] returns with the same case style and options.
+///
+/// ```rust
+/// use stringcase::{convert_into, Case, Options};
+///
+/// let opts = Options::default();
+/// let mut out = String::from("name: ");
+/// convert_into("fooBar100Baz", Case::Kebab, &opts, &mut out).unwrap();
+/// assert_eq!(out, "name: foo-bar100-baz");
+/// ```
+#[inline(always)]
+pub fn convert_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    case: Case,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, case.style())
+}
+
+/// Converts the input string to the specified case style with the specified options, and writes
+/// the result into the specified byte buffer without allocating memory.
+///
+/// It writes the same string as [`convert`] returns with the same case style and options, and
+/// returns the written part of the buffer as a string slice.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+/// use stringcase::{convert_to_slice, required_len, Case, Options};
+///
+/// let opts = Options::default();
+/// let mut buf = [0u8; 32];
+/// let result = convert_to_slice("fooBar100Baz", Case::Kebab, &opts, &mut buf);
+/// assert_eq!(result, Ok("foo-bar100-baz"));
+///
+/// let mut buf = [0u8; 8];
+/// let err = convert_to_slice("fooBar100Baz", Case::Kebab, &opts, &mut buf).unwrap_err();
+/// assert_eq!(err.required_len, 14);
+/// assert_eq!(required_len("fooBar100Baz", Case::Kebab, &opts), 14);
+/// ```
+#[inline(always)]
+pub fn convert_to_slice<'b>(
+    input: &str,
+    case: Case,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, case.style())
+}
+
+/// Returns the byte length of the string which is converted from the input string to the specified
+/// case style with the specified options.
+///
+/// This is the minimum length of the buffer for [`convert_to_slice`] and the `〜_case_to_slice`
+/// functions.
+///
+/// ```rust
+/// use stringcase::{required_len, Case, Options};
+///
+/// let opts = Options::default();
+/// assert_eq!(required_len("fooBar100Baz", Case::Snake, &opts), 14);
+/// assert_eq!(required_len("fooBar100Baz", Case::Pascal, &opts), 12);
+/// ```
+#[inline(always)]
+pub fn required_len(input: &str, case: Case, opts: &Options) -> usize {
+    render_len(input, opts, case.style())
+}
+
+/// A struct which displays a string slice in a case style with options.
+///
+/// It converts the string while formatting it in the same way as [`convert`], without allocating
+/// a new string.
+/// This is created by [`CaseDisplay::new`] or the `display_as` methods of [`Caser`](crate::Caser).
+/// The width, fill, alignment, and precision of the formatter are applied to the converted string.
+///
+/// ```rust
+/// use stringcase::{Case, CaseDisplay, Options};
+///
+/// let opts = Options::default();
+/// let s = format!("name: {}", CaseDisplay::new("fooBar100Baz", Case::Macro, &opts));
+/// assert_eq!(s, "name: FOO_BAR100_BAZ");
+/// ```
+#[derive(Clone, Copy)]
+pub struct CaseDisplay<'a, 'o> {
+    input: &'a str,
+    case: Case,
+    opts: &'o Options<'o>,
+}
+
+impl<'a, 'o> CaseDisplay<'a, 'o> {
+    /// Creates a `CaseDisplay` which displays `input` in `case` with `opts`.
+    pub fn new(input: &'a str, case: Case, opts: &'o Options<'o>) -> Self {
+        Self { input, case, opts }
+    }
+}
+
+impl fmt::Display for CaseDisplay<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.input, self.opts, self.case.style())
+    }
+}
+
+// The number of case styles, which is the length of arrays indexed by `Case`.
+pub(crate) const CASE_COUNT: usize = Case::ALL.len();
+
+// The order to check case styles in `detect_case`. A string which is in multiple case styles,
+// like a single word, is detected as the first of those styles in this order.
+pub(crate) const DETECTION_ORDER: [Case; CASE_COUNT] = [
+    Case::Snake,
+    Case::Kebab,
+    Case::Camel,
+    Case::Macro,
+    Case::Cobol,
+    Case::Pascal,
+    Case::Ada,
+    Case::Train,
+    Case::Title,
+];
+
+/// Detects the case style of the input string with the specified options.
+///
+/// A string is in a case style if converting it with the `〜_case_with_options` function of the
+/// style and the same options returns the same string.
+/// If the string is in multiple case styles, for example a single word like `foo` is in snake
+/// case, kebab case, and camel case, the first of them in the order of snake case, kebab case,
+/// camel case, macro case, cobol case, pascal case, Ada case, train case, and title case is
+/// returned.
+///
+/// # Parameters
+///
+/// - `input`: The target string slice (`&str`) to be checked.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - Returns the detected [`Case`], or `None` if the string is in none of the case styles, for
+///   example when it mixes case styles.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{detect_case_with_options, Case, Options};
+///
+/// let opts = Options{separate_before_non_alphabets: true, ..Default::default()};
+/// assert_eq!(detect_case_with_options("foo_bar_100_baz", &opts), Some(Case::Snake));
+/// assert_eq!(detect_case_with_options("foo_bar100_baz", &opts), None);
+/// ```
+pub fn detect_case_with_options(input: &str, opts: &Options) -> Option<Case> {
+    DETECTION_ORDER
+        .iter()
+        .copied()
+        .find(|case| is_rendered(input, opts, case.style()))
+}
+
+/// Detects the case style of the input string.
+///
+/// A string is in a case style if converting it with the `〜_case` function of the style returns
+/// the same string. If the string is in multiple case styles, the first of them is returned in
+/// the same order as [`detect_case_with_options`].
+///
+/// ```rust
+/// use stringcase::{detect_case, Case};
+///
+/// assert_eq!(detect_case("foo_bar100_baz"), Some(Case::Snake));
+/// assert_eq!(detect_case("fooBar100Baz"), Some(Case::Camel));
+/// assert_eq!(detect_case("Foo Bar100 Baz"), Some(Case::Title));
+/// assert_eq!(detect_case("foo_barBaz"), None);
+/// ```
+#[inline(always)]
+pub fn detect_case(input: &str) -> Option<Case> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    detect_case_with_options(input, &opts)
+}
+
+#[cfg(test)]
+mod tests_of_case {
+    use super::*;
+
+    #[test]
+    fn all_cases() {
+        assert_eq!(Case::ALL.len(), 9);
+        for (i, case) in Case::ALL.iter().enumerate() {
+            assert!(!Case::ALL[..i].contains(case));
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Case::Ada.to_string(), "Ada_Case");
+        assert_eq!(Case::Camel.to_string(), "camelCase");
+        assert_eq!(Case::Cobol.to_string(), "COBOL-CASE");
+        assert_eq!(Case::Kebab.to_string(), "kebab-case");
+        assert_eq!(Case::Macro.to_string(), "MACRO_CASE");
+        assert_eq!(Case::Pascal.to_string(), "PascalCase");
+        assert_eq!(Case::Snake.to_string(), "snake_case");
+        assert_eq!(Case::Title.to_string(), "Title Case");
+        assert_eq!(Case::Train.to_string(), "Train-Case");
+    }
+
+    #[test]
+    fn name_is_in_its_own_case() {
+        for &case in Case::ALL {
+            if case == Case::Cobol {
+                continue; // "COBOL" is not a capitalized word.
+            }
+            assert_eq!(detect_case(case.name()).map(|c| c == case), Some(true));
+        }
+    }
+
+    #[test]
+    fn parse_display_names() {
+        for &case in Case::ALL {
+            assert_eq!(case.to_string().parse(), Ok(case));
+        }
+    }
+
+    #[test]
+    fn parse_names_in_various_forms() {
+        assert_eq!("snake".parse(), Ok(Case::Snake));
+        assert_eq!("SNAKE-CASE".parse(), Ok(Case::Snake));
+        assert_eq!("Snake Case".parse(), Ok(Case::Snake));
+        assert_eq!("kebab".parse(), Ok(Case::Kebab));
+        assert_eq!("Title Case".parse(), Ok(Case::Title));
+        assert_eq!("title".parse(), Ok(Case::Title));
+        assert_eq!("ada".parse(), Ok(Case::Ada));
+        assert_eq!("train-case".parse(), Ok(Case::Train));
+    }
+
+    #[test]
+    fn parse_aliases() {
+        assert_eq!("SCREAMING_SNAKE".parse(), Ok(Case::Macro));
+        assert_eq!("SCREAMING_SNAKE_CASE".parse(), Ok(Case::Macro));
+        assert_eq!("CONSTANT_CASE".parse(), Ok(Case::Macro));
+        assert_eq!("SCREAMING-KEBAB".parse(), Ok(Case::Cobol));
+        assert_eq!("lowerCamelCase".parse(), Ok(Case::Camel));
+        assert_eq!("UpperCamelCase".parse(), Ok(Case::Pascal));
+    }
+
+    #[test]
+    fn fail_to_parse_unknown_names() {
+        let err = "sponge".parse::<Case>().unwrap_err();
+        #[cfg(feature = "alloc")]
+        assert_eq!(err.to_string(), "unknown case style: \"sponge\"");
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(err.to_string(), "unknown case style");
+        assert!("case".parse::<Case>().is_err());
+        assert!("".parse::<Case>().is_err());
+        assert!("snakecasecase".parse::<Case>().is_err());
+        assert!("screaming_snake_case_case_case".parse::<Case>().is_err());
+    }
+
+    #[test]
+    fn parse_variant_error() {
+        let err = ParseVariantError::new("Status", "todo");
+        assert_eq!(err.type_name(), "Status");
+        #[cfg(feature = "alloc")]
+        assert_eq!(err.to_string(), "unknown variant of Status: \"todo\"");
+        #[cfg(not(feature = "alloc"))]
+        assert_eq!(err.to_string(), "unknown variant of Status");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_convert {
+    use super::*;
+
+    #[test]
+    fn convert_to_each_case() {
+        let opts = Options::default();
+        let input = "fooBar100%BAZQux";
+        assert_eq!(convert(input, Case::Ada, &opts), "Foo_Bar100_Baz_Qux");
+        assert_eq!(convert(input, Case::Camel, &opts), "fooBar100BazQux");
+        assert_eq!(convert(input, Case::Cobol, &opts), "FOO-BAR100-BAZ-QUX");
+        assert_eq!(convert(input, Case::Kebab, &opts), "foo-bar100-baz-qux");
+        assert_eq!(convert(input, Case::Macro, &opts), "FOO_BAR100_BAZ_QUX");
+        assert_eq!(convert(input, Case::Pascal, &opts), "FooBar100BazQux");
+        assert_eq!(convert(input, Case::Snake, &opts), "foo_bar100_baz_qux");
+        assert_eq!(convert(input, Case::Title, &opts), "Foo Bar100 Baz Qux");
+        assert_eq!(convert(input, Case::Train, &opts), "Foo-Bar100-Baz-Qux");
+    }
+
+    #[test]
+    fn convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let input = "fooBar100%BAZQux";
+        assert_eq!(convert(input, Case::Snake, &opts), "foo_bar_100%_baz_qux");
+        assert_eq!(convert(input, Case::Title, &opts), "Foo Bar 100% Baz Qux");
+    }
+    #[test]
+    fn convert_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        for case in Case::ALL.iter() {
+            out.clear();
+            convert_into("fooBar100%BAZQux", *case, &opts, &mut out).unwrap();
+            assert_eq!(out, convert("fooBar100%BAZQux", *case, &opts));
+        }
+    }
+
+    #[test]
+    fn display_in_each_case() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for case in Case::ALL.iter() {
+            let display = CaseDisplay::new("fooBar100%BAZQux", *case, &opts);
+            assert_eq!(
+                display.to_string(),
+                convert("fooBar100%BAZQux", *case, &opts)
+            );
+        }
+    }
+    #[test]
+    fn convert_to_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 64];
+        for case in Case::ALL.iter() {
+            let expected = convert("fooBar100%BAZQux", *case, &opts);
+            let result = convert_to_slice("fooBar100%BAZQux", *case, &opts, &mut buf);
+            assert_eq!(result, Ok(expected.as_str()));
+            assert_eq!(
+                required_len("fooBar100%BAZQux", *case, &opts),
+                expected.len()
+            );
+        }
+    }
+
+    #[test]
+    fn fail_to_convert_to_a_too_small_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 17];
+        let result = convert_to_slice("fooBar100%BAZQux", Case::Title, &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 18 }));
+
+        let mut buf = [0u8; 18];
+        let result = convert_to_slice("fooBar100%BAZQux", Case::Title, &opts, &mut buf);
+        assert_eq!(result, Ok("Foo Bar100 Baz Qux"));
+    }
+}
+
+#[cfg(test)]
+mod tests_of_detect_case {
+    use super::*;
+
+    #[test]
+    fn detect_each_case() {
+        assert_eq!(detect_case("foo_bar100_baz"), Some(Case::Snake));
+        assert_eq!(detect_case("foo-bar100-baz"), Some(Case::Kebab));
+        assert_eq!(detect_case("fooBar100Baz"), Some(Case::Camel));
+        assert_eq!(detect_case("FOO_BAR100_BAZ"), Some(Case::Macro));
+        assert_eq!(detect_case("FOO-BAR100-BAZ"), Some(Case::Cobol));
+        assert_eq!(detect_case("FooBar100Baz"), Some(Case::Pascal));
+        assert_eq!(detect_case("Foo_Bar100_Baz"), Some(Case::Ada));
+        assert_eq!(detect_case("Foo-Bar100-Baz"), Some(Case::Train));
+        assert_eq!(detect_case("Foo Bar100 Baz"), Some(Case::Title));
+    }
+
+    #[test]
+    fn detect_single_words() {
+        assert_eq!(detect_case("foo"), Some(Case::Snake));
+        assert_eq!(detect_case("FOO"), Some(Case::Macro));
+        assert_eq!(detect_case("Foo"), Some(Case::Pascal));
+        assert_eq!(detect_case("foo100"), Some(Case::Snake));
+    }
+
+    #[test]
+    fn detect_mixed_case() {
+        assert_eq!(detect_case("foo_barBaz"), None);
+        assert_eq!(detect_case("foo-bar_baz"), None);
+        assert_eq!(detect_case("Foo_bar"), None);
+        assert_eq!(detect_case("FOOBar"), None);
+        assert_eq!(detect_case("foo bar"), None);
+        assert_eq!(detect_case("_foo"), None);
+    }
+
+    #[test]
+    fn detect_an_empty_string() {
+        assert_eq!(detect_case(""), Some(Case::Snake));
+    }
+
+    #[test]
+    fn detect_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            detect_case_with_options("foo_bar_100_baz", &opts),
+            Some(Case::Snake)
+        );
+        assert_eq!(detect_case_with_options("foo_bar100_baz", &opts), None);
+
+        let opts = Options {
+            keep: "$",
+            ..Default::default()
+        };
+        assert_eq!(
+            detect_case_with_options("foo_bar$", &opts),
+            Some(Case::Snake)
+        );
+        assert_eq!(
+            detect_case_with_options("FooBar$", &opts),
+            Some(Case::Pascal)
+        );
+    }
+
+    #[test]
+    fn detect_with_acronyms() {
+        let opts = Options {
+            acronyms: &["HTTP", "ID"],
+            uppercase_acronyms: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            detect_case_with_options("HTTPServerID", &opts),
+            Some(Case::Pascal)
+        );
+        assert_eq!(detect_case_with_options("HttpServerId", &opts), None);
+    }
+}
@@ -0,0 +1,611 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::chars::is_upper;
+use crate::options::Options;
+use crate::words::words;
+
+#[cfg(feature = "alloc")]
+use crate::options::DEFAULT_OPTIONS;
+#[cfg(feature = "alloc")]
+use crate::words::push_lower_word;
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::{cmp::Ordering, fmt, hash};
+
+use core::char::ToLowercase;
+
+#[cfg(feature = "std")]
+use std::collections::hash_map::{self, HashMap};
+#[cfg(feature = "std")]
+use std::ops::Index;
+
+// An iterator over the characters of a lowercased character, which are the same characters as
+// `push_lower_word` pushes for the character.
+enum LowerChars {
+    One(Option<char>),
+    Many(ToLowercase),
+}
+
+impl LowerChars {
+    fn new(ch: char, unicode: bool) -> Self {
+        if !is_upper(ch, unicode) {
+            LowerChars::One(Some(ch))
+        } else if ch.is_ascii() || !unicode {
+            LowerChars::One(Some(ch.to_ascii_lowercase()))
+        } else {
+            LowerChars::Many(ch.to_lowercase())
+        }
+    }
+}
+
+impl Iterator for LowerChars {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        match self {
+            LowerChars::One(ch) => ch.take(),
+            LowerChars::Many(chars) => chars.next(),
+        }
+    }
+}
+
+/// Checks whether two strings are equal ignoring their case styles with the specified options.
+///
+/// Two strings are equal ignoring their case styles if they are split into the same number of
+/// words in the same way as [`words`](crate::words), and their words are equal when they are
+/// lowercased.
+/// So `userId`, `user_id`, `USER-ID`, and `User Id` are equal to each other, but `userid` is not
+/// equal to them because it is a single word.
+/// This function does not allocate memory.
+///
+/// # Parameters
+///
+/// - `a`: The string slice (`&str`) to be compared.
+/// - `b`: The string slice (`&str`) to be compared.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - `true` if `a` and `b` are equal ignoring their case styles, otherwise `false`.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{eq_ignore_case_style, Options};
+///
+/// let opts = Options::default();
+/// assert!(eq_ignore_case_style("userId", "USER-ID", &opts));
+/// assert!(eq_ignore_case_style("user_id", "User Id", &opts));
+/// assert!(!eq_ignore_case_style("userId", "userid", &opts));
+/// ```
+pub fn eq_ignore_case_style(a: &str, b: &str, opts: &Options) -> bool {
+    let unicode = opts.unicode;
+    let mut b_words = words(b, opts);
+    for a_word in words(a, opts) {
+        let b_word = match b_words.next() {
+            Some(w) => w,
+            None => return false,
+        };
+        let a_chars = a_word
+            .text
+            .chars()
+            .flat_map(|c| LowerChars::new(c, unicode));
+        let b_chars = b_word
+            .text
+            .chars()
+            .flat_map(|c| LowerChars::new(c, unicode));
+        if !a_chars.eq(b_chars) {
+            return false;
+        }
+    }
+    b_words.next().is_none()
+}
+
+/// A string which is compared, hashed, and ordered ignoring its case style.
+///
+/// A `CaseKey` splits a string into words in the same way as [`words`](crate::words), and is
+/// equal to, hashed as, and ordered by the sequence of the lowercased words, as
+/// [`eq_ignore_case_style`] compares strings.
+/// So a `CaseKey` can be a key of `HashMap` or `BTreeMap` in which `userId`, `user_id`, and
+/// `USER-ID` are the same key.
+/// The original string is kept, and is displayed and returned by [`as_str`](CaseKey::as_str).
+///
+/// ```rust
+/// use stringcase::CaseKey;
+///
+/// let key = CaseKey::new("userId");
+/// assert_eq!(key, CaseKey::new("USER-ID"));
+/// assert_ne!(key, CaseKey::new("userid"));
+/// assert_eq!(key.as_str(), "userId");
+/// assert_eq!(key.to_string(), "userId");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct CaseKey {
+    original: String,
+    lower: String,
+    ends: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl CaseKey {
+    /// Creates a `CaseKey` of a string, which is split into words with the default options.
+    pub fn new(s: &str) -> Self {
+        Self::with_options(s, &DEFAULT_OPTIONS)
+    }
+
+    /// Creates a `CaseKey` of a string, which is split into words with the specified options.
+    ///
+    /// Keys which are created with different options should not be compared with each other.
+    pub fn with_options(s: &str, opts: &Options) -> Self {
+        Self::from_string(String::from(s), opts)
+    }
+
+    fn from_string(original: String, opts: &Options) -> Self {
+        let mut lower = String::with_capacity(original.len());
+        let mut ends = Vec::new();
+        for word in words(&original, opts) {
+            push_lower_word(&mut lower, word.text, opts.unicode);
+            ends.push(lower.len());
+        }
+        Self {
+            original,
+            lower,
+            ends,
+        }
+    }
+
+    /// Returns the original string of this key.
+    pub fn as_str(&self) -> &str {
+        &self.original
+    }
+
+    /// Returns the original string of this key.
+    pub fn into_string(self) -> String {
+        self.original
+    }
+
+    fn lower_words(&self) -> impl Iterator<Item = &str> {
+        let lower = &self.lower;
+        let mut start = 0;
+        self.ends.iter().map(move |end| {
+            let word = &lower[start..*end];
+            start = *end;
+            word
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for CaseKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ends == other.ends && self.lower == other.lower
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Eq for CaseKey {}
+
+#[cfg(feature = "alloc")]
+impl hash::Hash for CaseKey {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.lower.hash(state);
+        self.ends.hash(state);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialOrd for CaseKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ord for CaseKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.lower_words().cmp(other.lower_words())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for CaseKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CaseKey").field(&self.original).finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for CaseKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.original)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsRef<str> for CaseKey {
+    fn as_ref(&self) -> &str {
+        &self.original
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<&str> for CaseKey {
+    fn from(s: &str) -> Self {
+        Self::new(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for CaseKey {
+    fn from(s: String) -> Self {
+        Self::from_string(s, &DEFAULT_OPTIONS)
+    }
+}
+
+/// A `HashMap` whose keys are strings compared ignoring their case styles.
+///
+/// This is a thin wrapper of `HashMap<CaseKey, V>`, which takes keys as strings.
+/// The keys are split into words with the default options, so `userId`, `user_id`, and `USER-ID`
+/// are the same key.
+/// Like `HashMap`, inserting a value with a key which is already in the map updates the value but
+/// does not update the key, so the spelling of the key which is inserted first is kept.
+///
+/// ```rust
+/// use stringcase::CaseInsensitiveMap;
+///
+/// let mut map = CaseInsensitiveMap::new();
+/// map.insert("userId", 1);
+/// assert_eq!(map.insert("USER_ID", 2), Some(1));
+/// assert_eq!(map.get("user-id"), Some(&2));
+/// assert_eq!(map["User Id"], 2);
+/// assert_eq!(map.len(), 1);
+///
+/// let keys: Vec<&str> = map.keys().map(|k| k.as_str()).collect();
+/// assert_eq!(keys, ["userId"]);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseInsensitiveMap<V> {
+    map: HashMap<CaseKey, V>,
+}
+
+#[cfg(feature = "std")]
+impl<V> CaseInsensitiveMap<V> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty map with at least the specified capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map has no entry.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Inserts a value with a key, and returns the old value if the map already has the key
+    /// ignoring its case style.
+    ///
+    /// The key is a string, which is split into words with the default options as the keys given
+    /// to the other methods.
+    pub fn insert<K: Into<String>>(&mut self, key: K, value: V) -> Option<V> {
+        self.map.insert(CaseKey::from(key.into()), value)
+    }
+
+    /// Returns a reference to the value of a key ignoring its case style.
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.map.get(&CaseKey::new(key))
+    }
+
+    /// Returns a mutable reference to the value of a key ignoring its case style.
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        self.map.get_mut(&CaseKey::new(key))
+    }
+
+    /// Returns the stored key and a reference to the value of a key ignoring its case style.
+    pub fn get_key_value(&self, key: &str) -> Option<(&CaseKey, &V)> {
+        self.map.get_key_value(&CaseKey::new(key))
+    }
+
+    /// Returns `true` if the map has a key ignoring its case style.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.map.contains_key(&CaseKey::new(key))
+    }
+
+    /// Removes a key ignoring its case style, and returns its value if the map had the key.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        self.map.remove(&CaseKey::new(key))
+    }
+
+    /// Removes all entries.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns an iterator over the entries in arbitrary order.
+    pub fn iter(&self) -> hash_map::Iter<'_, CaseKey, V> {
+        self.map.iter()
+    }
+
+    /// Returns an iterator over the entries with mutable references to the values in arbitrary
+    /// order.
+    pub fn iter_mut(&mut self) -> hash_map::IterMut<'_, CaseKey, V> {
+        self.map.iter_mut()
+    }
+
+    /// Returns an iterator over the keys in arbitrary order.
+    pub fn keys(&self) -> hash_map::Keys<'_, CaseKey, V> {
+        self.map.keys()
+    }
+
+    /// Returns an iterator over the values in arbitrary order.
+    pub fn values(&self) -> hash_map::Values<'_, CaseKey, V> {
+        self.map.values()
+    }
+
+    /// Returns the inner `HashMap`.
+    pub fn into_inner(self) -> HashMap<CaseKey, V> {
+        self.map
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V> Default for CaseInsensitiveMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V> Index<&str> for CaseInsensitiveMap<V> {
+    type Output = V;
+
+    /// Returns a reference to the value of a key ignoring its case style.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map does not have the key.
+    fn index(&self, key: &str) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Into<String>, V> FromIterator<(K, V)> for CaseInsensitiveMap<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Into<String>, V> Extend<(K, V)> for CaseInsensitiveMap<V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            self.insert(k, v);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V> IntoIterator for CaseInsensitiveMap<V> {
+    type Item = (CaseKey, V);
+    type IntoIter = hash_map::IntoIter<CaseKey, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, V> IntoIterator for &'a CaseInsensitiveMap<V> {
+    type Item = (&'a CaseKey, &'a V);
+    type IntoIter = hash_map::Iter<'a, CaseKey, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests_of_eq_ignore_case_style {
+    use super::*;
+
+    #[test]
+    fn equal_in_each_case_style() {
+        let opts = Options::default();
+        let inputs = [
+            "fooBar100Baz",
+            "foo_bar100_baz",
+            "foo-bar100-baz",
+            "FOO_BAR100_BAZ",
+            "FOO-BAR100-BAZ",
+            "FooBar100Baz",
+            "Foo_Bar100_Baz",
+            "Foo-Bar100-Baz",
+            "Foo Bar100 Baz",
+            "  foo__Bar100--BAZ  ",
+        ];
+        for a in inputs.iter() {
+            for b in inputs.iter() {
+                assert!(eq_ignore_case_style(a, b, &opts), "{} {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn not_equal_with_different_words() {
+        let opts = Options::default();
+        assert!(!eq_ignore_case_style("fooBar", "foobar", &opts));
+        assert!(!eq_ignore_case_style("fooBar", "foo_bar_baz", &opts));
+        assert!(!eq_ignore_case_style("foo_bar_baz", "fooBar", &opts));
+        assert!(!eq_ignore_case_style("fooBar", "foo_baz", &opts));
+        assert!(!eq_ignore_case_style("fooBar", "", &opts));
+        assert!(eq_ignore_case_style("", "_-", &opts));
+    }
+
+    #[test]
+    fn equal_with_options() {
+        let opts = Options::new(true, true, "", "");
+        assert!(eq_ignore_case_style("foo100", "FOO_100", &opts));
+        let opts = Options::default();
+        assert!(!eq_ignore_case_style("foo100", "FOO_100", &opts));
+
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        assert!(eq_ignore_case_style("ÉtéÀ", "été_à", &opts));
+        assert!(eq_ignore_case_style("ΣΟΦΊΑ", "σοφία", &opts));
+        assert!(!eq_ignore_case_style("été", "ete", &opts));
+
+        let opts = Options {
+            acronyms: &["HTTP"],
+            ..Default::default()
+        };
+        assert!(eq_ignore_case_style("HTTPServer", "http_server", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_case_key {
+    use super::*;
+    use alloc::collections::BTreeSet;
+    use alloc::string::ToString;
+
+    #[test]
+    fn equal_ignoring_case_styles() {
+        assert_eq!(CaseKey::new("userId"), CaseKey::new("user_id"));
+        assert_eq!(CaseKey::new("userId"), CaseKey::from("USER-ID"));
+        assert_eq!(
+            CaseKey::new("userId"),
+            CaseKey::from(String::from("User Id"))
+        );
+        assert_ne!(CaseKey::new("userId"), CaseKey::new("userid"));
+        assert_ne!(CaseKey::new("a_bc"), CaseKey::new("ab_c"));
+    }
+
+    #[test]
+    fn keep_original_string() {
+        let key = CaseKey::new("USER-ID");
+        assert_eq!(key.as_str(), "USER-ID");
+        assert_eq!(key.as_ref(), "USER-ID");
+        assert_eq!(key.to_string(), "USER-ID");
+        assert_eq!(alloc::format!("{:?}", key), "CaseKey(\"USER-ID\")");
+        assert_eq!(key.into_string(), "USER-ID");
+    }
+
+    #[test]
+    fn order_by_lowercased_words() {
+        let set: BTreeSet<CaseKey> = ["fooBar", "FOO", "Baz-Qux", "ab_c", "a_bc"]
+            .iter()
+            .map(|s| CaseKey::new(s))
+            .collect();
+        let v: Vec<&str> = set.iter().map(|k| k.as_str()).collect();
+        assert_eq!(v, ["a_bc", "ab_c", "Baz-Qux", "FOO", "fooBar"]);
+    }
+
+    #[test]
+    fn create_with_options() {
+        let opts = Options::new(true, true, "", "");
+        assert_eq!(
+            CaseKey::with_options("foo100", &opts),
+            CaseKey::with_options("FOO_100", &opts)
+        );
+        assert_ne!(CaseKey::new("foo100"), CaseKey::new("FOO_100"));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests_of_case_insensitive_map {
+    use super::*;
+
+    #[test]
+    fn insert_and_get() {
+        let mut map = CaseInsensitiveMap::new();
+        assert!(map.is_empty());
+        assert_eq!(map.insert("userId", 1), None);
+        assert_eq!(map.insert(String::from("USER_ID"), 2), Some(1));
+        assert_eq!(map.insert("userName", 3), None);
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.get("user-id"), Some(&2));
+        assert_eq!(map.get("userid"), None);
+        assert!(map.contains_key("User Name"));
+        assert_eq!(map["user_name"], 3);
+
+        let (key, value) = map.get_key_value("UserId").unwrap();
+        assert_eq!(key.as_str(), "userId");
+        assert_eq!(*value, 2);
+
+        *map.get_mut("user_name").unwrap() += 10;
+        assert_eq!(map["userName"], 13);
+
+        assert_eq!(map.remove("USER-NAME"), Some(13));
+        assert_eq!(map.remove("USER-NAME"), None);
+        assert_eq!(map.len(), 1);
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "no entry found for key")]
+    fn fail_to_index_with_absent_key() {
+        let map: CaseInsensitiveMap<i32> = CaseInsensitiveMap::default();
+        let _ = map["fooBar"];
+    }
+
+    #[test]
+    fn iterate_over_entries() {
+        let mut map: CaseInsensitiveMap<i32> = [("fooBar", 1), ("baz", 2), ("FOO_BAR", 3)]
+            .iter()
+            .copied()
+            .collect();
+        assert_eq!(map.len(), 2);
+
+        for (_, v) in map.iter_mut() {
+            *v *= 10;
+        }
+        let mut entries: Vec<(&str, i32)> =
+            (&map).into_iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        entries.sort();
+        assert_eq!(entries, [("baz", 20), ("fooBar", 30)]);
+
+        let mut keys: Vec<String> = map.keys().map(|k| k.to_string()).collect();
+        keys.sort();
+        assert_eq!(keys, ["baz", "fooBar"]);
+        let mut values: Vec<i32> = map.values().copied().collect();
+        values.sort();
+        assert_eq!(values, [20, 30]);
+
+        let inner = map.clone().into_inner();
+        assert_eq!(inner.get(&CaseKey::new("foo-bar")), Some(&30));
+
+        let mut entries: Vec<(String, i32)> =
+            map.into_iter().map(|(k, v)| (k.into_string(), v)).collect();
+        entries.sort();
+        assert_eq!(
+            entries,
+            [(String::from("baz"), 20), (String::from("fooBar"), 30)]
+        );
+    }
+}
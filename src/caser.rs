@@ -2,14 +2,24 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
+use crate::ada_case::*;
 use crate::camel_case::*;
+use crate::capitalize::capitalize;
+use crate::case::*;
 use crate::cobol_case::*;
 use crate::kebab_case::*;
+use crate::lowerize::lowerize;
 use crate::macro_case::*;
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
 use crate::pascal_case::*;
 use crate::snake_case::*;
+use crate::title_case::*;
 use crate::train_case::*;
+use crate::upperize::upperize;
+use crate::words::*;
+
+use alloc::borrow::Cow;
+use alloc::string::String;
 
 /// `Caser` is the trait to attach methods for converting strings `&str` and
 /// `String` to various cases.
@@ -17,6 +27,46 @@ use crate::train_case::*;
 /// By declaring this trait with `use` keyword, all conversion methods
 /// provided by this library become available for `&str` and `String`.
 pub trait Caser<T: AsRef<str>> {
+    // ada case
+
+    /// Converts the input string to ada case.
+    ///
+    /// It treats the end of a sequence of non-alphabetic characters as a word boundary,
+    /// but not the beginning.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     let ada = "foo_bar100_baz".to_ada_case();
+    ///     assert_eq!(ada, "Foo_Bar100_Baz");
+    /// ```
+    fn to_ada_case(&self) -> String;
+
+    /// Converts the input string to ada case with the specified options.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     let opts = stringcase::Options{
+    ///       separate_before_non_alphabets: true,
+    ///       separate_after_non_alphabets: true,
+    ///       separators: "",
+    ///       keep: "",
+    ///       ..Default::default()
+    ///     };
+    ///     let ada = "fooBar100Baz".to_ada_case_with_options(&opts);
+    ///     assert_eq!(ada, "Foo_Bar_100_Baz");
+    /// ```
+    fn to_ada_case_with_options(&self, opts: &Options) -> String;
+
+    /// Converts the input string to Ada case, and borrows the input string if it is already in
+    /// Ada case.
+    fn to_ada_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to Ada case with the specified options, and borrows the input
+    /// string if it is already in Ada case.
+    fn to_ada_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     // camel case
 
     /// Converts the input string to camel case.
@@ -40,12 +90,21 @@ pub trait Caser<T: AsRef<str>> {
     ///       separate_after_non_alphabets: true,
     ///       separators: "",
     ///       keep: "",
+    ///       ..Default::default()
     ///     };
     ///     let camel = stringcase::camel_case_with_options("foo_bar_100_baz", &opts);
     ///     assert_eq!(camel, "fooBar100Baz");
     /// ```
     fn to_camel_case_with_options(&self, opts: &Options) -> String;
 
+    /// Converts the input string to camel case, and borrows the input string if it is already in
+    /// camel case.
+    fn to_camel_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to camel case with the specified options, and borrows the input
+    /// string if it is already in camel case.
+    fn to_camel_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     /// Converts the input string to camel case with the specified separator characters.
     #[deprecated(
         since = "0.4.0",
@@ -85,12 +144,21 @@ pub trait Caser<T: AsRef<str>> {
     ///       separate_after_non_alphabets: true,
     ///       separators: "",
     ///       keep: "",
+    ///       ..Default::default()
     ///     };
     ///     let cobol = "fooBar100Baz".to_cobol_case_with_options(&opts);
     ///     assert_eq!(cobol, "FOO-BAR-100-BAZ");
     /// ```
     fn to_cobol_case_with_options(&self, opts: &Options) -> String;
 
+    /// Converts the input string to cobol case, and borrows the input string if it is already in
+    /// cobol case.
+    fn to_cobol_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to cobol case with the specified options, and borrows the input
+    /// string if it is already in cobol case.
+    fn to_cobol_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     /// Converts the input string to cobol case.
     ///
     /// It treats the begin and the end of a sequence of non-alphabetic characters as a word
@@ -140,12 +208,21 @@ pub trait Caser<T: AsRef<str>> {
     ///       separate_after_non_alphabets: true,
     ///       separators: "",
     ///       keep: "",
+    ///       ..Default::default()
     ///     };
     ///     let kebab = "fooBar100Baz".to_kebab_case_with_options(&opts);
     ///     assert_eq!(kebab, "foo-bar-100-baz");
     /// ```
     fn to_kebab_case_with_options(&self, opts: &Options) -> String;
 
+    /// Converts the input string to kebab case, and borrows the input string if it is already in
+    /// kebab case.
+    fn to_kebab_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to kebab case with the specified options, and borrows the input
+    /// string if it is already in kebab case.
+    fn to_kebab_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     /// Converts the input string to kebab case.
     ///
     /// It treats the begin and the end of a sequence of non-alphabetic characters as a word
@@ -195,12 +272,21 @@ pub trait Caser<T: AsRef<str>> {
     ///       separate_after_non_alphabets: true,
     ///       separators: "",
     ///       keep: "",
+    ///       ..Default::default()
     ///     };
     ///     let result = "fooBar100Baz".to_macro_case_with_options(&opts);
     ///     assert_eq!(result, "FOO_BAR_100_BAZ");
     /// ```
     fn to_macro_case_with_options(&self, opts: &Options) -> String;
 
+    /// Converts the input string to macro case, and borrows the input string if it is already in
+    /// macro case.
+    fn to_macro_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to macro case with the specified options, and borrows the input
+    /// string if it is already in macro case.
+    fn to_macro_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     /// Converts the input string to macro case.
     ///
     /// It treats the begin and the end of a sequence of non-alphabetic characters as a word
@@ -250,12 +336,21 @@ pub trait Caser<T: AsRef<str>> {
     ///       separate_after_non_alphabets: true,
     ///       separators: "",
     ///       keep: "",
+    ///       ..Default::default()
     ///     };
     ///     let pascal = "foo_bar_100_baz".to_pascal_case_with_options(&opts);
     ///     assert_eq!(pascal, "FooBar100Baz");
     /// ```
     fn to_pascal_case_with_options(&self, opts: &Options) -> String;
 
+    /// Converts the input string to pascal case, and borrows the input string if it is already in
+    /// pascal case.
+    fn to_pascal_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to pascal case with the specified options, and borrows the input
+    /// string if it is already in pascal case.
+    fn to_pascal_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     /// Converts the input string to pascal case with the specified separator characters.
     #[deprecated(
         since = "0.4.0",
@@ -295,12 +390,21 @@ pub trait Caser<T: AsRef<str>> {
     ///       separate_after_non_alphabets: true,
     ///       separators: "",
     ///       keep: "",
+    ///       ..Default::default()
     ///     };
     ///     let snake = "fooBar100Baz".to_snake_case_with_options(&opts);
     ///     assert_eq!(snake, "foo_bar_100_baz");
     /// ```
     fn to_snake_case_with_options(&self, opts: &Options) -> String;
 
+    /// Converts the input string to snake case, and borrows the input string if it is already in
+    /// snake case.
+    fn to_snake_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to snake case with the specified options, and borrows the input
+    /// string if it is already in snake case.
+    fn to_snake_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     /// Converts the input string to snake case.
     ///
     /// It treats the begin and the end of a sequence of non-alphabetic characters as a word
@@ -325,6 +429,46 @@ pub trait Caser<T: AsRef<str>> {
     )]
     fn to_snake_case_with_keep(&self, kept: &str) -> String;
 
+    // title case
+
+    /// Converts the input string to title case.
+    ///
+    /// It treats the end of a sequence of non-alphabetic characters as a word boundary,
+    /// but not the beginning.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     let title = "fooBar100Baz".to_title_case();
+    ///     assert_eq!(title, "Foo Bar100 Baz");
+    /// ```
+    fn to_title_case(&self) -> String;
+
+    /// Converts the input string to title case with the specified options.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     let opts = stringcase::Options{
+    ///       separate_before_non_alphabets: true,
+    ///       separate_after_non_alphabets: true,
+    ///       separators: "",
+    ///       keep: "",
+    ///       ..Default::default()
+    ///     };
+    ///     let title = "fooBar100Baz".to_title_case_with_options(&opts);
+    ///     assert_eq!(title, "Foo Bar 100 Baz");
+    /// ```
+    fn to_title_case_with_options(&self, opts: &Options) -> String;
+
+    /// Converts the input string to title case, and borrows the input string if it is already in
+    /// title case.
+    fn to_title_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to title case with the specified options, and borrows the input
+    /// string if it is already in title case.
+    fn to_title_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     // train case
 
     /// Converts the input string to train case.
@@ -350,12 +494,21 @@ pub trait Caser<T: AsRef<str>> {
     ///       separate_after_non_alphabets: true,
     ///       separators: "",
     ///       keep: "",
+    ///       ..Default::default()
     ///     };
     ///     let train = "fooBar100Baz".to_train_case_with_options(&opts);
     ///     assert_eq!(train, "Foo-Bar-100-Baz");
     /// ```
     fn to_train_case_with_options(&self, opts: &Options) -> String;
 
+    /// Converts the input string to train case, and borrows the input string if it is already in
+    /// train case.
+    fn to_train_case_cow(&self) -> Cow<'_, str>;
+
+    /// Converts the input string to train case with the specified options, and borrows the input
+    /// string if it is already in train case.
+    fn to_train_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str>;
+
     /// Converts the input string to train case.
     ///
     /// It treats the begin and the end of a sequence of non-alphabetic characters as a word
@@ -379,9 +532,296 @@ pub trait Caser<T: AsRef<str>> {
         note = "Should use to_train_case_with_options instead"
     )]
     fn to_train_case_with_keep(&self, kept: &str) -> String;
+
+    // capitalize, lowerize, and upperize
+
+    /// Converts the input string to capitalized words joined by the specified joiner character
+    /// `JOINER` with the specified options.
+    ///
+    /// ```rust
+    ///     use stringcase::{Caser, Options};
+    ///
+    ///     let opts = Options::default();
+    ///     let result = "fooBar100Baz".to_capitalized::<'.'>(&opts);
+    ///     assert_eq!(result, "Foo.Bar100.Baz");
+    /// ```
+    fn to_capitalized<const JOINER: char>(&self, opts: &Options) -> String;
+
+    /// Converts the input string to lowercased words joined by the specified joiner character
+    /// `JOINER` with the specified options.
+    ///
+    /// ```rust
+    ///     use stringcase::{Caser, Options};
+    ///
+    ///     let opts = Options::default();
+    ///     let result = "fooBar100Baz".to_lowerized::<'.'>(&opts);
+    ///     assert_eq!(result, "foo.bar100.baz");
+    /// ```
+    fn to_lowerized<const JOINER: char>(&self, opts: &Options) -> String;
+
+    /// Converts the input string to uppercased words joined by the specified joiner character
+    /// `JOINER` with the specified options.
+    ///
+    /// ```rust
+    ///     use stringcase::{Caser, Options};
+    ///
+    ///     let opts = Options::default();
+    ///     let result = "fooBar100Baz".to_upperized::<'.'>(&opts);
+    ///     assert_eq!(result, "FOO.BAR100.BAZ");
+    /// ```
+    fn to_upperized<const JOINER: char>(&self, opts: &Options) -> String;
+
+    // any case
+
+    /// Converts the input string to the specified case style.
+    ///
+    /// It treats the end of a sequence of non-alphabetic characters as a word boundary,
+    /// but not the beginning.
+    ///
+    /// ```rust
+    ///     use stringcase::{Case, Caser};
+    ///
+    ///     let kebab = "fooBar100Baz".to_case(Case::Kebab);
+    ///     assert_eq!(kebab, "foo-bar100-baz");
+    /// ```
+    fn to_case(&self, case: Case) -> String;
+
+    /// Converts the input string to the specified case style with the specified options.
+    ///
+    /// ```rust
+    ///     use stringcase::{Case, Caser};
+    ///
+    ///     let opts = stringcase::Options{
+    ///       separate_before_non_alphabets: true,
+    ///       ..Default::default()
+    ///     };
+    ///     let kebab = "fooBar100Baz".to_case_with_options(Case::Kebab, &opts);
+    ///     assert_eq!(kebab, "foo-bar-100-baz");
+    /// ```
+    fn to_case_with_options(&self, case: Case, opts: &Options) -> String;
+
+    /// Returns a value which displays the input string in the specified case style, without
+    /// allocating a new string.
+    ///
+    /// ```rust
+    ///     use stringcase::{Case, Caser};
+    ///
+    ///     let s = format!("name: {}", "fooBar100Baz".display_as(Case::Snake));
+    ///     assert_eq!(s, "name: foo_bar100_baz");
+    /// ```
+    fn display_as(&self, case: Case) -> CaseDisplay<'_, 'static>;
+
+    /// Returns a value which displays the input string in the specified case style with the
+    /// specified options, without allocating a new string.
+    ///
+    /// ```rust
+    ///     use stringcase::{Case, Caser};
+    ///
+    ///     let opts = stringcase::Options{
+    ///       separate_before_non_alphabets: true,
+    ///       ..Default::default()
+    ///     };
+    ///     let s = format!("name: {}", "fooBar100Baz".display_as_with_options(Case::Snake, &opts));
+    ///     assert_eq!(s, "name: foo_bar_100_baz");
+    /// ```
+    fn display_as_with_options<'a, 'o>(
+        &'a self,
+        case: Case,
+        opts: &'o Options<'o>,
+    ) -> CaseDisplay<'a, 'o>;
+
+    // case detection
+
+    /// Checks whether the input string is in Ada case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("Foo_Bar100_Baz".is_ada_case());
+    ///     assert!(!"foo_bar100_baz".is_ada_case());
+    /// ```
+    fn is_ada_case(&self) -> bool;
+
+    /// Checks whether the input string is in Ada case with the specified options.
+    fn is_ada_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in camel case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("fooBar100Baz".is_camel_case());
+    ///     assert!(!"foo_bar100_baz".is_camel_case());
+    /// ```
+    fn is_camel_case(&self) -> bool;
+
+    /// Checks whether the input string is in camel case with the specified options.
+    fn is_camel_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in cobol case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("FOO-BAR100-BAZ".is_cobol_case());
+    ///     assert!(!"fooBar100Baz".is_cobol_case());
+    /// ```
+    fn is_cobol_case(&self) -> bool;
+
+    /// Checks whether the input string is in cobol case with the specified options.
+    fn is_cobol_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in kebab case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("foo-bar100-baz".is_kebab_case());
+    ///     assert!(!"fooBar100Baz".is_kebab_case());
+    /// ```
+    fn is_kebab_case(&self) -> bool;
+
+    /// Checks whether the input string is in kebab case with the specified options.
+    fn is_kebab_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in macro case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("FOO_BAR100_BAZ".is_macro_case());
+    ///     assert!(!"fooBar100Baz".is_macro_case());
+    /// ```
+    fn is_macro_case(&self) -> bool;
+
+    /// Checks whether the input string is in macro case with the specified options.
+    fn is_macro_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in pascal case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("FooBar100Baz".is_pascal_case());
+    ///     assert!(!"foo_bar100_baz".is_pascal_case());
+    /// ```
+    fn is_pascal_case(&self) -> bool;
+
+    /// Checks whether the input string is in pascal case with the specified options.
+    fn is_pascal_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in snake case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("foo_bar100_baz".is_snake_case());
+    ///     assert!(!"fooBar100Baz".is_snake_case());
+    /// ```
+    fn is_snake_case(&self) -> bool;
+
+    /// Checks whether the input string is in snake case with the specified options.
+    fn is_snake_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in title case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("Foo Bar100 Baz".is_title_case());
+    ///     assert!(!"fooBar100Baz".is_title_case());
+    /// ```
+    fn is_title_case(&self) -> bool;
+
+    /// Checks whether the input string is in title case with the specified options.
+    fn is_title_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Checks whether the input string is in train case.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     assert!("Foo-Bar100-Baz".is_train_case());
+    ///     assert!(!"fooBar100Baz".is_train_case());
+    /// ```
+    fn is_train_case(&self) -> bool;
+
+    /// Checks whether the input string is in train case with the specified options.
+    fn is_train_case_with_options(&self, opts: &Options) -> bool;
+
+    /// Detects the case style of the input string.
+    ///
+    /// ```rust
+    ///     use stringcase::{Case, Caser};
+    ///
+    ///     assert_eq!("fooBar100Baz".detect_case(), Some(Case::Camel));
+    ///     assert_eq!("foo_barBaz".detect_case(), None);
+    /// ```
+    fn detect_case(&self) -> Option<Case>;
+
+    /// Detects the case style of the input string with the specified options.
+    fn detect_case_with_options(&self, opts: &Options) -> Option<Case>;
+
+    // words
+
+    /// Splits the input string into words.
+    ///
+    /// It treats the end of a sequence of non-alphabetic characters as a word boundary,
+    /// but not the beginning.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     let words: Vec<&str> = "fooBar100_baz".words().map(|w| w.text).collect();
+    ///     assert_eq!(words, ["foo", "Bar100", "baz"]);
+    /// ```
+    fn words(&self) -> Words<'_, 'static>;
+
+    /// Splits the input string into words with the specified options.
+    ///
+    /// ```rust
+    ///     use stringcase::Caser;
+    ///
+    ///     let opts = stringcase::Options{
+    ///       separate_before_non_alphabets: true,
+    ///       ..Default::default()
+    ///     };
+    ///     let words: Vec<&str> = "fooBar100_baz".words_with_options(&opts).map(|w| w.text).collect();
+    ///     assert_eq!(words, ["foo", "Bar", "100", "baz"]);
+    /// ```
+    fn words_with_options<'a, 'o>(&'a self, opts: &'o Options<'o>) -> Words<'a, 'o>;
 }
 
 impl<T: AsRef<str>> Caser<T> for T {
+    // ada case
+
+    #[inline(always)]
+    fn to_ada_case(&self) -> String {
+        let opts = Options {
+            separate_before_non_alphabets: false,
+            separate_after_non_alphabets: true,
+            separators: "",
+            keep: "",
+            ..Default::default()
+        };
+        ada_case_with_options(self.as_ref(), &opts)
+    }
+
+    #[inline(always)]
+    fn to_ada_case_with_options(&self, opts: &Options) -> String {
+        ada_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn to_ada_case_cow(&self) -> Cow<'_, str> {
+        ada_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_ada_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        ada_case_cow_with_options(self.as_ref(), opts)
+    }
+
     // camel case
 
     #[inline(always)]
@@ -391,6 +831,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         camel_case_with_options(self.as_ref(), &opts)
     }
@@ -400,6 +841,16 @@ impl<T: AsRef<str>> Caser<T> for T {
         camel_case_with_options(self.as_ref(), opts)
     }
 
+    #[inline(always)]
+    fn to_camel_case_cow(&self) -> Cow<'_, str> {
+        camel_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_camel_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        camel_case_cow_with_options(self.as_ref(), opts)
+    }
+
     #[inline(always)]
     fn to_camel_case_with_sep(&self, seps: &str) -> String {
         let opts = Options {
@@ -407,6 +858,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: seps,
             keep: "",
+            ..Default::default()
         };
         camel_case_with_options(self.as_ref(), &opts)
     }
@@ -418,6 +870,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: kept,
+            ..Default::default()
         };
         camel_case_with_options(self.as_ref(), &opts)
     }
@@ -431,6 +884,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         cobol_case_with_options(self.as_ref(), &opts)
     }
@@ -440,6 +894,16 @@ impl<T: AsRef<str>> Caser<T> for T {
         cobol_case_with_options(self.as_ref(), opts)
     }
 
+    #[inline(always)]
+    fn to_cobol_case_cow(&self) -> Cow<'_, str> {
+        cobol_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_cobol_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        cobol_case_cow_with_options(self.as_ref(), opts)
+    }
+
     #[inline(always)]
     fn to_cobol_case_with_nums_as_word(&self) -> String {
         let opts = Options {
@@ -447,6 +911,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         cobol_case_with_options(self.as_ref(), &opts)
     }
@@ -458,6 +923,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: seps,
             keep: "",
+            ..Default::default()
         };
         cobol_case_with_options(self.as_ref(), &opts)
     }
@@ -469,6 +935,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: kept,
+            ..Default::default()
         };
         cobol_case_with_options(self.as_ref(), &opts)
     }
@@ -482,6 +949,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         kebab_case_with_options(self.as_ref(), &opts)
     }
@@ -491,6 +959,16 @@ impl<T: AsRef<str>> Caser<T> for T {
         kebab_case_with_options(self.as_ref(), opts)
     }
 
+    #[inline(always)]
+    fn to_kebab_case_cow(&self) -> Cow<'_, str> {
+        kebab_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_kebab_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        kebab_case_cow_with_options(self.as_ref(), opts)
+    }
+
     #[inline(always)]
     fn to_kebab_case_with_nums_as_word(&self) -> String {
         let opts = Options {
@@ -498,6 +976,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         kebab_case_with_options(self.as_ref(), &opts)
     }
@@ -509,6 +988,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: seps,
             keep: "",
+            ..Default::default()
         };
         kebab_case_with_options(self.as_ref(), &opts)
     }
@@ -520,6 +1000,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: kept,
+            ..Default::default()
         };
         kebab_case_with_options(self.as_ref(), &opts)
     }
@@ -533,6 +1014,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         macro_case_with_options(self.as_ref(), &opts)
     }
@@ -542,6 +1024,16 @@ impl<T: AsRef<str>> Caser<T> for T {
         macro_case_with_options(self.as_ref(), opts)
     }
 
+    #[inline(always)]
+    fn to_macro_case_cow(&self) -> Cow<'_, str> {
+        macro_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_macro_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        macro_case_cow_with_options(self.as_ref(), opts)
+    }
+
     #[inline(always)]
     fn to_macro_case_with_nums_as_word(&self) -> String {
         let opts = Options {
@@ -549,6 +1041,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         macro_case_with_options(self.as_ref(), &opts)
     }
@@ -560,6 +1053,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: seps,
             keep: "",
+            ..Default::default()
         };
         macro_case_with_options(self.as_ref(), &opts)
     }
@@ -571,6 +1065,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: kept,
+            ..Default::default()
         };
         macro_case_with_options(self.as_ref(), &opts)
     }
@@ -584,6 +1079,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         pascal_case_with_options(self.as_ref(), &opts)
     }
@@ -593,6 +1089,16 @@ impl<T: AsRef<str>> Caser<T> for T {
         pascal_case_with_options(self.as_ref(), opts)
     }
 
+    #[inline(always)]
+    fn to_pascal_case_cow(&self) -> Cow<'_, str> {
+        pascal_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_pascal_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        pascal_case_cow_with_options(self.as_ref(), opts)
+    }
+
     #[inline(always)]
     fn to_pascal_case_with_sep(&self, seps: &str) -> String {
         let opts = Options {
@@ -600,6 +1106,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: seps,
             keep: "",
+            ..Default::default()
         };
         pascal_case_with_options(self.as_ref(), &opts)
     }
@@ -611,6 +1118,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: kept,
+            ..Default::default()
         };
         pascal_case_with_options(self.as_ref(), &opts)
     }
@@ -624,6 +1132,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         snake_case_with_options(self.as_ref(), &opts)
     }
@@ -633,6 +1142,16 @@ impl<T: AsRef<str>> Caser<T> for T {
         snake_case_with_options(self.as_ref(), opts)
     }
 
+    #[inline(always)]
+    fn to_snake_case_cow(&self) -> Cow<'_, str> {
+        snake_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_snake_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        snake_case_cow_with_options(self.as_ref(), opts)
+    }
+
     #[inline(always)]
     fn to_snake_case_with_nums_as_word(&self) -> String {
         let opts = Options {
@@ -640,6 +1159,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         snake_case_with_options(self.as_ref(), &opts)
     }
@@ -651,6 +1171,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: seps,
             keep: "",
+            ..Default::default()
         };
         snake_case_with_options(self.as_ref(), &opts)
     }
@@ -662,10 +1183,40 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: kept,
+            ..Default::default()
         };
         snake_case_with_options(self.as_ref(), &opts)
     }
 
+    // title case
+
+    #[inline(always)]
+    fn to_title_case(&self) -> String {
+        let opts = Options {
+            separate_before_non_alphabets: false,
+            separate_after_non_alphabets: true,
+            separators: "",
+            keep: "",
+            ..Default::default()
+        };
+        title_case_with_options(self.as_ref(), &opts)
+    }
+
+    #[inline(always)]
+    fn to_title_case_with_options(&self, opts: &Options) -> String {
+        title_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn to_title_case_cow(&self) -> Cow<'_, str> {
+        title_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_title_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        title_case_cow_with_options(self.as_ref(), opts)
+    }
+
     // train case
 
     #[inline(always)]
@@ -675,6 +1226,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         train_case_with_options(self.as_ref(), &opts)
     }
@@ -684,6 +1236,16 @@ impl<T: AsRef<str>> Caser<T> for T {
         train_case_with_options(self.as_ref(), opts)
     }
 
+    #[inline(always)]
+    fn to_train_case_cow(&self) -> Cow<'_, str> {
+        train_case_cow(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn to_train_case_cow_with_options(&self, opts: &Options) -> Cow<'_, str> {
+        train_case_cow_with_options(self.as_ref(), opts)
+    }
+
     #[inline(always)]
     fn to_train_case_with_nums_as_word(&self) -> String {
         let opts = Options {
@@ -691,6 +1253,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
         train_case_with_options(self.as_ref(), &opts)
     }
@@ -702,6 +1265,7 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: seps,
             keep: "",
+            ..Default::default()
         };
         train_case_with_options(self.as_ref(), &opts)
     }
@@ -713,15 +1277,203 @@ impl<T: AsRef<str>> Caser<T> for T {
             separate_after_non_alphabets: true,
             separators: "",
             keep: kept,
+            ..Default::default()
         };
         train_case_with_options(self.as_ref(), &opts)
     }
+
+    // capitalize, lowerize, and upperize
+
+    #[inline(always)]
+    fn to_capitalized<const JOINER: char>(&self, opts: &Options) -> String {
+        capitalize::<JOINER>(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn to_lowerized<const JOINER: char>(&self, opts: &Options) -> String {
+        lowerize::<JOINER>(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn to_upperized<const JOINER: char>(&self, opts: &Options) -> String {
+        upperize::<JOINER>(self.as_ref(), opts)
+    }
+
+    // any case
+
+    #[inline(always)]
+    fn to_case(&self, case: Case) -> String {
+        convert(self.as_ref(), case, &DEFAULT_OPTIONS)
+    }
+
+    #[inline(always)]
+    fn to_case_with_options(&self, case: Case, opts: &Options) -> String {
+        convert(self.as_ref(), case, opts)
+    }
+
+    #[inline(always)]
+    fn display_as(&self, case: Case) -> CaseDisplay<'_, 'static> {
+        CaseDisplay::new(self.as_ref(), case, &DEFAULT_OPTIONS)
+    }
+
+    #[inline(always)]
+    fn display_as_with_options<'a, 'o>(
+        &'a self,
+        case: Case,
+        opts: &'o Options<'o>,
+    ) -> CaseDisplay<'a, 'o> {
+        CaseDisplay::new(self.as_ref(), case, opts)
+    }
+
+    // case detection
+
+    #[inline(always)]
+    fn is_ada_case(&self) -> bool {
+        is_ada_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_ada_case_with_options(&self, opts: &Options) -> bool {
+        is_ada_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_camel_case(&self) -> bool {
+        is_camel_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_camel_case_with_options(&self, opts: &Options) -> bool {
+        is_camel_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_cobol_case(&self) -> bool {
+        is_cobol_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_cobol_case_with_options(&self, opts: &Options) -> bool {
+        is_cobol_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_kebab_case(&self) -> bool {
+        is_kebab_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_kebab_case_with_options(&self, opts: &Options) -> bool {
+        is_kebab_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_macro_case(&self) -> bool {
+        is_macro_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_macro_case_with_options(&self, opts: &Options) -> bool {
+        is_macro_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_pascal_case(&self) -> bool {
+        is_pascal_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_pascal_case_with_options(&self, opts: &Options) -> bool {
+        is_pascal_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_snake_case(&self) -> bool {
+        is_snake_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_snake_case_with_options(&self, opts: &Options) -> bool {
+        is_snake_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_title_case(&self) -> bool {
+        is_title_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_title_case_with_options(&self, opts: &Options) -> bool {
+        is_title_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn is_train_case(&self) -> bool {
+        is_train_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn is_train_case_with_options(&self, opts: &Options) -> bool {
+        is_train_case_with_options(self.as_ref(), opts)
+    }
+
+    #[inline(always)]
+    fn detect_case(&self) -> Option<Case> {
+        detect_case(self.as_ref())
+    }
+
+    #[inline(always)]
+    fn detect_case_with_options(&self, opts: &Options) -> Option<Case> {
+        detect_case_with_options(self.as_ref(), opts)
+    }
+
+    // words
+
+    #[inline(always)]
+    fn words(&self) -> Words<'_, 'static> {
+        words(self.as_ref(), &DEFAULT_OPTIONS)
+    }
+
+    #[inline(always)]
+    fn words_with_options<'a, 'o>(&'a self, opts: &'o Options<'o>) -> Words<'a, 'o> {
+        words(self.as_ref(), opts)
+    }
 }
 
 #[cfg(test)]
 mod tests_of_caser {
     use super::*;
 
+    // ada case
+
+    #[test]
+    fn it_should_convert_to_ada_case() {
+        let result = "foo_bar100%BAZQux".to_ada_case();
+        assert_eq!(result, "Foo_Bar100_Baz_Qux");
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result = string.to_ada_case();
+        assert_eq!(result, "Foo_Bar100_Baz_Qux");
+    }
+
+    #[test]
+    fn it_should_convert_to_ada_case_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            separate_after_non_alphabets: true,
+            separators: "",
+            keep: "%",
+            ..Default::default()
+        };
+
+        let result = "foo_bar100%BAZQux".to_ada_case_with_options(&opts);
+        assert_eq!(result, "Foo_Bar_100%_Baz_Qux");
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result = string.to_ada_case_with_options(&opts);
+        assert_eq!(result, "Foo_Bar_100%_Baz_Qux");
+    }
+
     // camel case
 
     #[test]
@@ -741,6 +1493,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "_",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_camel_case_with_options(&opts);
@@ -758,6 +1511,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "%",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_camel_case_with_options(&opts);
@@ -787,6 +1541,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_cobol_case_with_options(&opts);
@@ -804,6 +1559,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "_",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_cobol_case_with_options(&opts);
@@ -821,6 +1577,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "%",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_cobol_case_with_options(&opts);
@@ -850,6 +1607,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_kebab_case_with_options(&opts);
@@ -867,6 +1625,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "_",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_kebab_case_with_options(&opts);
@@ -884,6 +1643,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "%",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_kebab_case_with_options(&opts);
@@ -913,6 +1673,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_macro_case_with_options(&opts);
@@ -930,6 +1691,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "_",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_macro_case_with_options(&opts);
@@ -947,6 +1709,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "%",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_macro_case_with_options(&opts);
@@ -976,6 +1739,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_pascal_case_with_options(&opts);
@@ -993,6 +1757,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "_",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_pascal_case_with_options(&opts);
@@ -1010,6 +1775,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "%",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_pascal_case_with_options(&opts);
@@ -1039,6 +1805,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_snake_case_with_options(&opts);
@@ -1056,6 +1823,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "_",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_snake_case_with_options(&opts);
@@ -1073,6 +1841,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "%",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_snake_case_with_options(&opts);
@@ -1083,6 +1852,36 @@ mod tests_of_caser {
         assert_eq!(result, "foo_bar100%_baz_qux");
     }
 
+    // title case
+
+    #[test]
+    fn it_should_convert_to_title_case() {
+        let result = "foo_bar100%BAZQux".to_title_case();
+        assert_eq!(result, "Foo Bar100 Baz Qux");
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result = string.to_title_case();
+        assert_eq!(result, "Foo Bar100 Baz Qux");
+    }
+
+    #[test]
+    fn it_should_convert_to_title_case_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            separate_after_non_alphabets: true,
+            separators: "",
+            keep: "%",
+            ..Default::default()
+        };
+
+        let result = "foo_bar100%BAZQux".to_title_case_with_options(&opts);
+        assert_eq!(result, "Foo Bar 100% Baz Qux");
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result = string.to_title_case_with_options(&opts);
+        assert_eq!(result, "Foo Bar 100% Baz Qux");
+    }
+
     // train case
 
     #[test]
@@ -1102,6 +1901,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_train_case_with_options(&opts);
@@ -1119,6 +1919,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "_",
             keep: "",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_train_case_with_options(&opts);
@@ -1136,6 +1937,7 @@ mod tests_of_caser {
             separate_after_non_alphabets: true,
             separators: "",
             keep: "%",
+            ..Default::default()
         };
 
         let result = "foo_bar100%BAZQux".to_train_case_with_options(&opts);
@@ -1145,4 +1947,252 @@ mod tests_of_caser {
         let result = string.to_train_case_with_options(&opts);
         assert_eq!(result, "Foo-Bar100%-Baz-Qux");
     }
+
+    // words
+
+    #[test]
+    fn it_should_split_into_words() {
+        let result: Vec<&str> = "foo_bar100%BAZQux".words().map(|w| w.text).collect();
+        assert_eq!(result, ["foo", "bar100", "BAZ", "Qux"]);
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result: Vec<&str> = string.words().map(|w| w.text).collect();
+        assert_eq!(result, ["foo", "bar100", "BAZ", "Qux"]);
+    }
+
+    #[test]
+    fn it_should_split_into_words_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            separate_after_non_alphabets: true,
+            separators: "",
+            keep: "%",
+            ..Default::default()
+        };
+
+        let result: Vec<&str> = "foo_bar100%BAZQux"
+            .words_with_options(&opts)
+            .map(|w| w.text)
+            .collect();
+        assert_eq!(result, ["foo", "bar", "100%", "BAZ", "Qux"]);
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result: Vec<&str> = string.words_with_options(&opts).map(|w| w.text).collect();
+        assert_eq!(result, ["foo", "bar", "100%", "BAZ", "Qux"]);
+    }
+
+    // cow
+
+    #[test]
+    fn it_should_convert_to_cow() {
+        assert!(matches!(
+            "foo_bar100_baz".to_snake_case_cow(),
+            Cow::Borrowed("foo_bar100_baz")
+        ));
+        assert!(matches!(
+            "Foo_Bar100_Baz".to_ada_case_cow(),
+            Cow::Borrowed("Foo_Bar100_Baz")
+        ));
+        assert!(matches!(
+            "Foo Bar100 Baz".to_title_case_cow(),
+            Cow::Borrowed("Foo Bar100 Baz")
+        ));
+
+        let string = String::from("foo_bar100%BAZQux");
+        assert_eq!(string.to_camel_case_cow(), "fooBar100BazQux");
+        assert_eq!(string.to_cobol_case_cow(), "FOO-BAR100-BAZ-QUX");
+        assert_eq!(string.to_kebab_case_cow(), "foo-bar100-baz-qux");
+        assert_eq!(string.to_macro_case_cow(), "FOO_BAR100_BAZ_QUX");
+        assert_eq!(string.to_pascal_case_cow(), "FooBar100BazQux");
+        assert_eq!(string.to_train_case_cow(), "Foo-Bar100-Baz-Qux");
+        assert!(matches!(string.to_snake_case_cow(), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn it_should_convert_to_cow_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        assert!(matches!(
+            "foo-bar-100%-baz".to_kebab_case_cow_with_options(&opts),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            "foo-bar100%-baz".to_kebab_case_cow_with_options(&opts),
+            Cow::Owned(_)
+        ));
+
+        let string = String::from("foo_bar100%BAZQux");
+        assert_eq!(
+            string.to_ada_case_cow_with_options(&opts),
+            "Foo_Bar_100%_Baz_Qux"
+        );
+        assert_eq!(
+            string.to_camel_case_cow_with_options(&opts),
+            "fooBar100%BazQux"
+        );
+        assert_eq!(
+            string.to_cobol_case_cow_with_options(&opts),
+            "FOO-BAR-100%-BAZ-QUX"
+        );
+        assert_eq!(
+            string.to_macro_case_cow_with_options(&opts),
+            "FOO_BAR_100%_BAZ_QUX"
+        );
+        assert_eq!(
+            string.to_pascal_case_cow_with_options(&opts),
+            "FooBar100%BazQux"
+        );
+        assert_eq!(
+            string.to_snake_case_cow_with_options(&opts),
+            "foo_bar_100%_baz_qux"
+        );
+        assert_eq!(
+            string.to_title_case_cow_with_options(&opts),
+            "Foo Bar 100% Baz Qux"
+        );
+        assert_eq!(
+            string.to_train_case_cow_with_options(&opts),
+            "Foo-Bar-100%-Baz-Qux"
+        );
+    }
+
+    // capitalize, lowerize, and upperize
+
+    #[test]
+    fn it_should_convert_to_capitalized_words() {
+        let opts = Options::default();
+        let result = "foo_bar100%BAZQux".to_capitalized::<'.'>(&opts);
+        assert_eq!(result, "Foo.Bar100.Baz.Qux");
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result = string.to_capitalized::<' '>(&opts);
+        assert_eq!(result, "Foo Bar100 Baz Qux");
+    }
+
+    #[test]
+    fn it_should_convert_to_lowerized_words() {
+        let opts = Options::default();
+        let result = "foo_bar100%BAZQux".to_lowerized::<'.'>(&opts);
+        assert_eq!(result, "foo.bar100.baz.qux");
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result = string.to_lowerized::<'/'>(&opts);
+        assert_eq!(result, "foo/bar100/baz/qux");
+    }
+
+    #[test]
+    fn it_should_convert_to_upperized_words() {
+        let opts = Options::default();
+        let result = "foo_bar100%BAZQux".to_upperized::<'.'>(&opts);
+        assert_eq!(result, "FOO.BAR100.BAZ.QUX");
+
+        let string = String::from("foo_bar100%BAZQux");
+        let result = string.to_upperized::<':'>(&opts);
+        assert_eq!(result, "FOO:BAR100:BAZ:QUX");
+    }
+
+    // any case
+
+    #[test]
+    fn it_should_convert_to_any_case() {
+        let case: Case = "SCREAMING_SNAKE".parse().unwrap();
+        assert_eq!("fooBar100%BAZQux".to_case(case), "FOO_BAR100_BAZ_QUX");
+
+        let string = String::from("fooBar100%BAZQux");
+        assert_eq!(string.to_case(Case::Train), "Foo-Bar100-Baz-Qux");
+    }
+
+    #[test]
+    fn it_should_convert_to_any_case_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let result = "fooBar100%BAZQux".to_case_with_options(Case::Kebab, &opts);
+        assert_eq!(result, "foo-bar-100%-baz-qux");
+
+        let string = String::from("fooBar100%BAZQux");
+        let result = string.to_case_with_options(Case::Pascal, &opts);
+        assert_eq!(result, "FooBar100%BazQux");
+    }
+
+    #[test]
+    fn it_should_display_as_any_case() {
+        let s = format!("<{}>", "fooBar100%BAZQux".display_as(Case::Cobol));
+        assert_eq!(s, "<FOO-BAR100-BAZ-QUX>");
+
+        let string = String::from("fooBar100%BAZQux");
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let s = format!("<{}>", string.display_as_with_options(Case::Title, &opts));
+        assert_eq!(s, "<Foo Bar 100% Baz Qux>");
+    }
+
+    // case detection
+
+    #[test]
+    fn it_should_check_cases() {
+        assert!("Foo_Bar100_Baz".is_ada_case());
+        assert!("fooBar100Baz".is_camel_case());
+        assert!("FOO-BAR100-BAZ".is_cobol_case());
+        assert!("foo-bar100-baz".is_kebab_case());
+        assert!("FOO_BAR100_BAZ".is_macro_case());
+        assert!("FooBar100Baz".is_pascal_case());
+        assert!("foo_bar100_baz".is_snake_case());
+        assert!("Foo Bar100 Baz".is_title_case());
+        assert!("Foo-Bar100-Baz".is_train_case());
+
+        let string = String::from("foo_bar100_baz");
+        assert!(string.is_snake_case());
+        assert!(!string.is_camel_case());
+        assert!(!string.is_kebab_case());
+    }
+
+    #[test]
+    fn it_should_check_cases_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!("Foo_Bar_100_Baz".is_ada_case_with_options(&opts));
+        assert!(!"Foo_Bar100_Baz".is_ada_case_with_options(&opts));
+        assert!("FOO-BAR-100-BAZ".is_cobol_case_with_options(&opts));
+        assert!("foo-bar-100-baz".is_kebab_case_with_options(&opts));
+        assert!("FOO_BAR_100_BAZ".is_macro_case_with_options(&opts));
+        assert!("foo_bar_100_baz".is_snake_case_with_options(&opts));
+        assert!("Foo Bar 100 Baz".is_title_case_with_options(&opts));
+        assert!("Foo-Bar-100-Baz".is_train_case_with_options(&opts));
+
+        let opts = Options {
+            keep: "_",
+            ..Default::default()
+        };
+        assert!("foo_Bar".is_camel_case_with_options(&opts));
+        assert!("Foo_Bar".is_pascal_case_with_options(&opts));
+        assert!(!"foo_bar".is_pascal_case_with_options(&opts));
+    }
+
+    #[test]
+    fn it_should_detect_case() {
+        assert_eq!("fooBar100Baz".detect_case(), Some(Case::Camel));
+        assert_eq!(String::from("FOO_BAR").detect_case(), Some(Case::Macro));
+        assert_eq!("foo_barBaz".detect_case(), None);
+
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            "foo-bar-100-baz".detect_case_with_options(&opts),
+            Some(Case::Kebab)
+        );
+        assert_eq!("foo-bar100-baz".detect_case_with_options(&opts), None);
+    }
 }
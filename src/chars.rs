@@ -0,0 +1,120 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+// Character classification and case mapping shared by all conversions.
+//
+// If `unicode` is `false`, only ASCII letters and digits are treated as letters and digits.
+// If `unicode` is `true`, all Unicode uppercase letters are treated as uppercase letters, and all
+// other Unicode alphabetic characters (lowercase letters, titlecase letters, and letters without
+// case like CJK ideographs) are treated as lowercase letters, which are never converted to
+// lowercase but are converted to uppercase if they have uppercase mappings.
+
+use crate::render::Sink;
+
+#[inline(always)]
+pub(crate) fn is_upper(ch: char, unicode: bool) -> bool {
+    if ch.is_ascii() || !unicode {
+        ch.is_ascii_uppercase()
+    } else {
+        ch.is_uppercase()
+    }
+}
+
+#[inline(always)]
+pub(crate) fn is_lower(ch: char, unicode: bool) -> bool {
+    if ch.is_ascii() || !unicode {
+        ch.is_ascii_lowercase()
+    } else {
+        ch.is_alphabetic() && !ch.is_uppercase()
+    }
+}
+
+#[inline(always)]
+pub(crate) fn is_digit(ch: char, unicode: bool) -> bool {
+    if ch.is_ascii() || !unicode {
+        ch.is_ascii_digit()
+    } else {
+        ch.is_numeric()
+    }
+}
+
+#[inline(always)]
+pub(crate) fn push_upper<S: Sink>(s: &mut S, ch: char, unicode: bool) {
+    if ch.is_ascii() || !unicode {
+        s.push(ch.to_ascii_uppercase());
+    } else {
+        for c in ch.to_uppercase() {
+            s.push(c);
+        }
+    }
+}
+
+#[inline(always)]
+pub(crate) fn push_lower<S: Sink>(s: &mut S, ch: char, unicode: bool) {
+    if ch.is_ascii() || !unicode {
+        s.push(ch.to_ascii_lowercase());
+    } else {
+        for c in ch.to_lowercase() {
+            s.push(c);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_chars {
+    use super::*;
+
+    #[test]
+    fn test_of_is_upper() {
+        assert!(is_upper('A', false));
+        assert!(is_upper('A', true));
+        assert!(!is_upper('a', true));
+        assert!(!is_upper('É', false));
+        assert!(is_upper('É', true));
+        assert!(!is_upper('é', true));
+        assert!(!is_upper('日', true));
+    }
+
+    #[test]
+    fn test_of_is_lower() {
+        assert!(is_lower('a', false));
+        assert!(is_lower('a', true));
+        assert!(!is_lower('A', true));
+        assert!(!is_lower('ß', false));
+        assert!(is_lower('ß', true));
+        assert!(!is_lower('É', true));
+        assert!(is_lower('日', true));
+        assert!(!is_lower('1', true));
+        assert!(!is_lower('-', true));
+    }
+
+    #[test]
+    fn test_of_is_digit() {
+        assert!(is_digit('1', false));
+        assert!(is_digit('1', true));
+        assert!(!is_digit('٣', false));
+        assert!(is_digit('٣', true));
+        assert!(!is_digit('a', true));
+    }
+
+    #[test]
+    fn test_of_push_upper() {
+        let mut s = String::new();
+        push_upper(&mut s, 'a', false);
+        push_upper(&mut s, 'é', false);
+        push_upper(&mut s, 'é', true);
+        push_upper(&mut s, 'ß', true);
+        assert_eq!(s, "AéÉSS");
+    }
+
+    #[test]
+    fn test_of_push_lower() {
+        let mut s = String::new();
+        push_lower(&mut s, 'A', false);
+        push_lower(&mut s, 'É', false);
+        push_lower(&mut s, 'É', true);
+        push_lower(&mut s, 'İ', true);
+        assert_eq!(s, "aÉéi\u{307}");
+    }
+}
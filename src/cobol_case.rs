@@ -2,9 +2,17 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+#[cfg(feature = "alloc")]
+use crate::render::render_cow;
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, COBOL};
+#[cfg(feature = "alloc")]
 use crate::upperize::upperize;
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
+
 /// Converts the input string to cobol case with the specified options.
 ///
 /// ```rust
@@ -13,10 +21,12 @@ use crate::upperize::upperize;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let cobol = stringcase::cobol_case_with_options("fooBar123Baz", &opts);
 ///     assert_eq!(cobol, "FOO-BAR-123-BAZ");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn cobol_case_with_options(input: &str, opts: &Options) -> String {
     upperize::<'-'>(input, opts)
@@ -31,6 +41,7 @@ pub fn cobol_case_with_options(input: &str, opts: &Options) -> String {
 ///     let cobol = stringcase::cobol_case("fooBar123Baz");
 ///     assert_eq!(cobol, "FOO-BAR123-BAZ");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn cobol_case(input: &str) -> String {
     let opts = Options {
@@ -38,11 +49,173 @@ pub fn cobol_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     upperize::<'-'>(input, &opts)
 }
 
+/// Converts the input string to cobol case with the specified options, without allocating a new
+/// string if the input string is already in cobol case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`cobol_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     let cobol = stringcase::cobol_case_cow_with_options("FOO-BAR-100-BAZ", &opts);
+///     assert!(matches!(cobol, Cow::Borrowed("FOO-BAR-100-BAZ")));
+///
+///     let cobol = stringcase::cobol_case_cow_with_options("FOO-BAR100-BAZ", &opts);
+///     assert!(matches!(cobol, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn cobol_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, COBOL)
+}
+
+/// Converts the input string to cobol case, without allocating a new string if the input string
+/// is already in cobol case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`cobol_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let cobol = stringcase::cobol_case_cow("FOO-BAR100-BAZ");
+///     assert!(matches!(cobol, Cow::Borrowed("FOO-BAR100-BAZ")));
+///
+///     let cobol = stringcase::cobol_case_cow("FOO_BAR100_BAZ");
+///     assert_eq!(cobol, stringcase::cobol_case("FOO_BAR100_BAZ"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn cobol_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, COBOL)
+}
+
+/// Converts the input string to cobol case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`cobol_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::cobol_case_into("FOO_BAR100_BAZ", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: FOO-BAR100-BAZ");
+/// ```
+#[inline(always)]
+pub fn cobol_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, COBOL)
+}
+
+/// Converts the input string to cobol case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`cobol_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::cobol_case_to_slice("FOO_BAR100_BAZ", &opts, &mut buf);
+///     assert_eq!(result, Ok("FOO-BAR100-BAZ"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::cobol_case_to_slice("FOO_BAR100_BAZ", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 14);
+/// ```
+#[inline(always)]
+pub fn cobol_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, COBOL)
+}
+
+/// A wrapper of a string slice which is displayed in cobol case.
+///
+/// It converts the string while formatting it in the same way as [`cobol_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::CobolCase;
+///
+///     let s = format!("name: {}", CobolCase("FOO_BAR100_BAZ"));
+///     assert_eq!(s, "name: FOO-BAR100-BAZ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CobolCase<'a>(pub &'a str);
+
+impl fmt::Display for CobolCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, COBOL)
+    }
+}
+
+/// Checks whether the input string is in cobol case with the specified options.
+///
+/// It returns `true` if converting the input string with [`cobol_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_cobol_case_with_options("FOO-BAR-100-BAZ", &opts));
+///     assert!(!stringcase::is_cobol_case_with_options("FOO-BAR100-BAZ", &opts));
+/// ```
+#[inline(always)]
+pub fn is_cobol_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, COBOL)
+}
+
+/// Checks whether the input string is in cobol case.
+///
+/// It returns `true` if converting the input string with [`cobol_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_cobol_case("FOO-BAR100-BAZ"));
+///     assert!(!stringcase::is_cobol_case("FOO_BAR100_BAZ"));
+/// ```
+#[inline(always)]
+pub fn is_cobol_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, COBOL)
+}
+
 /// Converts the input string to cobol case with the specified separator characters.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use cobol_case_with_options instead")]
 #[inline(always)]
@@ -52,11 +225,13 @@ pub fn cobol_case_with_sep(input: &str, seps: &str) -> String {
         separate_after_non_alphabets: true,
         separators: seps,
         keep: "",
+        ..Default::default()
     };
     upperize::<'-'>(input, &opts)
 }
 
 /// Converts the input string to cobol case with the specified characters to be kept.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use cobol_case_with_options instead")]
 #[inline(always)]
@@ -66,6 +241,7 @@ pub fn cobol_case_with_keep(input: &str, kept: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: kept,
+        ..Default::default()
     };
     upperize::<'-'>(input, &opts)
 }
@@ -74,6 +250,7 @@ pub fn cobol_case_with_keep(input: &str, kept: &str) -> String {
 ///
 /// It treats the beginning and the end of a sequence of non-alphabetic characters as a word
 /// boundary.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use cobol_case_with_options instead")]
 #[inline(always)]
@@ -83,11 +260,12 @@ pub fn cobol_case_with_nums_as_word(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     upperize::<'-'>(input, &opts)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_cobol_case {
     use super::*;
 
@@ -155,7 +333,154 @@ mod tests_of_cobol_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_cobol_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_cobol_case() {
+        assert!(matches!(
+            cobol_case_cow("FOO-BAR100-BAZ"),
+            Cow::Borrowed("FOO-BAR100-BAZ")
+        ));
+        assert!(matches!(cobol_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_cobol_case() {
+        let result = cobol_case_cow("fooBar100Baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "FOO-BAR100-BAZ");
+
+        let result = cobol_case_cow("FOO-BAR100-BAZ_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, cobol_case("FOO-BAR100-BAZ_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["FOO-BAR100-BAZ", "fooBar100Baz", "FOO-BAR100-BAZ_", ""].iter() {
+            let result = cobol_case_cow_with_options(input, &opts);
+            assert_eq!(result, cobol_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_cobol_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        cobol_case_into("FOO_BAR100_BAZ", &opts, &mut out).unwrap();
+        cobol_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "FOO-BAR100-BAZ");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        cobol_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, cobol_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(CobolCase("FOO_BAR100_BAZ").to_string(), "FOO-BAR100-BAZ");
+        assert_eq!(format!("<{}>", CobolCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_cobol_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = cobol_case_to_slice("FOO_BAR100_BAZ", &opts, &mut buf);
+        assert_eq!(result, Ok("FOO-BAR100-BAZ"));
+
+        let result = cobol_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 14];
+        let result = cobol_case_to_slice("FOO_BAR100_BAZ", &opts, &mut buf);
+        assert_eq!(result, Ok("FOO-BAR100-BAZ"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 13];
+        let result = cobol_case_to_slice("FOO_BAR100_BAZ", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 14 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = cobol_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(cobol_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_cobol_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_cobol_case() {
+        assert!(is_cobol_case("FOO-BAR100-BAZ"));
+        assert!(is_cobol_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_cobol_case() {
+        assert!(!is_cobol_case("foo-bar"));
+        assert!(!is_cobol_case("FOO_BAR"));
+        assert!(!is_cobol_case("Foo-Bar"));
+        assert!(!is_cobol_case("_FOO-BAR100-BAZ"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_cobol_case_with_options("FOO-BAR-100-BAZ", &opts));
+        assert!(is_cobol_case_with_options("", &opts));
+        assert!(!is_cobol_case_with_options("FOO-BAR100-BAZ", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_cobol_case_with_options {
     use super::*;
 
@@ -169,6 +494,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -181,6 +507,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -193,6 +520,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -205,6 +533,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -217,6 +546,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -229,6 +559,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -241,6 +572,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -253,6 +585,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC-123-456DEF-G-89HI-JKL-MN-12");
@@ -265,6 +598,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC-DEF-GHI-JK-LM-NO");
@@ -277,6 +611,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC-456DEF");
@@ -295,6 +630,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -311,6 +647,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -323,6 +660,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -335,6 +673,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -347,6 +686,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -359,6 +699,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -371,6 +712,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -383,6 +725,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -395,6 +738,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123-456-DEF-G89-HI-JKL-MN12");
@@ -407,6 +751,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC-DEF-GHI-JK-LM-NO");
@@ -419,6 +764,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-ABC456-DEF");
@@ -437,6 +783,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -453,6 +800,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -465,6 +813,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -477,6 +826,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -489,6 +839,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -501,6 +852,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -513,6 +865,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -525,6 +878,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -537,6 +891,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC-123-456-DEF-G-89-HI-JKL-MN-12");
@@ -549,6 +904,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC-DEF-GHI-JK-LM-NO");
@@ -561,6 +917,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-ABC-456-DEF");
@@ -579,6 +936,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -595,6 +953,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -607,6 +966,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -619,6 +979,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -631,6 +992,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -643,6 +1005,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -655,6 +1018,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -667,6 +1031,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -679,6 +1044,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123-456DEF-G89HI-JKL-MN12");
@@ -691,6 +1057,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC-DEF-GHI-JK-LM-NO");
@@ -703,6 +1070,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -721,6 +1089,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -737,6 +1106,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -749,6 +1119,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -761,6 +1132,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -777,6 +1149,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -793,6 +1166,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -809,6 +1183,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -825,6 +1200,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -841,6 +1217,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC-123-456DEF-G-89HI-JKL-MN-12");
@@ -857,6 +1234,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC-~!-DEF-#-GHI-%-JK-LM-NO-?");
@@ -869,6 +1247,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC-456DEF");
@@ -887,6 +1266,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -903,6 +1283,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -915,6 +1296,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -927,6 +1309,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -943,6 +1326,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -959,6 +1343,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -975,6 +1360,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -991,6 +1377,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1007,6 +1394,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123-456-DEF-G89-HI-JKL-MN12");
@@ -1023,6 +1411,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-ABC~!-DEF#-GHI%-JK-LM-NO-?");
@@ -1035,6 +1424,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-ABC456-DEF");
@@ -1053,6 +1443,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1069,6 +1460,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1081,6 +1473,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1093,6 +1486,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1109,6 +1503,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1125,6 +1520,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1141,6 +1537,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1157,6 +1554,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1173,6 +1571,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC-123-456-DEF-G-89-HI-JKL-MN-12");
@@ -1189,6 +1588,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-ABC-~!-DEF-#-GHI-%-JK-LM-NO-?");
@@ -1201,6 +1601,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-ABC-456-DEF");
@@ -1219,6 +1620,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1235,6 +1637,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1247,6 +1650,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1259,6 +1663,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1275,6 +1680,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1291,6 +1697,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1307,6 +1714,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1323,6 +1731,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1339,6 +1748,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123-456DEF-G89HI-JKL-MN12");
@@ -1355,6 +1765,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC~!-DEF#-GHI%-JK-LM-NO-?");
@@ -1367,6 +1778,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -1385,6 +1797,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1401,6 +1814,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1413,6 +1827,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1425,6 +1840,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-_DEF-_GHI");
@@ -1441,6 +1857,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1457,6 +1874,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1473,6 +1891,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1489,6 +1908,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1505,6 +1925,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC-123-456DEF-G-89HI-JKL-MN-12");
@@ -1521,6 +1942,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: ".~!#%?",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC-~!-DEF-#-GHI-%-JK-LM-NO-?");
@@ -1533,6 +1955,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC-456DEF");
@@ -1551,6 +1974,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1567,6 +1991,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1579,6 +2004,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1591,6 +2017,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1607,6 +2034,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1623,6 +2051,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1639,6 +2068,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1655,6 +2085,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1671,6 +2102,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123-456-DEF-G89-HI-JKL-MN12");
@@ -1687,6 +2119,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: ".~!#%?",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-ABC~!-DEF#-GHI%-JK-LM-NO-?");
@@ -1699,6 +2132,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-ABC456-DEF");
@@ -1717,6 +2151,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1733,6 +2168,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1745,6 +2181,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1757,6 +2194,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1773,6 +2211,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1789,6 +2228,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1805,6 +2245,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1821,6 +2262,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1837,6 +2279,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC-123-456-DEF-G-89-HI-JKL-MN-12");
@@ -1853,6 +2296,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: ".~!#%?",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-ABC-~!-DEF-#-GHI-%-JK-LM-NO-?");
@@ -1865,6 +2309,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-ABC-456-DEF");
@@ -1883,6 +2328,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1899,6 +2345,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1911,6 +2358,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC-DEF-GH-IJK");
@@ -1923,6 +2371,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1939,6 +2388,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1955,6 +2405,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1971,6 +2422,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -1987,6 +2439,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC-DEF-GHI");
@@ -2003,6 +2456,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123-456DEF-G89HI-JKL-MN12");
@@ -2019,6 +2473,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: ".~!#%?",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC~!-DEF#-GHI%-JK-LM-NO-?");
@@ -2031,6 +2486,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -2049,6 +2505,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 keep: "-_",
                 separators: "",
+                ..Default::default()
             };
             let result = cobol_case_with_options("", &opts);
             assert_eq!(result, "");
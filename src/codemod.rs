@@ -0,0 +1,1338 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+//! Renames identifiers in source code into case styles.
+//!
+//! `Codemod` tokenizes source code of Rust, JavaScript, TypeScript, Python, or Go enough to find
+//! identifiers, string literals, and comments, and converts the identifiers which match a pattern
+//! into a case style.
+//! The contents of string literals and comments are not changed unless asked, and keywords and
+//! builtin names of the language are never renamed.
+//! The result can be printed as a unified diff.
+//!
+//! ```rust
+//! use stringcase::codemod::{Codemod, Language};
+//! use stringcase::Case;
+//!
+//! fn main() {
+//!     let source = "\
+//! // Reads userName from the response.
+//! const userName = response.userName ?? \"userName\";
+//! ";
+//!     let rewrite = Codemod::new(Language::JavaScript, Case::Snake)
+//!         .pattern("user*")
+//!         .rewrite(source);
+//!     assert_eq!(rewrite.output(), "\
+//! // Reads userName from the response.
+//! const user_name = response.user_name ?? \"userName\";
+//! ");
+//!     assert_eq!(rewrite.unified_diff("main.js"), "\
+//! --- a/main.js
+//! +++ b/main.js
+//! @@ -1,2 +1,2 @@
+//!  // Reads userName from the response.
+//! -const userName = response.userName ?? \"userName\";
+//! +const user_name = response.user_name ?? \"userName\";
+//! ");
+//! }
+//! ```
+
+use crate::case::Case;
+use crate::keywords;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{is_rendered, render_string};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::ops::Range;
+use core::str::FromStr;
+
+/// An enum that represents the programming languages which `Codemod` can tokenize.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Rust.
+    Rust,
+
+    /// JavaScript, including JSX.
+    JavaScript,
+
+    /// TypeScript, including TSX.
+    TypeScript,
+
+    /// Python.
+    Python,
+
+    /// Go.
+    Go,
+}
+
+impl Language {
+    /// All languages which `Codemod` can tokenize.
+    pub const ALL: [Language; 5] = [
+        Language::Rust,
+        Language::JavaScript,
+        Language::TypeScript,
+        Language::Python,
+        Language::Go,
+    ];
+
+    /// Returns the name of this language.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::Rust => "Rust",
+            Language::JavaScript => "JavaScript",
+            Language::TypeScript => "TypeScript",
+            Language::Python => "Python",
+            Language::Go => "Go",
+        }
+    }
+
+    /// Returns the language of source files with the specified extension, like `rs` or `py`, or
+    /// `None` if the extension is unknown.
+    ///
+    /// ```rust
+    /// use stringcase::codemod::Language;
+    ///
+    /// assert_eq!(Language::from_extension("tsx"), Some(Language::TypeScript));
+    /// assert_eq!(Language::from_extension("md"), None);
+    /// ```
+    pub fn from_extension(ext: &str) -> Option<Language> {
+        match ext {
+            "rs" => Some(Language::Rust),
+            "js" | "mjs" | "cjs" | "jsx" => Some(Language::JavaScript),
+            "ts" | "mts" | "cts" | "tsx" => Some(Language::TypeScript),
+            "py" | "pyi" => Some(Language::Python),
+            "go" => Some(Language::Go),
+            _ => None,
+        }
+    }
+
+    // Returns the keywords, which cannot be identifiers.
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => keywords::RUST,
+            Language::JavaScript => keywords::JAVASCRIPT,
+            Language::TypeScript => keywords::TYPESCRIPT,
+            Language::Python => keywords::PYTHON,
+            Language::Go => keywords::GO,
+        }
+    }
+
+    // Returns the builtin names, which are identifiers but are not renamed.
+    fn builtins(&self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => RUST_BUILTINS,
+            Language::JavaScript | Language::TypeScript => JS_BUILTINS,
+            Language::Python => PYTHON_BUILTINS,
+            Language::Go => GO_BUILTINS,
+        }
+    }
+
+    fn is_ident_start(&self, ch: char) -> bool {
+        ch == '_' || ch.is_alphabetic() || (ch == '$' && self.is_js())
+    }
+
+    fn is_ident_continue(&self, ch: char) -> bool {
+        ch == '_' || ch.is_alphanumeric() || (ch == '$' && self.is_js())
+    }
+
+    fn is_js(&self) -> bool {
+        matches!(self, Language::JavaScript | Language::TypeScript)
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An error which is returned when parsing a string into a [`Language`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLanguageError {
+    name: String,
+}
+
+impl fmt::Display for ParseLanguageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown language: {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseLanguageError {}
+
+impl FromStr for Language {
+    type Err = ParseLanguageError;
+
+    /// Parses the name of a language ignoring letter cases, or one of its file extensions.
+    ///
+    /// ```rust
+    /// use stringcase::codemod::Language;
+    ///
+    /// assert_eq!("rust".parse(), Ok(Language::Rust));
+    /// assert_eq!("TypeScript".parse(), Ok(Language::TypeScript));
+    /// assert_eq!("py".parse(), Ok(Language::Python));
+    /// assert!("cobol".parse::<Language>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.to_ascii_lowercase();
+        match name.as_str() {
+            "rust" => Ok(Language::Rust),
+            "javascript" => Ok(Language::JavaScript),
+            "typescript" => Ok(Language::TypeScript),
+            "python" => Ok(Language::Python),
+            "go" | "golang" => Ok(Language::Go),
+            _ => Language::from_extension(&name).ok_or_else(|| ParseLanguageError {
+                name: s.to_string(),
+            }),
+        }
+    }
+}
+
+const RUST_BUILTINS: &[&str] = &[
+    "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "str", "u8", "u16",
+    "u32", "u64", "u128", "usize",
+];
+
+// The macros of Rust which take a format string, with the number of the arguments before it.
+// The names in `{` and `}` of a format string, like `{name}` or `{name:?}`, are identifiers.
+#[rustfmt::skip]
+const RUST_FORMAT_MACROS: &[(&str, usize)] = &[
+    ("format", 0), ("format_args", 0), ("print", 0), ("println", 0), ("eprint", 0),
+    ("eprintln", 0), ("panic", 0), ("unreachable", 0), ("todo", 0), ("unimplemented", 0),
+    ("trace", 0), ("debug", 0), ("info", 0), ("warn", 0), ("error", 0), ("write", 1),
+    ("writeln", 1), ("assert", 1), ("debug_assert", 1), ("assert_eq", 2), ("assert_ne", 2),
+    ("debug_assert_eq", 2), ("debug_assert_ne", 2),
+];
+
+#[rustfmt::skip]
+const JS_BUILTINS: &[&str] = &[
+    "Array", "Boolean", "Date", "Error", "Infinity", "JSON", "Map", "Math", "NaN", "Number",
+    "Object", "Promise", "RegExp", "Set", "String", "Symbol", "console", "document", "exports",
+    "globalThis", "module", "require", "undefined", "window",
+];
+
+#[rustfmt::skip]
+const PYTHON_BUILTINS: &[&str] = &[
+    "abs", "all", "any", "bool", "bytes", "cls", "dict", "enumerate", "filter", "float", "format",
+    "getattr", "hasattr", "int", "isinstance", "iter", "len", "list", "map", "max", "min", "next",
+    "object", "open", "print", "range", "repr", "self", "set", "setattr", "sorted", "str", "sum",
+    "super", "tuple", "type", "zip",
+];
+
+#[rustfmt::skip]
+const GO_BUILTINS: &[&str] = &[
+    "any", "append", "bool", "byte", "cap", "clear", "close", "comparable", "complex", "complex64",
+    "complex128", "copy", "delete", "error", "false", "float32", "float64", "imag", "int", "int8",
+    "int16", "int32", "int64", "iota", "len", "make", "max", "min", "new", "nil", "panic", "print",
+    "println", "real", "recover", "rune", "string", "true", "uint", "uint8", "uint16", "uint32",
+    "uint64", "uintptr",
+];
+
+/// Renames the identifiers in source code into a case style.
+///
+/// By default, all identifiers except keywords and builtin names of the language are renamed.
+/// The identifiers to be renamed can be narrowed with [`pattern`](Codemod::pattern) and
+/// [`from_case`](Codemod::from_case).
+/// An identifier which would become a keyword is not renamed, except that it is renamed into a
+/// raw identifier like `r#type` in Rust.
+/// Since a name in COBOL, kebab, title, or train case is not an identifier unless it is a single
+/// word, such a name is not used for identifiers.
+///
+/// The identifiers in interpolations of string literals, like template literals of JavaScript and
+/// f-strings of Python, and the inline arguments of format strings of Rust macros like
+/// `println!("{name}")`, are renamed as the other identifiers.
+/// The `$` at the head or the end of an identifier of JavaScript is kept, like `$userName` into
+/// `$user_name`.
+pub struct Codemod<'a> {
+    language: Language,
+    case: Case,
+    opts: &'a Options<'a>,
+    pattern: Option<&'a str>,
+    from: Option<Case>,
+    strings: bool,
+    comments: bool,
+}
+
+impl Codemod<'static> {
+    /// Creates a `Codemod` which renames identifiers in source code of the specified language into
+    /// the specified case style with the default options.
+    pub fn new(language: Language, case: Case) -> Self {
+        Self::with_options(language, case, &DEFAULT_OPTIONS)
+    }
+}
+
+impl<'a> Codemod<'a> {
+    /// Creates a `Codemod` which renames identifiers in source code of the specified language into
+    /// the specified case style with the specified options.
+    pub fn with_options(language: Language, case: Case, opts: &'a Options<'a>) -> Self {
+        Self {
+            language,
+            case,
+            opts,
+            pattern: None,
+            from: None,
+            strings: false,
+            comments: false,
+        }
+    }
+
+    /// Renames only the identifiers which match a glob pattern, in which `*` matches any
+    /// characters and `?` matches a character.
+    pub fn pattern(mut self, pattern: &'a str) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Renames only the identifiers which are in the specified case style.
+    pub fn from_case(mut self, case: Case) -> Self {
+        self.from = Some(case);
+        self
+    }
+
+    /// Specifies whether to rename also the words in string literals which match the conditions.
+    pub fn strings(mut self, strings: bool) -> Self {
+        self.strings = strings;
+        self
+    }
+
+    /// Specifies whether to rename also the words in comments which match the conditions.
+    pub fn comments(mut self, comments: bool) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Renames the identifiers in source code.
+    pub fn rewrite<'s>(&self, source: &'s str) -> Rewrite<'s> {
+        let mut edits = Vec::new();
+        for token in tokenize(source, self.language) {
+            let text = &source[token.start..token.end];
+            match token.kind {
+                Kind::Ident => {
+                    if let Some(to) = self.rename_ident(text) {
+                        edits.push(Edit {
+                            range: token.start..token.end,
+                            from: text,
+                            to,
+                        });
+                    }
+                }
+                Kind::Str if self.strings => self.rename_words(source, &token, &mut edits),
+                Kind::Comment if self.comments => self.rename_words(source, &token, &mut edits),
+                _ => {}
+            }
+        }
+
+        let mut output = String::with_capacity(source.len());
+        let mut last = 0;
+        for edit in &edits {
+            output.push_str(&source[last..edit.range.start]);
+            output.push_str(&edit.to);
+            last = edit.range.end;
+        }
+        output.push_str(&source[last..]);
+
+        Rewrite {
+            source,
+            output,
+            edits,
+        }
+    }
+
+    fn is_target(&self, name: &str) -> bool {
+        let lang = self.language;
+        !lang.builtins().contains(&name)
+            && self.pattern.map_or(true, |p| glob_match(p, name))
+            && self.from.map_or(true, |c| {
+                is_rendered(name.trim_matches('$'), self.opts, c.style())
+            })
+    }
+
+    fn rename_ident(&self, text: &str) -> Option<String> {
+        let lang = self.language;
+        // A raw identifier of Rust, like `r#type`, is renamed even if its name is a keyword.
+        let (raw, name) = match text.strip_prefix("r#") {
+            Some(name) if lang == Language::Rust => (true, name),
+            _ => (false, text),
+        };
+        if (!raw && lang.keywords().contains(&name)) || !self.is_target(name) {
+            return None;
+        }
+        let new_name = self.render(name)?;
+        let mut chars = new_name.chars();
+        let valid = chars.next().map_or(false, |c| lang.is_ident_start(c))
+            && chars.all(|c| lang.is_ident_continue(c));
+        if !valid {
+            return None;
+        }
+        let new_text = if lang.keywords().contains(&new_name.as_str()) {
+            if lang != Language::Rust || keywords::RUST_NON_RAW.contains(&new_name.as_str()) {
+                return None;
+            }
+            format!("r#{}", new_name)
+        } else {
+            new_name
+        };
+        Some(new_text).filter(|t| t != text)
+    }
+
+    // Converts a name into the case style. The `$` at the head or the end of an identifier of
+    // JavaScript, like `$el`, is kept, and a name with `$` in the middle is not converted.
+    fn render(&self, name: &str) -> Option<String> {
+        let core = name.trim_matches('$');
+        if core.is_empty() || core.contains('$') {
+            return None;
+        }
+        let head = &name[..name.len() - name.trim_start_matches('$').len()];
+        let tail = &name[name.trim_end_matches('$').len()..];
+        let core = render_string(core, self.opts, self.case.style());
+        if core.is_empty() {
+            return None;
+        }
+        Some(format!("{}{}{}", head, core, tail))
+    }
+
+    fn rename_words<'s>(&self, source: &'s str, token: &Token, edits: &mut Vec<Edit<'s>>) {
+        let lang = self.language;
+        let text = &source[token.start..token.end];
+        let mut iter = text.char_indices().peekable();
+        let mut prev = '\0';
+        while let Some((i, ch)) = iter.next() {
+            // A word just after a backslash is a part of an escape sequence, like `\n`.
+            if !lang.is_ident_start(ch) || prev == '\\' {
+                prev = ch;
+                continue;
+            }
+            let mut end = i + ch.len_utf8();
+            while let Some(&(j, c)) = iter.peek() {
+                if !lang.is_ident_continue(c) {
+                    break;
+                }
+                end = j + c.len_utf8();
+                iter.next();
+            }
+            prev = 'a';
+            let word = &text[i..end];
+            if lang.keywords().contains(&word) || !self.is_target(word) {
+                continue;
+            }
+            let to = match self.render(word) {
+                Some(to) => to,
+                None => continue,
+            };
+            if to != word {
+                edits.push(Edit {
+                    range: token.start + i..token.start + end,
+                    from: word,
+                    to,
+                });
+            }
+        }
+    }
+}
+
+/// A rename of an identifier, or a word in a string literal or a comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit<'s> {
+    /// The byte range of the renamed text in the source code.
+    pub range: Range<usize>,
+
+    /// The text before renaming.
+    pub from: &'s str,
+
+    /// The text after renaming.
+    pub to: String,
+}
+
+/// The result of renaming identifiers in source code by [`Codemod::rewrite`].
+#[derive(Debug, Clone)]
+pub struct Rewrite<'s> {
+    source: &'s str,
+    output: String,
+    edits: Vec<Edit<'s>>,
+}
+
+// The number of the unchanged lines around changed lines in a unified diff.
+const CONTEXT_LINES: usize = 3;
+
+impl<'s> Rewrite<'s> {
+    /// Returns the source code before renaming.
+    pub fn source(&self) -> &'s str {
+        self.source
+    }
+
+    /// Returns the source code after renaming.
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Returns the source code after renaming as a `String`.
+    pub fn into_output(self) -> String {
+        self.output
+    }
+
+    /// Returns the renames in the order of their positions.
+    pub fn edits(&self) -> &[Edit<'s>] {
+        &self.edits
+    }
+
+    /// Checks whether some identifiers are renamed.
+    pub fn is_changed(&self) -> bool {
+        !self.edits.is_empty()
+    }
+
+    /// Returns the changes as a unified diff with the paths `a/<path>` and `b/<path>`, or an
+    /// empty string if nothing is changed.
+    pub fn unified_diff(&self, path: &str) -> String {
+        let mut out = String::new();
+        if !self.is_changed() {
+            return out;
+        }
+
+        // Renames never add or remove line breaks, so that the lines correspond one to one.
+        let old: Vec<&str> = self.source.split_inclusive('\n').collect();
+        let new: Vec<&str> = self.output.split_inclusive('\n').collect();
+        let changed: Vec<usize> = (0..old.len()).filter(|&i| old[i] != new[i]).collect();
+
+        let _ = write!(out, "--- a/{}\n+++ b/{}\n", path, path);
+        let mut i = 0;
+        while i < changed.len() {
+            let mut j = i;
+            while j + 1 < changed.len() && changed[j + 1] - changed[j] - 1 <= 2 * CONTEXT_LINES {
+                j += 1;
+            }
+            let start = changed[i].saturating_sub(CONTEXT_LINES);
+            let end = (changed[j] + CONTEXT_LINES + 1).min(old.len());
+            let range = HunkRange(start, end - start);
+            let _ = writeln!(out, "@@ -{} +{} @@", range, range);
+
+            let mut k = start;
+            while k < end {
+                if old[k] == new[k] {
+                    push_diff_line(&mut out, ' ', old[k]);
+                    k += 1;
+                    continue;
+                }
+                let run_end = (k..end).find(|&m| old[m] == new[m]).unwrap_or(end);
+                for line in &old[k..run_end] {
+                    push_diff_line(&mut out, '-', line);
+                }
+                for line in &new[k..run_end] {
+                    push_diff_line(&mut out, '+', line);
+                }
+                k = run_end;
+            }
+            i = j + 1;
+        }
+        out
+    }
+}
+
+// The range of lines in a hunk header, which is the 0-based start line and the number of lines.
+struct HunkRange(usize, usize);
+
+impl fmt::Display for HunkRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.1 == 1 {
+            write!(f, "{}", self.0 + 1)
+        } else {
+            write!(f, "{},{}", self.0 + 1, self.1)
+        }
+    }
+}
+
+fn push_diff_line(out: &mut String, prefix: char, line: &str) {
+    out.push(prefix);
+    out.push_str(line);
+    if !line.ends_with('\n') {
+        out.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+// Checks whether a name matches a glob pattern with `*` and `?`.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let n: Vec<char> = name.chars().collect();
+    let (mut pi, mut ni) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while ni < n.len() {
+        if pi < p.len() && (p[pi] == '?' || p[pi] == n[ni]) {
+            pi += 1;
+            ni += 1;
+        } else if pi < p.len() && p[pi] == '*' {
+            star = Some((pi, ni));
+            pi += 1;
+        } else if let Some((sp, sn)) = star {
+            pi = sp + 1;
+            ni = sn + 1;
+            star = Some((sp, sn + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|c| *c == '*')
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Ident,
+    Str,
+    Comment,
+    Other,
+}
+
+// A token of source code. The token of a string literal covers only its contents, and its
+// prefix and quotes are other tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Token {
+    kind: Kind,
+    start: usize,
+    end: usize,
+}
+
+// The delimiters and the syntax of a string literal.
+#[derive(Debug, Clone, Copy)]
+struct Quote {
+    end: &'static str,
+    hashes: usize,
+    escapes: bool,
+    multiline: bool,
+    interp: Option<&'static str>,
+}
+
+const QUOTE: Quote = Quote {
+    end: "\"",
+    hashes: 0,
+    escapes: true,
+    multiline: false,
+    interp: None,
+};
+
+fn tokenize(source: &str, language: Language) -> Vec<Token> {
+    let mut lexer = Lexer {
+        src: source,
+        pos: 0,
+        language,
+        tokens: Vec::new(),
+        braces: Vec::new(),
+    };
+    lexer.run();
+    lexer.tokens
+}
+
+struct Lexer<'s> {
+    src: &'s str,
+    pos: usize,
+    language: Language,
+    tokens: Vec<Token>,
+    // The open braces, with the string literals which are resumed after the matching close braces
+    // if the braces open interpolations, like `${` of JavaScript or `{` of Python f-strings.
+    braces: Vec<Option<Quote>>,
+}
+
+impl<'s> Lexer<'s> {
+    fn rest(&self) -> &'s str {
+        &self.src[self.pos..]
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn push(&mut self, kind: Kind, start: usize) {
+        if self.pos > start {
+            self.tokens.push(Token {
+                kind,
+                start,
+                end: self.pos,
+            });
+        }
+    }
+
+    fn bump_ident(&mut self) {
+        while let Some(ch) = self.peek() {
+            if !self.language.is_ident_continue(ch) {
+                break;
+            }
+            self.pos += ch.len_utf8();
+        }
+    }
+
+    fn run(&mut self) {
+        while let Some(ch) = self.peek() {
+            let start = self.pos;
+            if ch.is_whitespace() {
+                self.pos += ch.len_utf8();
+            } else if self.comment() {
+                continue;
+            } else if self.language.is_ident_start(ch) {
+                self.ident();
+            } else if ch.is_ascii_digit() {
+                self.number();
+            } else {
+                match ch {
+                    '{' => {
+                        self.pos += 1;
+                        self.push(Kind::Other, start);
+                        self.braces.push(None);
+                    }
+                    '}' => {
+                        self.pos += 1;
+                        self.push(Kind::Other, start);
+                        if let Some(Some(quote)) = self.braces.pop() {
+                            self.string(quote);
+                        }
+                    }
+                    '"' | '\'' | '`' => self.quote(ch, start),
+                    '/' if self.regex_allowed() => self.regex(),
+                    _ => {
+                        self.pos += ch.len_utf8();
+                        self.push(Kind::Other, start);
+                    }
+                }
+            }
+        }
+    }
+
+    fn comment(&mut self) -> bool {
+        let start = self.pos;
+        let rest = self.rest();
+        let line = match self.language {
+            Language::Python => rest.starts_with('#'),
+            _ => rest.starts_with("//"),
+        };
+        if line {
+            self.pos += rest.find('\n').unwrap_or(rest.len());
+        } else if rest.starts_with("/*") && self.language != Language::Python {
+            // Block comments of Rust can be nested.
+            let nested = self.language == Language::Rust;
+            let mut depth = 0;
+            let bytes = rest.as_bytes();
+            let mut i = 0;
+            while i < bytes.len() {
+                if bytes[i..].starts_with(b"/*") && (depth == 0 || nested) {
+                    depth += 1;
+                    i += 2;
+                } else if bytes[i..].starts_with(b"*/") {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            self.pos += i;
+        } else {
+            return false;
+        }
+        self.push(Kind::Comment, start);
+        true
+    }
+
+    fn ident(&mut self) {
+        let start = self.pos;
+        self.bump_ident();
+        let word = &self.src[start..self.pos];
+        let rest = self.rest();
+        match self.language {
+            Language::Rust => {
+                let hashes = rest.bytes().take_while(|b| *b == b'#').count();
+                let after = &rest[hashes..];
+                if word == "r"
+                    && hashes == 1
+                    && after.starts_with(|c| self.language.is_ident_start(c))
+                {
+                    self.pos += 1;
+                    self.bump_ident();
+                } else if matches!(word, "r" | "br" | "cr") && after.starts_with('"') {
+                    let quote = Quote {
+                        hashes,
+                        escapes: false,
+                        multiline: true,
+                        interp: if word == "r" {
+                            self.format_interp()
+                        } else {
+                            None
+                        },
+                        ..QUOTE
+                    };
+                    return self.open_string(start, hashes + 1, quote);
+                } else if matches!(word, "b" | "c") && rest.starts_with('"') {
+                    let quote = Quote {
+                        multiline: true,
+                        ..QUOTE
+                    };
+                    return self.open_string(start, 1, quote);
+                } else if word == "b" && rest.starts_with('\'') {
+                    return self.rust_char(start);
+                }
+            }
+            Language::Python
+                if word.len() <= 2
+                    && word.chars().all(|c| "rRbBuUfF".contains(c))
+                    && rest.starts_with(|c| c == '"' || c == '\'') =>
+            {
+                return self.python_string(start);
+            }
+            _ => {}
+        }
+        self.push(Kind::Ident, start);
+    }
+
+    fn number(&mut self) {
+        let start = self.pos;
+        while let Some(ch) = self.peek() {
+            let fraction = ch == '.'
+                && self.rest()[1..].starts_with(|c: char| c.is_ascii_digit())
+                && !self.src[start..self.pos].contains('.');
+            if !(ch.is_ascii_alphanumeric() || ch == '_' || fraction) {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.push(Kind::Other, start);
+    }
+
+    fn quote(&mut self, ch: char, start: usize) {
+        match (self.language, ch) {
+            (Language::Rust, '"') => self.open_string(
+                start,
+                1,
+                Quote {
+                    multiline: true,
+                    interp: self.format_interp(),
+                    ..QUOTE
+                },
+            ),
+            (Language::Rust, '\'') => self.rust_char(start),
+            (Language::Python, _) if ch != '`' => self.python_string(start),
+            (Language::JavaScript | Language::TypeScript, '`') => self.open_string(
+                start,
+                1,
+                Quote {
+                    end: "`",
+                    multiline: true,
+                    interp: Some("${"),
+                    ..QUOTE
+                },
+            ),
+            (Language::Go, '`') => self.open_string(
+                start,
+                1,
+                Quote {
+                    end: "`",
+                    escapes: false,
+                    multiline: true,
+                    ..QUOTE
+                },
+            ),
+            (_, '"') => self.open_string(start, 1, QUOTE),
+            (_, '\'') => self.open_string(start, 1, Quote { end: "'", ..QUOTE }),
+            _ => {
+                self.pos += 1;
+                self.push(Kind::Other, start);
+            }
+        }
+    }
+
+    // Scans a char literal or a lifetime of Rust, which starts after the prefix `b` if any.
+    fn rust_char(&mut self, start: usize) {
+        let mut chars = self.rest()[1..].chars();
+        match (chars.next(), chars.next()) {
+            (Some('\\'), _) => self.open_string(start, 1, Quote { end: "'", ..QUOTE }),
+            (Some(c), Some('\'')) if c != '\'' => {
+                self.open_string(start, 1, Quote { end: "'", ..QUOTE })
+            }
+            _ => {
+                self.pos += 1;
+                self.bump_ident();
+                self.push(Kind::Other, start);
+            }
+        }
+    }
+
+    // Returns the opening of inline arguments, if a string literal of Rust which starts at the
+    // current position is the format string of a macro like `format!` or `println!`.
+    fn format_interp(&self) -> Option<&'static str> {
+        let mut tokens = self
+            .tokens
+            .iter()
+            .rev()
+            .filter(|t| t.kind != Kind::Comment)
+            .map(|t| (t.kind, &self.src[t.start..t.end]));
+        let mut depth = 0;
+        let mut commas = 0;
+        while let Some((kind, text)) = tokens.next() {
+            if kind != Kind::Other {
+                continue;
+            }
+            match text {
+                ")" | "]" | "}" => depth += 1,
+                "(" | "[" | "{" if depth > 0 => depth -= 1,
+                "(" | "[" | "{" => {
+                    let bang = tokens.next().map(|(_, t)| t);
+                    let name = tokens.next().filter(|(k, _)| *k == Kind::Ident);
+                    let is_format = bang == Some("!")
+                        && name.map_or(false, |(_, name)| {
+                            RUST_FORMAT_MACROS.contains(&(name, commas))
+                        });
+                    return if is_format { Some("{") } else { None };
+                }
+                "," if depth == 0 => commas += 1,
+                ";" => return None,
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn python_string(&mut self, start: usize) {
+        let prefix = &self.src[start..self.pos];
+        let interp = if prefix.contains(|c| c == 'f' || c == 'F') {
+            Some("{")
+        } else {
+            None
+        };
+        let rest = self.rest();
+        let (end, multiline) = if rest.starts_with("\"\"\"") {
+            ("\"\"\"", true)
+        } else if rest.starts_with("'''") {
+            ("'''", true)
+        } else if rest.starts_with('"') {
+            ("\"", false)
+        } else {
+            ("'", false)
+        };
+        let quote = Quote {
+            end,
+            multiline,
+            interp,
+            ..QUOTE
+        };
+        self.open_string(start, end.len(), quote);
+    }
+
+    // Scans a string literal whose prefix starts at `start` and whose opening quote ends at
+    // `open_len` bytes after the current position.
+    fn open_string(&mut self, start: usize, open_len: usize, quote: Quote) {
+        self.pos += open_len;
+        self.push(Kind::Other, start);
+        self.string(quote);
+    }
+
+    // Scans the contents and the closing quote of a string literal.
+    fn string(&mut self, quote: Quote) {
+        let start = self.pos;
+        loop {
+            let rest = self.rest();
+            let ch = match rest.chars().next() {
+                Some(ch) => ch,
+                None => break,
+            };
+            if quote.escapes && ch == '\\' {
+                self.pos += 1;
+                if let Some(c) = self.peek() {
+                    self.pos += c.len_utf8();
+                }
+                continue;
+            }
+            if rest.starts_with(quote.end) {
+                let tail = &rest.as_bytes()[quote.end.len()..];
+                if tail.len() >= quote.hashes && tail[..quote.hashes].iter().all(|b| *b == b'#') {
+                    self.push(Kind::Str, start);
+                    let close = self.pos;
+                    self.pos += quote.end.len() + quote.hashes;
+                    self.push(Kind::Other, close);
+                    return;
+                }
+            }
+            if let Some(open) = quote.interp {
+                if open == "{" && rest.starts_with("{{") {
+                    self.pos += 2;
+                    continue;
+                }
+                if rest.starts_with(open) {
+                    self.push(Kind::Str, start);
+                    let brace = self.pos;
+                    self.pos += open.len();
+                    self.push(Kind::Other, brace);
+                    self.braces.push(Some(quote));
+                    return;
+                }
+            }
+            if ch == '\n' && !quote.multiline {
+                break;
+            }
+            self.pos += ch.len_utf8();
+        }
+        self.push(Kind::Str, start);
+    }
+
+    // Checks whether `/` starts a regular expression literal of JavaScript and TypeScript, which
+    // is where an expression is expected.
+    fn regex_allowed(&self) -> bool {
+        if !self.language.is_js() || self.rest().starts_with("/*") {
+            return false;
+        }
+        let prev = match self.tokens.iter().rev().find(|t| t.kind != Kind::Comment) {
+            Some(prev) => prev,
+            None => return true,
+        };
+        let text = &self.src[prev.start..prev.end];
+        match prev.kind {
+            Kind::Ident => matches!(
+                text,
+                "return"
+                    | "typeof"
+                    | "instanceof"
+                    | "in"
+                    | "of"
+                    | "new"
+                    | "delete"
+                    | "void"
+                    | "throw"
+                    | "case"
+                    | "do"
+                    | "else"
+                    | "yield"
+                    | "await"
+            ),
+            Kind::Str => false,
+            _ => !text.ends_with(|c: char| {
+                c.is_alphanumeric() || matches!(c, '_' | '$' | ')' | ']' | '"' | '\'' | '`')
+            }),
+        }
+    }
+
+    fn regex(&mut self) {
+        let start = self.pos;
+        self.pos += 1;
+        let mut class = false;
+        while let Some(ch) = self.peek() {
+            match ch {
+                '\\' => {
+                    self.pos += 1;
+                    if let Some(c) = self.peek() {
+                        self.pos += c.len_utf8();
+                    }
+                    continue;
+                }
+                '\n' => break,
+                '[' => class = true,
+                ']' => class = false,
+                '/' if !class => {
+                    self.pos += 1;
+                    self.bump_ident();
+                    break;
+                }
+                _ => {}
+            }
+            self.pos += ch.len_utf8();
+        }
+        self.push(Kind::Other, start);
+    }
+}
+
+#[cfg(test)]
+mod tests_of_codemod {
+    use super::*;
+
+    fn tokens(source: &str, language: Language) -> Vec<(Kind, &str)> {
+        tokenize(source, language)
+            .iter()
+            .filter(|t| t.kind != Kind::Other)
+            .map(|t| (t.kind, &source[t.start..t.end]))
+            .collect()
+    }
+
+    use Kind::{Comment, Ident, Str};
+
+    #[test]
+    fn test_of_language() {
+        assert_eq!(Language::from_extension("rs"), Some(Language::Rust));
+        assert_eq!(Language::from_extension("mjs"), Some(Language::JavaScript));
+        assert_eq!(Language::from_extension("ts"), Some(Language::TypeScript));
+        assert_eq!(Language::from_extension("py"), Some(Language::Python));
+        assert_eq!(Language::from_extension("go"), Some(Language::Go));
+        assert_eq!(Language::from_extension("c"), None);
+
+        for lang in Language::ALL.iter() {
+            assert_eq!(lang.name().parse(), Ok(*lang));
+            assert_eq!(lang.to_string(), lang.name());
+        }
+        assert_eq!("golang".parse(), Ok(Language::Go));
+        assert_eq!(
+            "c".parse::<Language>().unwrap_err().to_string(),
+            "unknown language: \"c\""
+        );
+    }
+
+    #[test]
+    fn test_of_glob_match() {
+        assert!(glob_match("*", ""));
+        assert!(glob_match("*", "fooBar"));
+        assert!(glob_match("foo*", "fooBar"));
+        assert!(glob_match("*Bar", "fooBar"));
+        assert!(glob_match("f?o*r", "fooBar"));
+        assert!(glob_match("*o*a*", "fooBar"));
+        assert!(!glob_match("foo", "fooBar"));
+        assert!(!glob_match("*Baz", "fooBar"));
+        assert!(!glob_match("?", ""));
+    }
+
+    #[test]
+    fn test_of_tokenize_rust() {
+        let source = r####"
+/* outer /* inner */ still comment */
+fn r#type<'a>(s: &'a str) -> char { // line
+    let c = '\''; let b = b'x'; let n = 1.5e3u32 + x.0.len();
+    r#"raw "quoted" fooBar"# ; br##"bytes"## ; "esc\"aped" 'static
+}
+"####;
+        assert_eq!(
+            tokens(source, Language::Rust),
+            [
+                (Comment, "/* outer /* inner */ still comment */"),
+                (Ident, "fn"),
+                (Ident, "r#type"),
+                (Ident, "s"),
+                (Ident, "str"),
+                (Ident, "char"),
+                (Comment, "// line"),
+                (Ident, "let"),
+                (Ident, "c"),
+                (Str, "\\'"),
+                (Ident, "let"),
+                (Ident, "b"),
+                (Str, "x"),
+                (Ident, "let"),
+                (Ident, "n"),
+                (Ident, "x"),
+                (Ident, "len"),
+                (Str, "raw \"quoted\" fooBar"),
+                (Str, "bytes"),
+                (Str, "esc\\\"aped"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_of_tokenize_javascript() {
+        let source = "const re = /a\"b[/]/g, q = a / b / c; // c\n\
+            let t = `x ${ fooBar + `y ${z}` } w`; let s = 'it\\'s'; $el.#priv;";
+        assert_eq!(
+            tokens(source, Language::JavaScript),
+            [
+                (Ident, "const"),
+                (Ident, "re"),
+                (Ident, "q"),
+                (Ident, "a"),
+                (Ident, "b"),
+                (Ident, "c"),
+                (Comment, "// c"),
+                (Ident, "let"),
+                (Ident, "t"),
+                (Str, "x "),
+                (Ident, "fooBar"),
+                (Str, "y "),
+                (Ident, "z"),
+                (Str, " w"),
+                (Ident, "let"),
+                (Ident, "s"),
+                (Str, "it\\'s"),
+                (Ident, "$el"),
+                (Ident, "priv"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_of_tokenize_python() {
+        let source = "def f(x):  # comment\n    \"\"\"Doc \"string\".\"\"\"\n    \
+            return rb'raw' + f\"{x!r:>{width}} {{not}}\" + '''a\nb'''\n";
+        assert_eq!(
+            tokens(source, Language::Python),
+            [
+                (Ident, "def"),
+                (Ident, "f"),
+                (Ident, "x"),
+                (Comment, "# comment"),
+                (Str, "Doc \"string\"."),
+                (Ident, "return"),
+                (Str, "raw"),
+                (Ident, "x"),
+                (Ident, "r"),
+                (Ident, "width"),
+                (Str, " {{not}}"),
+                (Str, "a\nb"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_of_tokenize_go() {
+        let source = "func main() { r := 'x'; s := `raw\\` + \"a\\\"b\" /* c */ }";
+        assert_eq!(
+            tokens(source, Language::Go),
+            [
+                (Ident, "func"),
+                (Ident, "main"),
+                (Ident, "r"),
+                (Str, "x"),
+                (Ident, "s"),
+                (Str, "raw\\"),
+                (Str, "a\\\"b"),
+                (Comment, "/* c */"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_of_rewrite() {
+        let source = "fn getUserName(userId: u32) -> String { let Type = userId; /* userId */ }";
+        let rewrite = Codemod::new(Language::Rust, Case::Snake).rewrite(source);
+        assert_eq!(
+            rewrite.output(),
+            "fn get_user_name(user_id: u32) -> string { let r#type = user_id; /* userId */ }"
+        );
+        assert_eq!(rewrite.source(), source);
+        assert_eq!(
+            rewrite.edits()[0],
+            Edit {
+                range: 3..14,
+                from: "getUserName",
+                to: "get_user_name".to_string(),
+            }
+        );
+
+        let rewrite = Codemod::new(Language::Rust, Case::Snake)
+            .from_case(Case::Camel)
+            .comments(true)
+            .rewrite(source);
+        assert_eq!(
+            rewrite.output(),
+            "fn get_user_name(user_id: u32) -> String { let Type = user_id; /* user_id */ }"
+        );
+
+        let rewrite = Codemod::new(Language::Rust, Case::Pascal).rewrite("let r#type = self;");
+        assert_eq!(rewrite.output(), "let Type = self;");
+
+        let rewrite = Codemod::new(Language::Python, Case::Snake).rewrite("If = Class");
+        assert_eq!(rewrite.output(), "If = Class");
+
+        let rewrite = Codemod::new(Language::Go, Case::Kebab).rewrite("var fooBar, Baz int");
+        assert_eq!(rewrite.output(), "var fooBar, baz int");
+    }
+
+    #[test]
+    fn test_of_rewrite_strings() {
+        let source = "const data = {\"userName\": userName, \"a\\nuserId\": 1};";
+        let codemod = Codemod::new(Language::JavaScript, Case::Snake).pattern("user*");
+        assert_eq!(
+            codemod.rewrite(source).output(),
+            "const data = {\"userName\": user_name, \"a\\nuserId\": 1};"
+        );
+        assert_eq!(
+            codemod.strings(true).rewrite(source).output(),
+            "const data = {\"user_name\": user_name, \"a\\nuser_id\": 1};"
+        );
+    }
+
+    #[test]
+    fn test_of_rewrite_rust_format_args() {
+        let source = r#"let userName = 1;
+println!("{userName} {userName:>8} {{userName}} {}", userName);
+write!(out, "{userName:?}").unwrap();
+assert_eq!(userName, 1, "{userName}");
+let s = "{userName}"; let r = format!(r"{userName}");
+"#;
+        let rewrite = Codemod::new(Language::Rust, Case::Snake).rewrite(source);
+        assert_eq!(
+            rewrite.output(),
+            r#"let user_name = 1;
+println!("{user_name} {user_name:>8} {{userName}} {}", user_name);
+write!(out, "{user_name:?}").unwrap();
+assert_eq!(user_name, 1, "{user_name}");
+let s = "{userName}"; let r = format!(r"{user_name}");
+"#
+        );
+    }
+
+    #[test]
+    fn test_of_rewrite_js_dollar() {
+        let source = "const $el = $; const $userName = userName$; const a$b = 1;";
+        let codemod = Codemod::new(Language::JavaScript, Case::Snake);
+        assert_eq!(
+            codemod.rewrite(source).output(),
+            "const $el = $; const $user_name = user_name$; const a$b = 1;"
+        );
+        let codemod = Codemod::new(Language::JavaScript, Case::Snake).from_case(Case::Camel);
+        assert_eq!(
+            codemod.strings(true).rewrite("f(\"$userName\")").output(),
+            "f(\"$user_name\")"
+        );
+    }
+
+    #[test]
+    fn test_of_rewrite_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        let codemod = Codemod::with_options(Language::Python, Case::Snake, &opts);
+        assert_eq!(codemod.rewrite("apiV2 = 1").output(), "api_v_2 = 1");
+    }
+
+    #[test]
+    fn test_of_unified_diff() {
+        let source = "a\nb\nc\nfooBar\nd\ne\nf\ng\nh\ni\nj\nk\nbazQux\nl\nfooBar";
+        let rewrite = Codemod::new(Language::Go, Case::Snake)
+            .pattern("none*")
+            .rewrite(source);
+        assert!(!rewrite.is_changed());
+        assert_eq!(rewrite.unified_diff("x.go"), "");
+
+        let rewrite = Codemod::new(Language::Go, Case::Snake).rewrite(source);
+        assert!(rewrite.is_changed());
+        assert_eq!(
+            rewrite.unified_diff("x.go"),
+            "\
+--- a/x.go
++++ b/x.go
+@@ -1,7 +1,7 @@
+ a
+ b
+ c
+-fooBar
++foo_bar
+ d
+ e
+ f
+@@ -10,6 +10,6 @@
+ i
+ j
+ k
+-bazQux
++baz_qux
+ l
+-fooBar
+\\ No newline at end of file
++foo_bar
+\\ No newline at end of file
+"
+        );
+
+        let rewrite = Codemod::new(Language::Go, Case::Snake).rewrite("aB\n\nb\ncD\n");
+        assert_eq!(
+            rewrite.unified_diff("y.go"),
+            "--- a/y.go\n+++ b/y.go\n@@ -1,4 +1,4 @@\n-aB\n+a_b\n \n b\n-cD\n+c_d\n"
+        );
+        assert_eq!(rewrite.into_output(), "a_b\n\nb\nc_d\n");
+    }
+}
@@ -0,0 +1,577 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::case::{convert, Case};
+use crate::chars::is_upper;
+use crate::keywords;
+use crate::options::{Options, DEFAULT_OPTIONS};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// An enum that represents the kinds of items which are named in source code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ItemKind {
+    /// A type, like a struct, a class, or an enum.
+    Type,
+    /// An interface, a trait, or a protocol.
+    Interface,
+    /// A function or a method.
+    Function,
+    /// A constant or a static variable.
+    Constant,
+    /// A field, a property, or a local variable.
+    Field,
+    /// A variant of an enum.
+    EnumVariant,
+    /// A module, a namespace, or a source file.
+    Module,
+    /// A package, a crate, or a library.
+    Package,
+}
+
+impl ItemKind {
+    /// All item kinds.
+    pub const ALL: &'static [ItemKind] = &[
+        ItemKind::Type,
+        ItemKind::Interface,
+        ItemKind::Function,
+        ItemKind::Constant,
+        ItemKind::Field,
+        ItemKind::EnumVariant,
+        ItemKind::Module,
+        ItemKind::Package,
+    ];
+}
+
+// The number of item kinds, which is the length of the rules of a convention.
+const ITEM_KIND_COUNT: usize = ItemKind::ALL.len();
+
+/// A struct that represents how items of a kind are named in a [`Convention`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// The case style of names.
+    pub case: Case,
+    /// The prefix of names, like `I` of interfaces in C#.
+    /// It is not added to a name which already starts with it followed by an uppercase letter.
+    pub prefix: &'static str,
+    /// Whether to lowercase names after the conversion.
+    /// With [`Case::Camel`], names are lowercased letters and digits without separators, like
+    /// package names of Go and Java.
+    pub lowercase: bool,
+}
+
+impl Rule {
+    /// Creates a rule which names items in the specified case style without a prefix.
+    pub const fn new(case: Case) -> Self {
+        Self {
+            case,
+            prefix: "",
+            lowercase: false,
+        }
+    }
+
+    /// Creates a rule which names items in lowercased letters and digits without separators.
+    pub const fn flat() -> Self {
+        Self {
+            case: Case::Camel,
+            prefix: "",
+            lowercase: true,
+        }
+    }
+
+    /// Returns this rule with the specified prefix.
+    pub const fn with_prefix(self, prefix: &'static str) -> Self {
+        Self { prefix, ..self }
+    }
+}
+
+// How a name which is a reserved word is escaped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    // `r#` before the name, or `_` after the name if it cannot be a raw identifier.
+    Raw,
+    // `@` before the name.
+    At,
+    // Backticks around the name.
+    Backticks,
+    // `_` after the name.
+    Underscore,
+}
+
+/// A struct that represents the naming convention of a programming language.
+///
+/// A convention has a [`Rule`] for each [`ItemKind`], a set of acronyms which are uppercased
+/// in names, and a set of reserved words which are escaped if a converted name is one of them.
+/// The built-in conventions are the following:
+///
+/// | Item kind   | `RUST`  | `GO`     | `JAVA`  | `CSHARP`  | `PYTHON` | `TYPESCRIPT` | `KOTLIN` |
+/// |-------------|---------|----------|---------|-----------|----------|--------------|----------|
+/// | Type        | Pascal  | Pascal   | Pascal  | Pascal    | Pascal   | Pascal       | Pascal   |
+/// | Interface   | Pascal  | Pascal   | Pascal  | `I`Pascal | Pascal   | Pascal       | Pascal   |
+/// | Function    | snake   | Pascal   | camel   | Pascal    | snake    | camel        | camel    |
+/// | Constant    | MACRO   | Pascal   | MACRO   | Pascal    | MACRO    | MACRO        | MACRO    |
+/// | Field       | snake   | Pascal   | camel   | Pascal    | snake    | camel        | camel    |
+/// | EnumVariant | Pascal  | Pascal   | MACRO   | Pascal    | MACRO    | Pascal       | MACRO    |
+/// | Module      | snake   | flat     | flat    | Pascal    | snake    | kebab        | flat     |
+/// | Package     | snake   | flat     | flat    | Pascal    | flat     | kebab        | flat     |
+/// | Acronyms    |         | `ID` ... |         |           |          |              |          |
+/// | Escape      | `r#`    | `_`      | `_`     | `@`       | `_`      | `_`          | `` ` ``  |
+///
+/// `flat` is lowercased letters and digits without separators.
+/// The Go convention uppercases the common initialisms of Go, like `ID`, `URL`, and `HTTP`, and
+/// names exported items.
+/// The Rust convention escapes `crate`, `self`, `Self`, and `super` with `_` after them because
+/// they cannot be raw identifiers.
+/// Reserved words are escaped only in names of the case styles without spaces and hyphens.
+///
+/// A rule of a built-in convention can be replaced with [`with_rule`](Convention::with_rule).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Convention {
+    name: &'static str,
+    rules: [Rule; ITEM_KIND_COUNT],
+    acronyms: &'static [&'static str],
+    keywords: &'static [&'static str],
+    escape: Escape,
+}
+
+// The common initialisms which are uppercased in names of Go.
+#[rustfmt::skip]
+const GO_INITIALISMS: &[&str] = &[
+    "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID", "IP",
+    "JSON", "QPS", "RAM", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS", "TTL", "UDP", "UI",
+    "UID", "URI", "URL", "UUID", "VM", "XML", "XMPP", "XSRF", "XSS",
+];
+
+const PASCAL: Rule = Rule::new(Case::Pascal);
+const CAMEL: Rule = Rule::new(Case::Camel);
+const SNAKE: Rule = Rule::new(Case::Snake);
+const MACRO: Rule = Rule::new(Case::Macro);
+const KEBAB: Rule = Rule::new(Case::Kebab);
+const FLAT: Rule = Rule::flat();
+
+impl Convention {
+    /// The naming convention of Rust.
+    pub const RUST: Convention = Convention {
+        name: "Rust",
+        rules: [PASCAL, PASCAL, SNAKE, MACRO, SNAKE, PASCAL, SNAKE, SNAKE],
+        acronyms: &[],
+        keywords: keywords::RUST,
+        escape: Escape::Raw,
+    };
+
+    /// The naming convention of Go, for exported items.
+    pub const GO: Convention = Convention {
+        name: "Go",
+        rules: [PASCAL, PASCAL, PASCAL, PASCAL, PASCAL, PASCAL, FLAT, FLAT],
+        acronyms: GO_INITIALISMS,
+        keywords: keywords::GO,
+        escape: Escape::Underscore,
+    };
+
+    /// The naming convention of Java.
+    pub const JAVA: Convention = Convention {
+        name: "Java",
+        rules: [PASCAL, PASCAL, CAMEL, MACRO, CAMEL, MACRO, FLAT, FLAT],
+        acronyms: &[],
+        keywords: keywords::JAVA,
+        escape: Escape::Underscore,
+    };
+
+    /// The naming convention of C#.
+    pub const CSHARP: Convention = Convention {
+        name: "C#",
+        rules: [
+            PASCAL,
+            PASCAL.with_prefix("I"),
+            PASCAL,
+            PASCAL,
+            PASCAL,
+            PASCAL,
+            PASCAL,
+            PASCAL,
+        ],
+        acronyms: &[],
+        keywords: keywords::CSHARP,
+        escape: Escape::At,
+    };
+
+    /// The naming convention of Python.
+    pub const PYTHON: Convention = Convention {
+        name: "Python",
+        rules: [PASCAL, PASCAL, SNAKE, MACRO, SNAKE, MACRO, SNAKE, FLAT],
+        acronyms: &[],
+        keywords: keywords::PYTHON,
+        escape: Escape::Underscore,
+    };
+
+    /// The naming convention of TypeScript.
+    pub const TYPESCRIPT: Convention = Convention {
+        name: "TypeScript",
+        rules: [PASCAL, PASCAL, CAMEL, MACRO, CAMEL, PASCAL, KEBAB, KEBAB],
+        acronyms: &[],
+        keywords: keywords::JAVASCRIPT,
+        escape: Escape::Underscore,
+    };
+
+    /// The naming convention of Kotlin.
+    pub const KOTLIN: Convention = Convention {
+        name: "Kotlin",
+        rules: [PASCAL, PASCAL, CAMEL, MACRO, CAMEL, MACRO, FLAT, FLAT],
+        acronyms: &[],
+        keywords: keywords::KOTLIN,
+        escape: Escape::Backticks,
+    };
+
+    /// All built-in conventions.
+    pub const ALL: &'static [Convention] = &[
+        Convention::RUST,
+        Convention::GO,
+        Convention::JAVA,
+        Convention::CSHARP,
+        Convention::PYTHON,
+        Convention::TYPESCRIPT,
+        Convention::KOTLIN,
+    ];
+
+    /// Returns the name of the language of this convention, like `Rust` or `C#`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the rule of the specified item kind.
+    pub fn rule(&self, kind: ItemKind) -> Rule {
+        self.rules[kind as usize]
+    }
+
+    /// Returns this convention with the rule of the specified item kind replaced.
+    ///
+    /// ```rust
+    /// use stringcase::{Case, Convention, ItemKind, Rule};
+    ///
+    /// let conv = Convention::TYPESCRIPT.with_rule(ItemKind::Constant, Rule::new(Case::Camel));
+    /// assert_eq!(conv.convert("max_retry_count", ItemKind::Constant), "maxRetryCount");
+    /// ```
+    pub fn with_rule(mut self, kind: ItemKind, rule: Rule) -> Self {
+        self.rules[kind as usize] = rule;
+        self
+    }
+
+    /// Returns the acronyms which are uppercased in names of this convention.
+    pub fn acronyms(&self) -> &'static [&'static str] {
+        self.acronyms
+    }
+
+    /// Returns whether the specified name is a reserved word of the language of this convention.
+    pub fn is_keyword(&self, name: &str) -> bool {
+        self.keywords.contains(&name)
+    }
+
+    /// Converts the input string into a name of an item of the specified kind in this
+    /// convention.
+    ///
+    /// This method behaves in the same way as [`convert_with_options`](Self::convert_with_options)
+    /// with the default options.
+    pub fn convert(&self, input: &str, kind: ItemKind) -> String {
+        self.convert_with_options(input, kind, &DEFAULT_OPTIONS)
+    }
+
+    /// Converts the input string into a name of an item of the specified kind in this
+    /// convention with the specified options.
+    ///
+    /// The input string is converted in the case style of the [`Rule`] of the kind with the
+    /// specified options, in the same way as the `〜_case_with_options` functions.
+    /// If this convention has acronyms, they are added to the acronyms of the options, and all
+    /// acronyms are uppercased.
+    /// Then the result is lowercased, is prefixed, and is escaped if it is a reserved word,
+    /// according to the rule and this convention.
+    /// If the input string has no words, an empty string is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `input`: The string slice (`&str`) to be converted.
+    /// - `kind`: The [`ItemKind`] of the item to be named.
+    /// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+    ///   behaviors.
+    ///
+    /// # Returns
+    ///
+    /// - A `String` containing the name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use stringcase::{Convention, ItemKind, Options};
+    ///
+    /// let opts = Options::default();
+    /// let go = Convention::GO;
+    /// assert_eq!(go.convert_with_options("user_id", ItemKind::Field, &opts), "UserID");
+    /// assert_eq!(go.convert_with_options("http-server", ItemKind::Type, &opts), "HTTPServer");
+    /// assert_eq!(go.convert_with_options("net_http", ItemKind::Package, &opts), "nethttp");
+    ///
+    /// let rust = Convention::RUST;
+    /// assert_eq!(rust.convert_with_options("Type", ItemKind::Field, &opts), "r#type");
+    /// ```
+    pub fn convert_with_options(&self, input: &str, kind: ItemKind, opts: &Options) -> String {
+        let rule = self.rule(kind);
+
+        let mut name = if self.acronyms.is_empty() {
+            convert(input, rule.case, opts)
+        } else {
+            let acronyms: Vec<&str> = opts.acronyms.iter().chain(self.acronyms).copied().collect();
+            let opts = opts.with_acronyms(&acronyms).with_uppercase_acronyms(true);
+            convert(input, rule.case, &opts)
+        };
+        if name.is_empty() {
+            return name;
+        }
+
+        if rule.lowercase {
+            name = if opts.unicode {
+                name.to_lowercase()
+            } else {
+                name.to_ascii_lowercase()
+            };
+        }
+
+        if !rule.prefix.is_empty() && !has_prefix(&name, rule.prefix, opts.unicode) {
+            name.insert_str(0, rule.prefix);
+        }
+
+        if is_identifier_case(rule.case) && self.is_keyword(&name) {
+            name = self.escape(name);
+        }
+        name
+    }
+
+    fn escape(&self, name: String) -> String {
+        match self.escape {
+            Escape::Raw if !keywords::RUST_NON_RAW.contains(&name.as_str()) => {
+                let mut s = String::with_capacity(name.len() + 2);
+                s.push_str("r#");
+                s.push_str(&name);
+                s
+            }
+            Escape::At => {
+                let mut s = String::with_capacity(name.len() + 1);
+                s.push('@');
+                s.push_str(&name);
+                s
+            }
+            Escape::Backticks => {
+                let mut s = String::with_capacity(name.len() + 2);
+                s.push('`');
+                s.push_str(&name);
+                s.push('`');
+                s
+            }
+            Escape::Raw | Escape::Underscore => {
+                let mut s = name;
+                s.push('_');
+                s
+            }
+        }
+    }
+}
+
+// Returns whether the name starts with the prefix followed by an uppercase letter.
+fn has_prefix(name: &str, prefix: &str, unicode: bool) -> bool {
+    name.starts_with(prefix)
+        && name[prefix.len()..]
+            .chars()
+            .next()
+            .map_or(false, |ch| is_upper(ch, unicode))
+}
+
+// Returns whether names in the case style can be identifiers.
+fn is_identifier_case(case: Case) -> bool {
+    matches!(
+        case,
+        Case::Ada | Case::Camel | Case::Macro | Case::Pascal | Case::Snake
+    )
+}
+
+impl fmt::Display for Convention {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// An error which is returned when parsing a string into a [`Convention`] fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseConventionError {
+    name: String,
+}
+
+impl fmt::Display for ParseConventionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown convention: {:?}", self.name)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseConventionError {}
+
+impl FromStr for Convention {
+    type Err = ParseConventionError;
+
+    /// Parses the name of a language ignoring letter cases, or one of its file extensions.
+    ///
+    /// ```rust
+    /// use stringcase::Convention;
+    ///
+    /// assert_eq!("rust".parse(), Ok(Convention::RUST));
+    /// assert_eq!("golang".parse(), Ok(Convention::GO));
+    /// assert_eq!("C#".parse(), Ok(Convention::CSHARP));
+    /// assert_eq!("py".parse(), Ok(Convention::PYTHON));
+    /// assert!("cobol".parse::<Convention>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "rust" | "rs" => Ok(Convention::RUST),
+            "go" | "golang" => Ok(Convention::GO),
+            "java" => Ok(Convention::JAVA),
+            "c#" | "csharp" | "cs" => Ok(Convention::CSHARP),
+            "python" | "py" => Ok(Convention::PYTHON),
+            "typescript" | "ts" => Ok(Convention::TYPESCRIPT),
+            "kotlin" | "kt" => Ok(Convention::KOTLIN),
+            _ => Err(ParseConventionError {
+                name: s.to_string(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests_of_convention {
+    use super::*;
+
+    #[test]
+    fn convert_in_rust() {
+        let conv = Convention::RUST;
+        assert_eq!(conv.convert("user_account", ItemKind::Type), "UserAccount");
+        assert_eq!(
+            conv.convert("getUserName", ItemKind::Function),
+            "get_user_name"
+        );
+        assert_eq!(
+            conv.convert("maxRetryCount", ItemKind::Constant),
+            "MAX_RETRY_COUNT"
+        );
+        assert_eq!(
+            conv.convert("http-client", ItemKind::Package),
+            "http_client"
+        );
+    }
+
+    #[test]
+    fn uppercase_go_initialisms() {
+        let conv = Convention::GO;
+        assert_eq!(conv.convert("user_id", ItemKind::Field), "UserID");
+        assert_eq!(
+            conv.convert("http_server_url", ItemKind::Type),
+            "HTTPServerURL"
+        );
+        assert_eq!(
+            conv.convert("parseJsonApi", ItemKind::Function),
+            "ParseJSONAPI"
+        );
+        assert_eq!(conv.convert("identity", ItemKind::Type), "Identity");
+        assert_eq!(conv.convert("net_http", ItemKind::Package), "nethttp");
+
+        let acronyms = ["GRPC"];
+        let opts = Options {
+            acronyms: &acronyms,
+            ..Default::default()
+        };
+        assert_eq!(
+            conv.convert_with_options("grpc_user_id", ItemKind::Type, &opts),
+            "GRPCUserID"
+        );
+    }
+
+    #[test]
+    fn prefix_csharp_interfaces() {
+        let conv = Convention::CSHARP;
+        assert_eq!(
+            conv.convert("user_repository", ItemKind::Interface),
+            "IUserRepository"
+        );
+        assert_eq!(
+            conv.convert("IUserRepository", ItemKind::Interface),
+            "IUserRepository"
+        );
+        assert_eq!(conv.convert("item", ItemKind::Interface), "IItem");
+        assert_eq!(
+            conv.convert("user_repository", ItemKind::Type),
+            "UserRepository"
+        );
+        assert_eq!(conv.convert("", ItemKind::Interface), "");
+    }
+
+    #[test]
+    fn escape_keywords() {
+        assert_eq!(Convention::RUST.convert("type", ItemKind::Field), "r#type");
+        assert_eq!(Convention::RUST.convert("self", ItemKind::Module), "self_");
+        assert_eq!(Convention::RUST.convert("self", ItemKind::Type), "Self_");
+        assert_eq!(Convention::GO.convert("type", ItemKind::Package), "type_");
+        assert_eq!(Convention::JAVA.convert("class", ItemKind::Field), "class_");
+        assert_eq!(
+            Convention::CSHARP.convert("string", ItemKind::Field),
+            "String"
+        );
+        assert_eq!(
+            Convention::CSHARP
+                .with_rule(ItemKind::Field, CAMEL)
+                .convert("string", ItemKind::Field),
+            "@string"
+        );
+        assert_eq!(
+            Convention::PYTHON.convert("lambda", ItemKind::Field),
+            "lambda_"
+        );
+        assert_eq!(Convention::PYTHON.convert("none", ItemKind::Type), "None_");
+        assert_eq!(
+            Convention::TYPESCRIPT.convert("delete", ItemKind::Function),
+            "delete_"
+        );
+        assert_eq!(
+            Convention::TYPESCRIPT.convert("delete", ItemKind::Module),
+            "delete"
+        );
+        assert_eq!(
+            Convention::KOTLIN.convert("object", ItemKind::Field),
+            "`object`"
+        );
+    }
+
+    #[test]
+    fn rule_of_each_item_kind() {
+        for conv in Convention::ALL.iter() {
+            for kind in ItemKind::ALL.iter() {
+                assert_eq!(conv.rule(*kind), conv.rules[*kind as usize]);
+            }
+        }
+        assert_eq!(Convention::PYTHON.rule(ItemKind::Constant), MACRO);
+        assert_eq!(Convention::JAVA.rule(ItemKind::Package), Rule::flat());
+        assert_eq!(
+            Convention::CSHARP.rule(ItemKind::Interface),
+            Rule::new(Case::Pascal).with_prefix("I")
+        );
+    }
+
+    #[test]
+    fn parse_and_display() {
+        for conv in Convention::ALL.iter() {
+            assert_eq!(conv.to_string().parse(), Ok(*conv));
+        }
+        assert_eq!("KT".parse(), Ok(Convention::KOTLIN));
+        assert_eq!(
+            "cobol".parse::<Convention>().unwrap_err().to_string(),
+            "unknown convention: \"cobol\""
+        );
+    }
+}
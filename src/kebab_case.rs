@@ -2,8 +2,16 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
+#[cfg(feature = "alloc")]
 use crate::lowerize::lowerize;
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+#[cfg(feature = "alloc")]
+use crate::render::render_cow;
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, KEBAB};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
 /// Converts the input string to kebab case with the specified options.
 ///
@@ -13,10 +21,12 @@ use crate::options::Options;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let kebab = stringcase::kebab_case_with_options("fooBar123Baz", &opts);
 ///     assert_eq!(kebab, "foo-bar-123-baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn kebab_case_with_options(input: &str, opts: &Options) -> String {
     lowerize::<'-'>(input, opts)
@@ -31,6 +41,7 @@ pub fn kebab_case_with_options(input: &str, opts: &Options) -> String {
 ///     let kebab = stringcase::kebab_case("fooBar123Baz");
 ///     assert_eq!(kebab, "foo-bar123-baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn kebab_case(input: &str) -> String {
     let opts = Options {
@@ -38,11 +49,173 @@ pub fn kebab_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     lowerize::<'-'>(input, &opts)
 }
 
+/// Converts the input string to kebab case with the specified options, without allocating a new
+/// string if the input string is already in kebab case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`kebab_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     let kebab = stringcase::kebab_case_cow_with_options("foo-bar-100-baz", &opts);
+///     assert!(matches!(kebab, Cow::Borrowed("foo-bar-100-baz")));
+///
+///     let kebab = stringcase::kebab_case_cow_with_options("foo-bar100-baz", &opts);
+///     assert!(matches!(kebab, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn kebab_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, KEBAB)
+}
+
+/// Converts the input string to kebab case, without allocating a new string if the input string
+/// is already in kebab case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`kebab_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let kebab = stringcase::kebab_case_cow("foo-bar100-baz");
+///     assert!(matches!(kebab, Cow::Borrowed("foo-bar100-baz")));
+///
+///     let kebab = stringcase::kebab_case_cow("foo_bar100_baz");
+///     assert_eq!(kebab, stringcase::kebab_case("foo_bar100_baz"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn kebab_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, KEBAB)
+}
+
+/// Converts the input string to kebab case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`kebab_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::kebab_case_into("foo_bar100_baz", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: foo-bar100-baz");
+/// ```
+#[inline(always)]
+pub fn kebab_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, KEBAB)
+}
+
+/// Converts the input string to kebab case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`kebab_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::kebab_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+///     assert_eq!(result, Ok("foo-bar100-baz"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::kebab_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 14);
+/// ```
+#[inline(always)]
+pub fn kebab_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, KEBAB)
+}
+
+/// A wrapper of a string slice which is displayed in kebab case.
+///
+/// It converts the string while formatting it in the same way as [`kebab_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::KebabCase;
+///
+///     let s = format!("name: {}", KebabCase("foo_bar100_baz"));
+///     assert_eq!(s, "name: foo-bar100-baz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KebabCase<'a>(pub &'a str);
+
+impl fmt::Display for KebabCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, KEBAB)
+    }
+}
+
+/// Checks whether the input string is in kebab case with the specified options.
+///
+/// It returns `true` if converting the input string with [`kebab_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_kebab_case_with_options("foo-bar-100-baz", &opts));
+///     assert!(!stringcase::is_kebab_case_with_options("foo-bar100-baz", &opts));
+/// ```
+#[inline(always)]
+pub fn is_kebab_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, KEBAB)
+}
+
+/// Checks whether the input string is in kebab case.
+///
+/// It returns `true` if converting the input string with [`kebab_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_kebab_case("foo-bar100-baz"));
+///     assert!(!stringcase::is_kebab_case("foo_bar100_baz"));
+/// ```
+#[inline(always)]
+pub fn is_kebab_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, KEBAB)
+}
+
 /// Converts the input string to kebab case with the specified separator characters.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use kebab_case_with_options instead")]
 #[inline(always)]
@@ -52,11 +225,13 @@ pub fn kebab_case_with_sep(input: &str, seps: &str) -> String {
         separate_after_non_alphabets: true,
         separators: seps,
         keep: "",
+        ..Default::default()
     };
     lowerize::<'-'>(input, &opts)
 }
 
 /// Converts the input string to kebab case with the specified characters to be kept.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use kebab_case_with_options instead")]
 #[inline(always)]
@@ -66,6 +241,7 @@ pub fn kebab_case_with_keep(input: &str, kept: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: kept,
+        ..Default::default()
     };
     lowerize::<'-'>(input, &opts)
 }
@@ -74,6 +250,7 @@ pub fn kebab_case_with_keep(input: &str, kept: &str) -> String {
 ///
 /// It treats the beginning and the end of a sequence of non-alphabetic characters as a word
 /// boundary.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use kebab_case_with_options instead")]
 #[inline(always)]
@@ -83,11 +260,12 @@ pub fn kebab_case_with_nums_as_word(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     lowerize::<'-'>(input, &opts)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_kebab_case {
     use super::*;
 
@@ -164,7 +342,154 @@ mod tests_of_kebab_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_kebab_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_kebab_case() {
+        assert!(matches!(
+            kebab_case_cow("foo-bar100-baz"),
+            Cow::Borrowed("foo-bar100-baz")
+        ));
+        assert!(matches!(kebab_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_kebab_case() {
+        let result = kebab_case_cow("fooBar100Baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "foo-bar100-baz");
+
+        let result = kebab_case_cow("foo-bar100-baz_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, kebab_case("foo-bar100-baz_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["foo-bar100-baz", "fooBar100Baz", "foo-bar100-baz_", ""].iter() {
+            let result = kebab_case_cow_with_options(input, &opts);
+            assert_eq!(result, kebab_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_kebab_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        kebab_case_into("foo_bar100_baz", &opts, &mut out).unwrap();
+        kebab_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "foo-bar100-baz");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        kebab_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, kebab_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(KebabCase("foo_bar100_baz").to_string(), "foo-bar100-baz");
+        assert_eq!(format!("<{}>", KebabCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_kebab_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = kebab_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Ok("foo-bar100-baz"));
+
+        let result = kebab_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 14];
+        let result = kebab_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Ok("foo-bar100-baz"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 13];
+        let result = kebab_case_to_slice("foo_bar100_baz", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 14 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = kebab_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(kebab_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_kebab_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_kebab_case() {
+        assert!(is_kebab_case("foo-bar100-baz"));
+        assert!(is_kebab_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_kebab_case() {
+        assert!(!is_kebab_case("foo_bar"));
+        assert!(!is_kebab_case("fooBar"));
+        assert!(!is_kebab_case("Foo-Bar"));
+        assert!(!is_kebab_case("_foo-bar100-baz"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_kebab_case_with_options("foo-bar-100-baz", &opts));
+        assert!(is_kebab_case_with_options("", &opts));
+        assert!(!is_kebab_case_with_options("foo-bar100-baz", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_cobol_case_with_options {
     use super::*;
 
@@ -178,6 +503,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -191,6 +517,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -204,6 +531,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -217,6 +545,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -230,6 +559,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -243,6 +573,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -256,6 +587,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -269,6 +601,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -282,6 +615,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -295,6 +629,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -314,6 +649,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -331,6 +667,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -344,6 +681,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -357,6 +695,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -370,6 +709,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -383,6 +723,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -396,6 +737,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -409,6 +751,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -422,6 +765,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -435,6 +779,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -448,6 +793,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -467,6 +813,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -484,6 +831,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -497,6 +845,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -510,6 +859,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -523,6 +873,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -536,6 +887,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -549,6 +901,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -562,6 +915,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -575,6 +929,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -588,6 +943,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -601,6 +957,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -620,6 +977,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -637,6 +995,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -650,6 +1009,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -663,6 +1023,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -676,6 +1037,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -689,6 +1051,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -702,6 +1065,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -715,6 +1079,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -728,6 +1093,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -741,6 +1107,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -754,6 +1121,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -773,6 +1141,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -790,6 +1159,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -803,6 +1173,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -816,6 +1187,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -833,6 +1205,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -850,6 +1223,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -867,6 +1241,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -884,6 +1259,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -901,6 +1277,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -918,6 +1295,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -931,6 +1309,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -947,6 +1326,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -960,6 +1340,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123def", &opts);
@@ -977,6 +1358,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -990,6 +1372,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -1003,6 +1386,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -1020,6 +1404,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -1037,6 +1422,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -1054,6 +1440,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -1071,6 +1458,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -1088,6 +1476,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -1105,6 +1494,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1118,6 +1508,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -1134,6 +1525,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -1147,6 +1539,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123def", &opts);
@@ -1164,6 +1557,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -1177,6 +1571,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -1190,6 +1585,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -1207,6 +1603,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -1224,6 +1621,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -1241,6 +1639,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -1258,6 +1657,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -1275,6 +1675,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -1292,6 +1693,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1305,6 +1707,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -1321,6 +1724,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -1334,6 +1738,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123def", &opts);
@@ -1351,6 +1756,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -1364,6 +1770,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -1377,6 +1784,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -1394,6 +1802,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -1411,6 +1820,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -1428,6 +1838,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -1445,6 +1856,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -1462,6 +1874,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -1479,6 +1892,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1492,6 +1906,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -1508,6 +1923,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -1521,6 +1937,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abc123def", &opts);
@@ -1538,6 +1955,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -1551,6 +1969,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -1564,6 +1983,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -1581,6 +2001,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -1598,6 +2019,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -1615,6 +2037,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -1632,6 +2055,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -1649,6 +2073,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -1666,6 +2091,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -1682,6 +2108,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1695,6 +2122,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -1712,6 +2140,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -1725,6 +2154,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -1738,6 +2168,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -1755,6 +2186,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -1772,6 +2204,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -1789,6 +2222,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -1806,6 +2240,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -1823,6 +2258,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -1840,6 +2276,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -1857,6 +2294,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1870,6 +2308,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -1887,6 +2326,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -1900,6 +2340,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -1913,6 +2354,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -1930,6 +2372,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -1947,6 +2390,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -1964,6 +2408,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -1981,6 +2426,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -1998,6 +2444,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -2015,6 +2462,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -2031,6 +2479,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -2044,6 +2493,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
@@ -2061,6 +2511,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("abcDefGHIjk", &opts);
@@ -2074,6 +2525,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("AbcDefGHIjk", &opts);
@@ -2087,6 +2539,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc_def_ghi", &opts);
@@ -2104,6 +2557,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc-def-ghi", &opts);
@@ -2121,6 +2575,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("Abc-Def-Ghi", &opts);
@@ -2138,6 +2593,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC_DEF_GHI", &opts);
@@ -2155,6 +2611,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("ABC-DEF-GHI", &opts);
@@ -2172,6 +2629,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = kebab_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -2189,6 +2647,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("123abc456def", &opts);
@@ -2205,6 +2664,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -2218,6 +2678,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = kebab_case_with_options("", &opts);
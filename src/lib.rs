@@ -13,6 +13,15 @@
 //! capitalization. All characters other than ASCII uppercase and lowercase letters and ASCII
 //! numbers are removed as word separators.
 //!
+//! If you want to treat non-ASCII letters as letters, set the `unicode` field of `Options` to
+//! `true`. Then all Unicode uppercase and lowercase letters are used to detect word boundaries
+//! and are converted with full Unicode case mappings (e.g. `ß` is uppercased to `SS`).
+//! This field is `false` by default, so the `〜_case` functions which do not take `Options` and
+//! the methods of `Caser` always handle only ASCII letters, and the Unicode mode is opted in per
+//! call, for example with `Options::default().with_unicode(true)`.
+//! This mode is not a cargo feature, because a feature enabled by any crate in a dependency graph
+//! would change the results of these functions for all other crates in the graph.
+//!
 //! If you want to use some symbols as separators, specify those symbols in the `separators` field
 //! of `Options` struct and use the `〜case_with_options` function for the desired case.
 //! If you want to retain certain symbols and use everything else as separators, specify those
@@ -29,13 +38,63 @@
 //! In other words, they behave as if `separate_before_non_alphabets = false` and
 //! `separate_after_non_alphabets = true`.
 //!
+//! If some words in the input strings are acronyms, specify them in the `acronyms` field of
+//! `Options`. A sequence of uppercase letters which consists only of these acronyms is split into
+//! each acronym, and the `uppercase_acronyms` field specifies whether to uppercase these acronyms
+//! (e.g. `HTTPServerID`) or to capitalize them like other words (e.g. `HttpServerId`) in case
+//! styles which capitalize words. The `uppercase_acronyms_in` field limits the case styles in
+//! which acronyms are uppercased, so that each case style decides how acronyms are rendered.
+//!
 //! ## Install
 //!
 //! In `Cargo.toml`, write this crate as a dependency.
 //!
 //! ```toml
 //! [dependencies]
-//! stringcase = "0.4.0"
+//! stringcase = "0.5.0"
+//! ```
+//!
+//! This crate depends on `std` by default through the `std` feature.
+//! To use this crate in `no_std` environments, disable the default features and enable the
+//! `alloc` feature.
+//! Then this crate depends only on `core` and `alloc`, and all conversion functions, `Options`,
+//! and `Caser` are still available.
+//!
+//! ```toml
+//! [dependencies]
+//! stringcase = { version = "0.5.0", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! Without the `alloc` feature, this crate does not allocate memory at all, and only the functions
+//! which do not return `String` are available, such as `〜_case_to_slice`, `〜_case_into`,
+//! `is_〜_case`, `detect_case`, `eq_ignore_case_style`, and `words`.
+//!
+//! The `serde` feature, which also enables the `std` feature, enables the module `stringcase::serde`
+//! to convert the keys of serialized and deserialized data into a case style given at runtime.
+//!
+//! ```toml
+//! [dependencies]
+//! stringcase = { version = "0.5.0", features = ["serde"] }
+//! ```
+//!
+//! The `syn` feature enables the module `stringcase::syn` to convert identifiers of `proc_macro2`
+//! and `syn` in procedural macros, handling raw identifiers and keywords and keeping spans.
+//!
+//! The `codemod` feature enables the module `stringcase::codemod` to rename identifiers in source
+//! code of Rust, JavaScript, TypeScript, Python, and Go into case styles, and to print the changes
+//! as unified diffs.
+//!
+//! The `cli` feature builds the command-line tool `stringcase`, which converts arguments or lines
+//! of the standard input into case styles, prints them in all case styles as a table or JSON, and
+//! detects their case styles. All fields of `Options` are available as its flags.
+//! Its subcommand `rename` renames the files and directories in directory trees into a case style,
+//! and `codemod` renames the identifiers in source files into a case style.
+//!
+//! ```sh
+//! $ cargo install stringcase --features cli
+//! $ stringcase convert snake fooBar "HTTP Server"
+//! foo_bar
+//! http_server
 //! ```
 //!
 //! ## Usage
@@ -43,26 +102,87 @@
 //! The functions in this crate can be used as follows:
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")]
 //! use stringcase::snake_case;
 //!
+//! # #[cfg(feature = "alloc")]
 //! fn main() {
 //!     let input = "fooBar123Baz";
 //!     let snake = snake_case(input);
 //!     assert_eq!(snake, "foo_bar123_baz");
 //! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
 //! ```
 //!
 //! If you want the conversion to behave differently, use `〜_case_with_options`.
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")]
 //! use stringcase::{snake_case_with_options, Options};
 //!
+//! # #[cfg(feature = "alloc")]
 //! fn main() {
 //!     let opts = Options{separate_before_non_alphabets: true, ..Default::default()};
 //!     let input = "fooBar123Baz";
 //!     let snake = snake_case_with_options(input, &opts);
 //!     assert_eq!(snake, "foo_bar_123_baz");
 //! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! If most of the input strings are already in the target case, the `〜_case_cow` functions avoid
+//! allocating new strings.
+//! They return `Cow::Borrowed` with the input string when the conversion would not change it, and
+//! `Cow::Owned` with the converted string otherwise:
+//!
+//! ```rust
+//! use std::borrow::Cow;
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::snake_case_cow;
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     assert!(matches!(snake_case_cow("foo_bar123_baz"), Cow::Borrowed("foo_bar123_baz")));
+//!     assert_eq!(snake_case_cow("fooBar123Baz"), "foo_bar123_baz");
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! To write converted strings into generated source code or log lines without intermediate
+//! strings, use the `〜_case_into` functions, which write into any `std::fmt::Write`, or the
+//! wrappers like `SnakeCase` and the `display_as` method of `Caser`, which implement `Display`:
+//!
+//! ```rust
+//! use std::fmt::Write;
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{kebab_case_into, Case, Caser, Options, SnakeCase};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     let mut out = String::new();
+//!     kebab_case_into("fooBar123Baz", &Options::default(), &mut out).unwrap();
+//!     write!(out, " {} {}", SnakeCase("fooBar123Baz"), "fooBar123Baz".display_as(Case::Title))
+//!         .unwrap();
+//!     assert_eq!(out, "foo-bar123-baz foo_bar123_baz Foo Bar123 Baz");
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! Without an allocator, the `〜_case_to_slice` functions write converted strings into byte
+//! buffers, and return `BufferTooSmall` with the required length if the buffers are too small:
+//!
+//! ```rust
+//! use stringcase::{snake_case_to_slice, Options};
+//!
+//! fn main() {
+//!     let mut buf = [0u8; 32];
+//!     let snake = snake_case_to_slice("fooBar123Baz", &Options::default(), &mut buf);
+//!     assert_eq!(snake, Ok("foo_bar123_baz"));
+//! }
 //! ```
 //!
 //! You can also use the generic functions `capitalize`, `lowerize`, and `upperize` to convert
@@ -70,8 +190,10 @@
 //! character:
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")]
 //! use stringcase::{capitalize, lowerize, upperize, Options};
 //!
+//! # #[cfg(feature = "alloc")]
 //! fn main() {
 //!     let opts = Options {
 //!         separate_before_non_alphabets: true,
@@ -83,14 +205,202 @@
 //!     assert_eq!(lowerize::<'.'>(input, &opts), "foo.bar.123.baz");
 //!     assert_eq!(upperize::<'.'>(input, &opts), "FOO.BAR.123.BAZ");
 //! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! The function `words` splits a string into the words which the above functions capitalize,
+//! lowercase, or uppercase. Each word is yielded as a slice of the input string with its byte
+//! range:
+//!
+//! ```rust
+//! use stringcase::{words, Options};
+//!
+//! fn main() {
+//!     let opts = Options::default();
+//!     let input = "fooBar123Baz";
+//!     let result: Vec<&str> = words(input, &opts).map(|w| w.text).collect();
+//!     assert_eq!(result, ["foo", "Bar123", "Baz"]);
+//! }
+//! ```
+//!
+//! The functions `is_〜_case` check whether a string is in a case style, which means that
+//! converting it with the matching `〜_case` function returns it unchanged.
+//! And the function `detect_case` returns the case style of a string as a `Case` value, or `None`
+//! if the string mixes case styles.
+//! A string which is in multiple case styles, like a single word `foo`, is detected as the first
+//! of them in the order of snake, kebab, camel, macro, cobol, pascal, Ada, train, and title case.
+//!
+//! ```rust
+//! use stringcase::{detect_case, is_snake_case, Case};
+//!
+//! fn main() {
+//!     assert!(is_snake_case("foo_bar123_baz"));
+//!     assert!(!is_snake_case("fooBar123Baz"));
+//!
+//!     assert_eq!(detect_case("fooBar123Baz"), Some(Case::Camel));
+//!     assert_eq!(detect_case("foo_barBaz"), None);
+//! }
+//! ```
+//!
+//! The enum `Case` represents these case styles at runtime, for example when a case style is read
+//! from a configuration file.
+//! It can be parsed from names like `"snake_case"`, `"kebab"`, `"SCREAMING_SNAKE"`, or
+//! `"Title Case"`, and the function `convert` converts a string to a `Case`:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{convert, Case, Options};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     let case: Case = "kebab".parse().unwrap();
+//!     let opts = Options::default();
+//!     assert_eq!(convert("fooBar123Baz", case, &opts), "foo-bar123-baz");
+//!     assert_eq!(case.to_string(), "kebab-case");
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! If you need a string in all case styles, for example to index identifiers for search, the
+//! function `all_cases` splits the string into words only once and converts it into all case
+//! styles, which are the same as the results of the individual functions:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{all_cases, Case, Options};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     let cases = all_cases("fooBar123Baz", &Options::default());
+//!     assert_eq!(cases.snake_case(), "foo_bar123_baz");
+//!     assert_eq!(cases.get(Case::Macro), "FOO_BAR123_BAZ");
+//!     for (case, s) in cases.iter() {
+//!         println!("{}: {}", case, s);
+//!     }
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! When many names are converted at once, for example the column names of a table into field
+//! names, distinct names like `userID` and `user_id` can be converted into the same name.
+//! The function `convert_batch` converts a list of strings into a `Case` and reports such
+//! collisions, and its result can make the converted strings unique with deterministic suffixes:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{convert_batch, Case, Options, Suffix};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     let opts = Options::default();
+//!     let batch = convert_batch(&["userID", "user_id", "name"], Case::Snake, &opts);
+//!     assert_eq!(batch.outputs(), ["user_id", "user_id", "name"]);
+//!     assert_eq!(batch.collisions()[0].inputs, ["userID", "user_id"]);
+//!     assert_eq!(batch.resolve(Suffix::Number), ["user_id_2", "user_id", "name"]);
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! When the length of names is limited, like 63 bytes of identifiers in PostgreSQL, the functions
+//! `shorten` and `shorten_with_options` convert a string into a `Case` and shorten it to a maximum
+//! byte length by word.
+//! They drop vowels and trim words from the middle words, and append a short stable hash when
+//! names are shortened, so that long names do not collide after shortening.
+//! They return `None` if the maximum length is too short to hold the head of the name and the
+//! hash:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{shorten, Case};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     let input = "customerAccountBillingAddressId";
+//!     assert_eq!(shorten(input, Case::Snake, 63).unwrap(), "customer_account_billing_address_id");
+//!     assert_eq!(shorten(input, Case::Snake, 31).unwrap(), "cstmr_accn_blln_addrs_id_e934b1");
+//!     assert_eq!(shorten(input, Case::Snake, 24).unwrap(), "cst_acc_bl_add_id_e934b1");
+//!     assert_eq!(shorten(input, Case::Snake, 7), None);
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! To rename a concept in a text, the function `replace_all_variants` replaces all case variants
+//! of a string, like `user_account`, `userAccount`, `USER_ACCOUNT`, and `User Account`, with the
+//! same case variants of another string, and returns the replacements made:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{replace_all_variants, Options};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     let opts = Options::default();
+//!     let text = "let user_account = UserAccount::new(); // USER_ACCOUNT";
+//!     let (result, replacements) =
+//!         replace_all_variants(text, "user account", "customer profile", &opts);
+//!     assert_eq!(result, "let customer_profile = CustomerProfile::new(); // CUSTOMER_PROFILE");
+//!     assert_eq!(replacements.len(), 3);
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! To treat strings in different case styles as the same string, the function
+//! `eq_ignore_case_style` compares the lowercased words of strings, and `CaseKey` is a string which
+//! is compared, hashed, and ordered in the same way while keeping its original spelling.
+//! And `CaseInsensitiveMap`, which requires the `std` feature, is a `HashMap` with such keys, in
+//! which `userId`, `user_id`, and `USER-ID` are the same key:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{eq_ignore_case_style, CaseKey, Options};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     assert!(eq_ignore_case_style("userId", "USER-ID", &Options::default()));
+//!     assert_eq!(CaseKey::new("userId"), CaseKey::new("user_id"));
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+//!
+//! To name items of source code in the naming convention of a programming language,
+//! `Convention` has the built-in profiles of Rust, Go, Java, C#, Python, TypeScript, and Kotlin.
+//! A profile maps an `ItemKind`, like a type, a function, a constant, or a package, to a case
+//! style, and uppercases acronyms, like the initialisms of Go, adds prefixes, like `I` of
+//! interfaces in C#, and escapes reserved words:
+//!
+//! ```rust
+//! # #[cfg(feature = "alloc")]
+//! use stringcase::{Convention, ItemKind};
+//!
+//! # #[cfg(feature = "alloc")]
+//! fn main() {
+//!     assert_eq!(Convention::GO.convert("user_id", ItemKind::Field), "UserID");
+//!     assert_eq!(Convention::PYTHON.convert("maxRetries", ItemKind::Constant), "MAX_RETRIES");
+//!     assert_eq!(
+//!         Convention::CSHARP.convert("user_repository", ItemKind::Interface),
+//!         "IUserRepository"
+//!     );
+//!     assert_eq!(Convention::RUST.convert("type", ItemKind::Field), "r#type");
+//! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
 //! ```
 //!
 //! And by bringing `Caser` with `use` declaration, it will be able to execute
 //! methods of strings, `String` or `&str`, to convert to their cases.
 //!
 //! ```rust
+//! # #[cfg(feature = "alloc")]
 //! use stringcase::{Caser, Options};
 //!
+//! # #[cfg(feature = "alloc")]
 //! fn main() {
 //!     let input = "fooBar123Baz";
 //!     let snake = input.to_snake_case();
@@ -100,12 +410,26 @@
 //!     let snake = input.to_snake_case_with_options(&opts);
 //!     assert_eq!(snake, "foo_bar_123_baz");
 //! }
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
 //! ```
 
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod chars;
+#[cfg(feature = "alloc")]
+mod keywords;
 mod options;
+mod render;
 pub use options::Options;
+pub use render::BufferTooSmall;
 
+#[cfg(feature = "alloc")]
 mod upperize;
+#[cfg(feature = "alloc")]
 pub use upperize::upperize;
 
 mod cobol_case;
@@ -113,7 +437,9 @@ mod macro_case;
 pub use cobol_case::*;
 pub use macro_case::*;
 
+#[cfg(feature = "alloc")]
 mod lowerize;
+#[cfg(feature = "alloc")]
 pub use lowerize::lowerize;
 
 mod kebab_case;
@@ -121,7 +447,9 @@ mod snake_case;
 pub use kebab_case::*;
 pub use snake_case::*;
 
+#[cfg(feature = "alloc")]
 mod capitalize;
+#[cfg(feature = "alloc")]
 pub use capitalize::capitalize;
 
 mod ada_case;
@@ -136,5 +464,54 @@ pub use train_case::*;
 mod camel_case;
 pub use camel_case::*;
 
+mod words;
+pub use words::{words, Word, Words};
+
+mod case;
+pub use case::*;
+
+mod case_key;
+pub use case_key::eq_ignore_case_style;
+#[cfg(feature = "std")]
+pub use case_key::CaseInsensitiveMap;
+#[cfg(feature = "alloc")]
+pub use case_key::CaseKey;
+
+#[cfg(feature = "alloc")]
+mod all_cases;
+#[cfg(feature = "alloc")]
+pub use all_cases::{all_cases, AllCases, AllCasesIntoIter, AllCasesIter};
+
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "alloc")]
+pub use batch::{convert_batch, BatchConversion, Collision, Suffix};
+
+#[cfg(feature = "alloc")]
+mod shorten;
+#[cfg(feature = "alloc")]
+pub use shorten::{shorten, shorten_with_options};
+
+#[cfg(feature = "alloc")]
+mod replace;
+#[cfg(feature = "alloc")]
+pub use replace::{replace_all_variants, Replacement};
+
+#[cfg(feature = "alloc")]
+mod convention;
+#[cfg(feature = "alloc")]
+pub use convention::{Convention, ItemKind, ParseConventionError, Rule};
+
+#[cfg(feature = "alloc")]
 mod caser;
+#[cfg(feature = "alloc")]
 pub use caser::*;
+
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "syn")]
+pub mod syn;
+
+#[cfg(feature = "codemod")]
+pub mod codemod;
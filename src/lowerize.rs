@@ -3,6 +3,9 @@
 // See the file LICENSE in this distribution for more details.
 
 use crate::options::Options;
+use crate::render::{render_string, Style, WordCase};
+
+use alloc::string::String;
 
 /// A generic function that converts string cases into a lowercased format joined by a specified
 /// joiner character.
@@ -11,13 +14,15 @@ use crate::options::Options;
 /// non-alphabetic character rules defined in `opts`, converts alphabetic characters to lowercase,
 /// and joins the words using the const generic character `JOINER`.
 ///
-/// It operates by iterating character by character through `input` using an internal state
-/// machine. It handles ASCII uppercase, ASCII lowercase, and non-alphabetic characters (digits and
+/// It splits `input` into words in the same way as the [`words`](crate::words) function.
+/// It handles ASCII uppercase, ASCII lowercase, and non-alphabetic characters (digits and
 /// symbols) according to options such as `opts.separators`, `opts.keep`,
 /// `opts.separate_before_non_alphabets`, and `opts.separate_after_non_alphabets` to determine
 /// word boundaries, lowerizing characters, and insert the `JOINER` character.
 /// If a character is specified in both `opts.separators` and `opts.keep`, the character in
 /// `opts.separators` takes precedence and the character in `opts.keep` is ignored.
+/// If `opts.unicode` is `true`, it handles Unicode uppercase and lowercase letters instead of
+/// ASCII ones and converts them with full Unicode case mappings.
 ///
 /// # Parameters
 ///
@@ -41,99 +46,13 @@ use crate::options::Options;
 ///     separate_after_non_alphabets: true,
 ///     separators: "",
 ///     keep: "",
+///     ..Default::default()
 /// };
 /// let result = lowerize::<'.' >("foo_bar_100_baz", &opts);
 /// assert_eq!(result, "foo.bar.100.baz");
 /// ```
 pub fn lowerize<const JOINER: char>(input: &str, opts: &Options) -> String {
-    let mut result = String::with_capacity(input.len() + input.len() / 2);
-    // .len returns byte count but ok in this case!
-
-    #[derive(PartialEq)]
-    enum ChIs {
-        FirstOfStr,
-        NextOfUpper,
-        NextOfContdUpper,
-        NextOfSepMark,
-        NextOfKeptMark,
-        Other,
-    }
-
-    let mut flag = ChIs::FirstOfStr;
-
-    for ch in input.chars() {
-        if ch.is_ascii_uppercase() {
-            if flag == ChIs::FirstOfStr {
-                result.push(ch.to_ascii_lowercase());
-                flag = ChIs::NextOfUpper;
-            } else if flag == ChIs::NextOfUpper
-                || flag == ChIs::NextOfContdUpper
-                || (!opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(ch.to_ascii_lowercase());
-                flag = ChIs::NextOfContdUpper;
-            } else {
-                result.push(JOINER);
-                result.push(ch.to_ascii_lowercase());
-                flag = ChIs::NextOfUpper;
-            }
-        } else if ch.is_ascii_lowercase() {
-            if flag == ChIs::NextOfContdUpper {
-                if let Some(prev) = result.pop() {
-                    result.push(JOINER);
-                    result.push(prev);
-                    result.push(ch);
-                }
-            } else if flag == ChIs::NextOfSepMark
-                || (opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(JOINER);
-                result.push(ch);
-            } else {
-                result.push(ch);
-            }
-            flag = ChIs::Other;
-        } else {
-            let mut is_kept_char = false;
-            if ch.is_ascii_digit() {
-                is_kept_char = true;
-            } else if !opts.separators.is_empty() {
-                if !opts.separators.contains(ch) {
-                    is_kept_char = true;
-                }
-            } else if !opts.keep.is_empty() {
-                #[allow(clippy::collapsible_if)]
-                if opts.keep.contains(ch) {
-                    is_kept_char = true;
-                }
-            }
-
-            if is_kept_char {
-                if opts.separate_before_non_alphabets {
-                    if flag == ChIs::FirstOfStr || flag == ChIs::NextOfKeptMark {
-                        result.push(ch);
-                    } else {
-                        result.push(JOINER);
-                        result.push(ch);
-                    }
-                } else {
-                    if flag != ChIs::NextOfSepMark {
-                        result.push(ch);
-                    } else {
-                        result.push(JOINER);
-                        result.push(ch);
-                    }
-                }
-                flag = ChIs::NextOfKeptMark;
-            } else {
-                if flag != ChIs::FirstOfStr {
-                    flag = ChIs::NextOfSepMark;
-                }
-            }
-        }
-    }
-
-    result
+    render_string(input, opts, Style::joined(WordCase::Lower, JOINER))
 }
 
 #[cfg(test)]
@@ -150,6 +69,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -163,6 +83,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -176,6 +97,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -189,6 +111,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -202,6 +125,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -215,6 +139,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -228,6 +153,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -241,6 +167,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -254,6 +181,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -267,6 +195,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -286,6 +215,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -303,6 +233,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -316,6 +247,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -329,6 +261,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -342,6 +275,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -355,6 +289,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -368,6 +303,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -381,6 +317,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -394,6 +331,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -407,6 +345,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -420,6 +359,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -439,6 +379,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -456,6 +397,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -469,6 +411,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -482,6 +425,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -495,6 +439,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -508,6 +453,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -521,6 +467,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -534,6 +481,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -547,6 +495,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -560,6 +509,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -573,6 +523,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -592,6 +543,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -609,6 +561,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -622,6 +575,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -635,6 +589,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -648,6 +603,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -661,6 +617,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -674,6 +631,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -687,6 +645,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -700,6 +659,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -713,6 +673,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -726,6 +687,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -745,6 +707,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -762,6 +725,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -775,6 +739,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -788,6 +753,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -805,6 +771,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -822,6 +789,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -839,6 +807,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -856,6 +825,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -873,6 +843,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -890,6 +861,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -903,6 +875,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -919,6 +892,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -932,6 +906,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123def", &opts);
@@ -949,6 +924,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -962,6 +938,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -975,6 +952,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -992,6 +970,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -1009,6 +988,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -1026,6 +1006,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -1043,6 +1024,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -1060,6 +1042,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -1077,6 +1060,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1090,6 +1074,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -1106,6 +1091,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -1119,6 +1105,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123def", &opts);
@@ -1136,6 +1123,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -1149,6 +1137,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -1162,6 +1151,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -1179,6 +1169,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -1196,6 +1187,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -1213,6 +1205,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -1230,6 +1223,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -1247,6 +1241,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -1264,6 +1259,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1277,6 +1273,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -1293,6 +1290,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -1306,6 +1304,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123def", &opts);
@@ -1323,6 +1322,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -1336,6 +1336,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -1349,6 +1350,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -1366,6 +1368,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -1383,6 +1386,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -1400,6 +1404,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -1417,6 +1422,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -1434,6 +1440,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -1451,6 +1458,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1464,6 +1472,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -1480,6 +1489,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -1493,6 +1503,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abc123def", &opts);
@@ -1510,6 +1521,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -1523,6 +1535,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -1536,6 +1549,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -1553,6 +1567,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -1570,6 +1585,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -1587,6 +1603,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -1604,6 +1621,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -1621,6 +1639,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -1638,6 +1657,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -1654,6 +1674,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1667,6 +1688,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -1684,6 +1706,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -1697,6 +1720,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -1710,6 +1734,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -1727,6 +1752,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -1744,6 +1770,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -1761,6 +1788,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -1778,6 +1806,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -1795,6 +1824,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -1812,6 +1842,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -1829,6 +1860,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -1842,6 +1874,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -1859,6 +1892,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -1872,6 +1906,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -1885,6 +1920,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -1902,6 +1938,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -1919,6 +1956,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -1936,6 +1974,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -1953,6 +1992,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -1970,6 +2010,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -1987,6 +2028,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -2003,6 +2045,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -2016,6 +2059,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
@@ -2033,6 +2077,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("abcDefGHIjk", &opts);
@@ -2046,6 +2091,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("AbcDefGHIjk", &opts);
@@ -2059,6 +2105,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc_def_ghi", &opts);
@@ -2076,6 +2123,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc-def-ghi", &opts);
@@ -2093,6 +2141,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("Abc-Def-Ghi", &opts);
@@ -2110,6 +2159,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC_DEF_GHI", &opts);
@@ -2127,6 +2177,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("ABC-DEF-GHI", &opts);
@@ -2144,6 +2195,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
 
             let mut result = lowerize::<'.'>("abc123-456defG89HIJklMN12", &opts);
@@ -2161,6 +2213,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("123abc456def", &opts);
@@ -2177,6 +2230,7 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -2190,10 +2244,79 @@ mod tests_of_lowerize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
 
             let result = lowerize::<'.'>("", &opts);
             assert_eq!(result, "");
         }
     }
+
+    mod unicode_letters {
+        use super::*;
+
+        #[test]
+        fn convert_non_ascii_letters_as_separators_by_default() {
+            let opts = Options {
+                unicode: false,
+                ..Default::default()
+            };
+            let result = lowerize::<'.'>("straßeÜberweisung", &opts);
+            assert_eq!(result, "stra.e.berweisung");
+        }
+
+        #[test]
+        fn convert_non_ascii_letters() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = lowerize::<'.'>("straßeÜberweisung", &opts);
+            assert_eq!(result, "straße.überweisung");
+
+            let result = lowerize::<'.'>("ÉtatCivil", &opts);
+            assert_eq!(result, "état.civil");
+
+            let result = lowerize::<'.'>("ÉTATCivil", &opts);
+            assert_eq!(result, "état.civil");
+        }
+
+        #[test]
+        fn convert_with_one_to_many_case_mappings() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = lowerize::<'.'>("İSTANBULİçin", &opts);
+            assert_eq!(result, "i̇stanbul.i̇çin");
+        }
+
+        #[test]
+        fn convert_letters_without_case_and_non_ascii_digits() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = lowerize::<'.'>("日本語_fooBar٣baz", &opts);
+            assert_eq!(result, "日本語.foo.bar٣.baz");
+        }
+    }
+
+    mod acronyms {
+        use super::*;
+
+        #[test]
+        fn convert_with_acronyms() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                uppercase_acronyms: true,
+                ..Default::default()
+            };
+            let result = lowerize::<'.'>("XMLHTTPRequest", &opts);
+            assert_eq!(result, "xml.http.request");
+
+            let result = lowerize::<'.'>("httpServerID", &opts);
+            assert_eq!(result, "http.server.id");
+        }
+    }
 }
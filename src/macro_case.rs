@@ -2,9 +2,17 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+#[cfg(feature = "alloc")]
+use crate::render::render_cow;
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, MACRO};
+#[cfg(feature = "alloc")]
 use crate::upperize::upperize;
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
+
 /// Converts the input string to macro case with the specified options.
 ///
 /// ```rust
@@ -13,10 +21,12 @@ use crate::upperize::upperize;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let result = stringcase::macro_case_with_options("fooBar123Baz", &opts);
 ///     assert_eq!(result, "FOO_BAR_123_BAZ");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn macro_case_with_options(input: &str, opts: &Options) -> String {
     upperize::<'_'>(input, opts)
@@ -31,6 +41,7 @@ pub fn macro_case_with_options(input: &str, opts: &Options) -> String {
 ///     let result = stringcase::macro_case("fooBar123Baz");
 ///     assert_eq!(result, "FOO_BAR123_BAZ");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn macro_case(input: &str) -> String {
     let opts = Options {
@@ -38,11 +49,173 @@ pub fn macro_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     upperize::<'_'>(input, &opts)
 }
 
+/// Converts the input string to macro case with the specified options, without allocating a new
+/// string if the input string is already in macro case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`macro_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     let result = stringcase::macro_case_cow_with_options("FOO_BAR_100_BAZ", &opts);
+///     assert!(matches!(result, Cow::Borrowed("FOO_BAR_100_BAZ")));
+///
+///     let result = stringcase::macro_case_cow_with_options("FOO_BAR100_BAZ", &opts);
+///     assert!(matches!(result, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn macro_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, MACRO)
+}
+
+/// Converts the input string to macro case, without allocating a new string if the input string
+/// is already in macro case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`macro_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let result = stringcase::macro_case_cow("FOO_BAR100_BAZ");
+///     assert!(matches!(result, Cow::Borrowed("FOO_BAR100_BAZ")));
+///
+///     let result = stringcase::macro_case_cow("FOO-BAR100-BAZ");
+///     assert_eq!(result, stringcase::macro_case("FOO-BAR100-BAZ"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn macro_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, MACRO)
+}
+
+/// Converts the input string to macro case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`macro_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::macro_case_into("FOO-BAR100-BAZ", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: FOO_BAR100_BAZ");
+/// ```
+#[inline(always)]
+pub fn macro_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, MACRO)
+}
+
+/// Converts the input string to macro case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`macro_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::macro_case_to_slice("FOO-BAR100-BAZ", &opts, &mut buf);
+///     assert_eq!(result, Ok("FOO_BAR100_BAZ"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::macro_case_to_slice("FOO-BAR100-BAZ", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 14);
+/// ```
+#[inline(always)]
+pub fn macro_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, MACRO)
+}
+
+/// A wrapper of a string slice which is displayed in macro case.
+///
+/// It converts the string while formatting it in the same way as [`macro_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::MacroCase;
+///
+///     let s = format!("name: {}", MacroCase("FOO-BAR100-BAZ"));
+///     assert_eq!(s, "name: FOO_BAR100_BAZ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MacroCase<'a>(pub &'a str);
+
+impl fmt::Display for MacroCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, MACRO)
+    }
+}
+
+/// Checks whether the input string is in macro case with the specified options.
+///
+/// It returns `true` if converting the input string with [`macro_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_macro_case_with_options("FOO_BAR_100_BAZ", &opts));
+///     assert!(!stringcase::is_macro_case_with_options("FOO_BAR100_BAZ", &opts));
+/// ```
+#[inline(always)]
+pub fn is_macro_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, MACRO)
+}
+
+/// Checks whether the input string is in macro case.
+///
+/// It returns `true` if converting the input string with [`macro_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_macro_case("FOO_BAR100_BAZ"));
+///     assert!(!stringcase::is_macro_case("FOO-BAR100-BAZ"));
+/// ```
+#[inline(always)]
+pub fn is_macro_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, MACRO)
+}
+
 /// Converts the input string to macro case with the specified separator characters.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use macro_case_with_options instead")]
 #[inline(always)]
@@ -52,11 +225,13 @@ pub fn macro_case_with_sep(input: &str, seps: &str) -> String {
         separate_after_non_alphabets: true,
         separators: seps,
         keep: "",
+        ..Default::default()
     };
     upperize::<'_'>(input, &opts)
 }
 
 /// Converts the input string to macro case with the specified characters to be kept.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use macro_case_with_options instead")]
 #[inline(always)]
@@ -66,6 +241,7 @@ pub fn macro_case_with_keep(input: &str, kept: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: kept,
+        ..Default::default()
     };
     upperize::<'_'>(input, &opts)
 }
@@ -74,6 +250,7 @@ pub fn macro_case_with_keep(input: &str, kept: &str) -> String {
 ///
 /// It treats the beginning and the end of a sequence of non-alphabetic characters as a word
 /// boundary.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use macro_case_with_options instead")]
 #[inline(always)]
@@ -83,11 +260,12 @@ pub fn macro_case_with_nums_as_word(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     upperize::<'_'>(input, &opts)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_macro_case {
     use super::*;
 
@@ -164,7 +342,154 @@ mod tests_of_macro_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_macro_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_macro_case() {
+        assert!(matches!(
+            macro_case_cow("FOO_BAR100_BAZ"),
+            Cow::Borrowed("FOO_BAR100_BAZ")
+        ));
+        assert!(matches!(macro_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_macro_case() {
+        let result = macro_case_cow("fooBar100Baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "FOO_BAR100_BAZ");
+
+        let result = macro_case_cow("FOO_BAR100_BAZ_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, macro_case("FOO_BAR100_BAZ_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["FOO_BAR100_BAZ", "fooBar100Baz", "FOO_BAR100_BAZ_", ""].iter() {
+            let result = macro_case_cow_with_options(input, &opts);
+            assert_eq!(result, macro_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_macro_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        macro_case_into("FOO-BAR100-BAZ", &opts, &mut out).unwrap();
+        macro_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "FOO_BAR100_BAZ");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        macro_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, macro_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(MacroCase("FOO-BAR100-BAZ").to_string(), "FOO_BAR100_BAZ");
+        assert_eq!(format!("<{}>", MacroCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_macro_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = macro_case_to_slice("FOO-BAR100-BAZ", &opts, &mut buf);
+        assert_eq!(result, Ok("FOO_BAR100_BAZ"));
+
+        let result = macro_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 14];
+        let result = macro_case_to_slice("FOO-BAR100-BAZ", &opts, &mut buf);
+        assert_eq!(result, Ok("FOO_BAR100_BAZ"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 13];
+        let result = macro_case_to_slice("FOO-BAR100-BAZ", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 14 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = macro_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(macro_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_macro_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_macro_case() {
+        assert!(is_macro_case("FOO_BAR100_BAZ"));
+        assert!(is_macro_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_macro_case() {
+        assert!(!is_macro_case("foo_bar"));
+        assert!(!is_macro_case("FOO-BAR"));
+        assert!(!is_macro_case("Foo_Bar"));
+        assert!(!is_macro_case("_FOO_BAR100_BAZ"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_macro_case_with_options("FOO_BAR_100_BAZ", &opts));
+        assert!(is_macro_case_with_options("", &opts));
+        assert!(!is_macro_case_with_options("FOO_BAR100_BAZ", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_macro_case_with_options {
     use super::*;
 
@@ -178,6 +503,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -190,6 +516,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -202,6 +529,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -214,6 +542,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -226,6 +555,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -238,6 +568,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -250,6 +581,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -262,6 +594,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC_123_456DEF_G_89HI_JKL_MN_12");
@@ -274,6 +607,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC_DEF_GHI_JK_LM_NO");
@@ -286,6 +620,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC_456DEF");
@@ -304,6 +639,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -320,6 +656,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -332,6 +669,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -344,6 +682,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -356,6 +695,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -368,6 +708,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -380,6 +721,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -392,6 +734,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -404,6 +747,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123_456_DEF_G89_HI_JKL_MN12");
@@ -416,6 +760,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC_DEF_GHI_JK_LM_NO");
@@ -428,6 +773,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_ABC456_DEF");
@@ -446,6 +792,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -462,6 +809,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -474,6 +822,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -486,6 +835,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -498,6 +848,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -510,6 +861,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -522,6 +874,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -534,6 +887,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -546,6 +900,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC_123_456_DEF_G_89_HI_JKL_MN_12");
@@ -558,6 +913,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC_DEF_GHI_JK_LM_NO");
@@ -570,6 +926,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_ABC_456_DEF");
@@ -588,6 +945,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -604,6 +962,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -616,6 +975,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -628,6 +988,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -640,6 +1001,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -652,6 +1014,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -664,6 +1027,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -676,6 +1040,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -688,6 +1053,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123_456DEF_G89HI_JKL_MN12");
@@ -700,6 +1066,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC_DEF_GHI_JK_LM_NO");
@@ -712,6 +1079,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -730,6 +1098,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -746,6 +1115,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -758,6 +1128,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -770,6 +1141,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -786,6 +1158,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -802,6 +1175,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -818,6 +1192,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -834,6 +1209,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -850,6 +1226,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC_123_456DEF_G_89HI_JKL_MN_12");
@@ -866,6 +1243,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC_~!_DEF_#_GHI_%_JK_LM_NO_?");
@@ -878,6 +1256,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC_456DEF");
@@ -896,6 +1275,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -912,6 +1292,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -924,6 +1305,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -936,6 +1318,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -952,6 +1335,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -968,6 +1352,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -984,6 +1369,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1000,6 +1386,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1016,6 +1403,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123_456_DEF_G89_HI_JKL_MN12");
@@ -1032,6 +1420,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._ABC~!_DEF#_GHI%_JK_LM_NO_?");
@@ -1044,6 +1433,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_ABC456_DEF");
@@ -1062,6 +1452,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1078,6 +1469,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1090,6 +1482,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1102,6 +1495,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1118,6 +1512,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1134,6 +1529,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1150,6 +1546,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1166,6 +1563,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1182,6 +1580,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC_123_456_DEF_G_89_HI_JKL_MN_12");
@@ -1198,6 +1597,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._ABC_~!_DEF_#_GHI_%_JK_LM_NO_?");
@@ -1210,6 +1610,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_ABC_456_DEF");
@@ -1228,6 +1629,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1244,6 +1646,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1256,6 +1659,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1268,6 +1672,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1284,6 +1689,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1300,6 +1706,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1316,6 +1723,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1332,6 +1740,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1348,6 +1757,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123_456DEF_G89HI_JKL_MN12");
@@ -1364,6 +1774,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC~!_DEF#_GHI%_JK_LM_NO_?");
@@ -1376,6 +1787,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -1394,6 +1806,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1410,6 +1823,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1422,6 +1836,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1434,6 +1849,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC__DEF__GHI");
@@ -1450,6 +1866,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1466,6 +1883,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1482,6 +1900,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1498,6 +1917,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1514,6 +1934,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC_123_456DEF_G_89HI_JKL_MN_12");
@@ -1530,6 +1951,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC_~!_DEF_#_GHI_%_JK_LM_NO_?");
@@ -1542,6 +1964,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC_456DEF");
@@ -1560,6 +1983,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1576,6 +2000,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1588,6 +2013,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1600,6 +2026,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1616,6 +2043,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1632,6 +2060,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1648,6 +2077,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1664,6 +2094,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1680,6 +2111,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123_456_DEF_G89_HI_JKL_MN12");
@@ -1696,6 +2128,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._ABC~!_DEF#_GHI%_JK_LM_NO_?");
@@ -1708,6 +2141,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_ABC456_DEF");
@@ -1726,6 +2160,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1742,6 +2177,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1754,6 +2190,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1766,6 +2203,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1782,6 +2220,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1798,6 +2237,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1814,6 +2254,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1830,6 +2271,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1846,6 +2288,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC_123_456_DEF_G_89_HI_JKL_MN_12");
@@ -1862,6 +2305,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._ABC_~!_DEF_#_GHI_%_JK_LM_NO_?");
@@ -1874,6 +2318,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_ABC_456_DEF");
@@ -1892,6 +2337,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1908,6 +2354,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1920,6 +2367,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC_DEF_GH_IJK");
@@ -1932,6 +2380,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1948,6 +2397,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1964,6 +2414,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1980,6 +2431,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -1996,6 +2448,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC_DEF_GHI");
@@ -2012,6 +2465,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = macro_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123_456DEF_G89HI_JKL_MN12");
@@ -2028,6 +2482,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = macro_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC~!_DEF#_GHI%_JK_LM_NO_?");
@@ -2040,6 +2495,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -2058,6 +2514,7 @@ mod tests_of_macro_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = macro_case_with_options("", &opts);
             assert_eq!(result, "");
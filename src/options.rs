@@ -2,6 +2,8 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
+use crate::case::Case;
+
 /// A struct that represents options for case conversion of strings.
 ///
 /// The `separate_before_non_alphabets` field specifies whether to treat the
@@ -13,9 +15,18 @@
 /// The `keep` field specifies the set of characters not to be treated as word
 /// separators and kept in the result string.
 ///
+/// The `unicode` field specifies whether to detect word boundaries and convert
+/// letter cases with Unicode letter categories instead of ASCII only.
+///
+/// The `acronyms` field specifies the set of words to be treated as acronyms,
+/// and the `uppercase_acronyms` field specifies whether to uppercase these
+/// acronyms in case styles which capitalize words. The `uppercase_acronyms_in`
+/// field limits the case styles in which these acronyms are uppercased.
+///
 /// Alphanumeric characters specified in `separators` and `keep` are ignored.
 /// If both `separators` and `keep` are specified, `separators` takes precedence
 /// and `keep` is ignored.
+#[derive(Debug, Clone, Copy)]
 pub struct Options<'a> {
     /// Specifies whether to treat the beginning of a sequence of non-alphabetic
     /// characters as a word boundary.
@@ -32,6 +43,34 @@ pub struct Options<'a> {
     /// Specifies the set of characters not to be treated as word separators
     /// and kept in the result string. Alphanumeric characters are ignored.
     pub keep: &'a str,
+
+    /// Specifies whether to treat all Unicode uppercase and lowercase letters
+    /// as letters and to convert them with full Unicode case mappings (e.g.
+    /// `ß` to `SS`). If `false`, only ASCII letters are treated as letters and
+    /// other characters are treated like symbols.
+    ///
+    /// This is `false` by default.
+    pub unicode: bool,
+
+    /// Specifies the set of words to be treated as acronyms, like `"HTTP"` or
+    /// `"ID"`. A sequence of uppercase letters which consists only of these
+    /// acronyms is split into each acronym (e.g. `XMLHTTP` into `XML` and
+    /// `HTTP`). Acronyms are compared ignoring case.
+    pub acronyms: &'a [&'a str],
+
+    /// Specifies whether to uppercase the whole of words which are equal to
+    /// one of `acronyms` in case styles which capitalize words, such as
+    /// PascalCase (e.g. `HTTPServerID`). If `false`, acronyms are capitalized
+    /// like other words (e.g. `HttpServerId`).
+    pub uppercase_acronyms: bool,
+
+    /// Specifies the case styles in which acronyms are uppercased if
+    /// `uppercase_acronyms` is `true`, so that each case style can decide whether
+    /// to render acronyms fully uppercased (e.g. `HTTPServerID`) or capitalized
+    /// (e.g. `Http Server Id`). If empty, acronyms are uppercased in all case
+    /// styles which capitalize words, and also by `capitalize`, which has no
+    /// [`Case`].
+    pub uppercase_acronyms_in: &'a [Case],
 }
 
 impl<'a> Options<'a> {
@@ -46,18 +85,60 @@ impl<'a> Options<'a> {
             separate_after_non_alphabets,
             separators,
             keep,
+            ..Default::default()
+        }
+    }
+
+    /// Returns these options with the `unicode` field set.
+    pub fn with_unicode(self, unicode: bool) -> Self {
+        Self { unicode, ..self }
+    }
+
+    /// Returns these options with the `acronyms` field set.
+    pub fn with_acronyms(self, acronyms: &'a [&'a str]) -> Self {
+        Self { acronyms, ..self }
+    }
+
+    /// Returns these options with the `uppercase_acronyms` field set.
+    pub fn with_uppercase_acronyms(self, uppercase_acronyms: bool) -> Self {
+        Self {
+            uppercase_acronyms,
+            ..self
+        }
+    }
+
+    /// Returns these options with the `uppercase_acronyms_in` field set.
+    pub fn with_uppercase_acronyms_in(self, uppercase_acronyms_in: &'a [Case]) -> Self {
+        Self {
+            uppercase_acronyms_in,
+            ..self
         }
     }
+
+    // Returns whether acronyms are uppercased in the case style, where `None` is a style which
+    // is not one of `Case`.
+    pub(crate) fn uppercases_acronyms(&self, case: Option<Case>) -> bool {
+        self.uppercase_acronyms
+            && (self.uppercase_acronyms_in.is_empty()
+                || case.map_or(false, |c| self.uppercase_acronyms_in.contains(&c)))
+    }
 }
 
+// The default options which can be borrowed for the `'static` lifetime.
+pub(crate) const DEFAULT_OPTIONS: Options<'static> = Options {
+    separate_before_non_alphabets: false,
+    separate_after_non_alphabets: true,
+    separators: "",
+    keep: "",
+    unicode: false,
+    acronyms: &[],
+    uppercase_acronyms: false,
+    uppercase_acronyms_in: &[],
+};
+
 impl Default for Options<'_> {
     fn default() -> Self {
-        Self {
-            separate_before_non_alphabets: false,
-            separate_after_non_alphabets: true,
-            separators: "",
-            keep: "",
-        }
+        DEFAULT_OPTIONS
     }
 }
 
@@ -68,19 +149,27 @@ mod tests_of_options {
     #[test]
     fn test_of_new() {
         let opts = Options::new(true, true, "-_", "#@");
-        assert_eq!(opts.separate_before_non_alphabets, true);
-        assert_eq!(opts.separate_after_non_alphabets, true);
+        assert!(opts.separate_before_non_alphabets);
+        assert!(opts.separate_after_non_alphabets);
         assert_eq!(opts.separators, "-_");
         assert_eq!(opts.keep, "#@");
+        assert!(!opts.unicode);
+        assert!(opts.acronyms.is_empty());
+        assert!(!opts.uppercase_acronyms);
+        assert!(opts.uppercase_acronyms_in.is_empty());
     }
 
     #[test]
     fn test_of_default() {
         let opts = Options::default();
-        assert_eq!(opts.separate_before_non_alphabets, false);
-        assert_eq!(opts.separate_after_non_alphabets, true);
+        assert!(!opts.separate_before_non_alphabets);
+        assert!(opts.separate_after_non_alphabets);
         assert_eq!(opts.separators, "");
         assert_eq!(opts.keep, "");
+        assert!(!opts.unicode);
+        assert!(opts.acronyms.is_empty());
+        assert!(!opts.uppercase_acronyms);
+        assert!(opts.uppercase_acronyms_in.is_empty());
     }
 
     #[test]
@@ -90,9 +179,79 @@ mod tests_of_options {
             separators: "-#@",
             ..Default::default()
         };
-        assert_eq!(opts.separate_before_non_alphabets, true);
-        assert_eq!(opts.separate_after_non_alphabets, true);
+        assert!(opts.separate_before_non_alphabets);
+        assert!(opts.separate_after_non_alphabets);
         assert_eq!(opts.separators, "-#@");
         assert_eq!(opts.keep, "");
+        assert!(!opts.unicode);
+        assert!(opts.acronyms.is_empty());
+        assert!(!opts.uppercase_acronyms);
+        assert!(opts.uppercase_acronyms_in.is_empty());
+    }
+
+    #[test]
+    fn test_of_unicode_by_fields() {
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        assert!(!opts.separate_before_non_alphabets);
+        assert!(opts.separate_after_non_alphabets);
+        assert_eq!(opts.separators, "");
+        assert_eq!(opts.keep, "");
+        assert!(opts.unicode);
+    }
+
+    #[test]
+    fn test_of_acronyms_by_fields() {
+        let opts = Options {
+            acronyms: &["HTTP", "ID"],
+            uppercase_acronyms: true,
+            ..Default::default()
+        };
+        assert!(!opts.separate_before_non_alphabets);
+        assert!(opts.separate_after_non_alphabets);
+        assert_eq!(opts.separators, "");
+        assert_eq!(opts.keep, "");
+        assert_eq!(opts.acronyms, &["HTTP", "ID"]);
+        assert!(opts.uppercase_acronyms);
+    }
+
+    #[test]
+    fn test_of_builder() {
+        let opts = Options::new(true, false, "-", "")
+            .with_unicode(true)
+            .with_acronyms(&["HTTP", "ID"])
+            .with_uppercase_acronyms(true)
+            .with_uppercase_acronyms_in(&[Case::Pascal]);
+        assert!(opts.separate_before_non_alphabets);
+        assert!(!opts.separate_after_non_alphabets);
+        assert_eq!(opts.separators, "-");
+        assert_eq!(opts.keep, "");
+        assert!(opts.unicode);
+        assert_eq!(opts.acronyms, &["HTTP", "ID"]);
+        assert!(opts.uppercase_acronyms);
+        assert_eq!(opts.uppercase_acronyms_in, &[Case::Pascal]);
+    }
+
+    #[test]
+    fn test_of_uppercases_acronyms() {
+        let opts = Options::default();
+        assert!(!opts.uppercases_acronyms(None));
+        assert!(!opts.uppercases_acronyms(Some(Case::Pascal)));
+
+        let opts = Options::default().with_uppercase_acronyms(true);
+        assert!(opts.uppercases_acronyms(None));
+        assert!(opts.uppercases_acronyms(Some(Case::Pascal)));
+        assert!(opts.uppercases_acronyms(Some(Case::Title)));
+
+        let opts = opts.with_uppercase_acronyms_in(&[Case::Pascal, Case::Camel]);
+        assert!(!opts.uppercases_acronyms(None));
+        assert!(opts.uppercases_acronyms(Some(Case::Pascal)));
+        assert!(opts.uppercases_acronyms(Some(Case::Camel)));
+        assert!(!opts.uppercases_acronyms(Some(Case::Title)));
+
+        let opts = opts.with_uppercase_acronyms(false);
+        assert!(!opts.uppercases_acronyms(Some(Case::Pascal)));
     }
 }
@@ -2,7 +2,16 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{
+    is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, PASCAL,
+};
+#[cfg(feature = "alloc")]
+use crate::render::{render_cow, render_string};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
 /// Converts the input string to pascal case with the specified options.
 ///
@@ -12,76 +21,14 @@ use crate::options::Options;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let pascal = stringcase::pascal_case_with_options("foo_bar_100_baz", &opts);
 ///     assert_eq!(pascal, "FooBar100Baz");
 /// ```
+#[cfg(feature = "alloc")]
 pub fn pascal_case_with_options(input: &str, opts: &Options) -> String {
-    let mut result = String::with_capacity(input.len());
-    // .len returns byte count but ok in this case!
-
-    #[derive(PartialEq)]
-    enum ChIs {
-        FirstOfStr,
-        NextOfUpper,
-        NextOfContdUpper,
-        NextOfSepMark,
-        NextOfKeptMark,
-        Other,
-    }
-
-    let mut flag = ChIs::FirstOfStr;
-
-    for ch in input.chars() {
-        if ch.is_ascii_uppercase() {
-            if flag == ChIs::NextOfUpper
-                || flag == ChIs::NextOfContdUpper
-                || (!opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(ch.to_ascii_lowercase());
-                flag = ChIs::NextOfContdUpper;
-            } else {
-                result.push(ch);
-                flag = ChIs::NextOfUpper;
-            }
-        } else if ch.is_ascii_lowercase() {
-            if flag == ChIs::NextOfContdUpper {
-                if let Some(prev) = result.pop() {
-                    result.push(prev.to_ascii_uppercase());
-                    result.push(ch);
-                }
-            } else if flag == ChIs::NextOfSepMark
-                || flag == ChIs::FirstOfStr
-                || (opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(ch.to_ascii_uppercase());
-            } else {
-                result.push(ch);
-            }
-            flag = ChIs::Other;
-        } else if ch.is_ascii_digit() {
-            result.push(ch);
-            flag = ChIs::NextOfKeptMark;
-        } else if !opts.separators.is_empty() {
-            if !opts.separators.contains(ch) {
-                result.push(ch);
-                flag = ChIs::NextOfKeptMark;
-            } else if flag != ChIs::FirstOfStr {
-                flag = ChIs::NextOfSepMark;
-            }
-        } else if !opts.keep.is_empty() {
-            if opts.keep.contains(ch) {
-                result.push(ch);
-                flag = ChIs::NextOfKeptMark;
-            } else if flag != ChIs::FirstOfStr {
-                flag = ChIs::NextOfSepMark;
-            }
-        } else if flag != ChIs::FirstOfStr {
-            flag = ChIs::NextOfSepMark;
-        }
-    }
-
-    result
+    render_string(input, opts, PASCAL)
 }
 
 /// Converts the input string to pascal case.
@@ -93,6 +40,7 @@ pub fn pascal_case_with_options(input: &str, opts: &Options) -> String {
 ///     let pascal = stringcase::pascal_case("foo_bar100_baz");
 ///     assert_eq!(pascal, "FooBar100Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn pascal_case(input: &str) -> String {
     let opts = Options {
@@ -100,11 +48,173 @@ pub fn pascal_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     pascal_case_with_options(input, &opts)
 }
 
+/// Converts the input string to pascal case with the specified options, without allocating a new
+/// string if the input string is already in pascal case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`pascal_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       keep: "_",
+///       ..Default::default()
+///     };
+///     let pascal = stringcase::pascal_case_cow_with_options("Foo_Bar100Baz", &opts);
+///     assert!(matches!(pascal, Cow::Borrowed("Foo_Bar100Baz")));
+///
+///     let pascal = stringcase::pascal_case_cow_with_options("foo_bar100_baz", &opts);
+///     assert!(matches!(pascal, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn pascal_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, PASCAL)
+}
+
+/// Converts the input string to pascal case, without allocating a new string if the input string
+/// is already in pascal case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`pascal_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let pascal = stringcase::pascal_case_cow("FooBar100Baz");
+///     assert!(matches!(pascal, Cow::Borrowed("FooBar100Baz")));
+///
+///     let pascal = stringcase::pascal_case_cow("fooBar100Baz");
+///     assert_eq!(pascal, stringcase::pascal_case("fooBar100Baz"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn pascal_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, PASCAL)
+}
+
+/// Converts the input string to pascal case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`pascal_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::pascal_case_into("fooBar100Baz", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: FooBar100Baz");
+/// ```
+#[inline(always)]
+pub fn pascal_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, PASCAL)
+}
+
+/// Converts the input string to pascal case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`pascal_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::pascal_case_to_slice("fooBar100Baz", &opts, &mut buf);
+///     assert_eq!(result, Ok("FooBar100Baz"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::pascal_case_to_slice("fooBar100Baz", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 12);
+/// ```
+#[inline(always)]
+pub fn pascal_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, PASCAL)
+}
+
+/// A wrapper of a string slice which is displayed in pascal case.
+///
+/// It converts the string while formatting it in the same way as [`pascal_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::PascalCase;
+///
+///     let s = format!("name: {}", PascalCase("fooBar100Baz"));
+///     assert_eq!(s, "name: FooBar100Baz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PascalCase<'a>(pub &'a str);
+
+impl fmt::Display for PascalCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, PASCAL)
+    }
+}
+
+/// Checks whether the input string is in pascal case with the specified options.
+///
+/// It returns `true` if converting the input string with [`pascal_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       keep: "_",
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_pascal_case_with_options("Foo_Bar100Baz", &opts));
+///     assert!(!stringcase::is_pascal_case_with_options("foo_bar100_baz", &opts));
+/// ```
+#[inline(always)]
+pub fn is_pascal_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, PASCAL)
+}
+
+/// Checks whether the input string is in pascal case.
+///
+/// It returns `true` if converting the input string with [`pascal_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_pascal_case("FooBar100Baz"));
+///     assert!(!stringcase::is_pascal_case("fooBar100Baz"));
+/// ```
+#[inline(always)]
+pub fn is_pascal_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, PASCAL)
+}
+
 /// Converts the input string to pascal case with the specified separator characters.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use pascal_case_with_options instead")]
 #[inline(always)]
@@ -114,11 +224,13 @@ pub fn pascal_case_with_sep(input: &str, seps: &str) -> String {
         separate_after_non_alphabets: true,
         separators: seps,
         keep: "",
+        ..Default::default()
     };
     pascal_case_with_options(input, &opts)
 }
 
 /// Converts the input string to pascal case with the specified characters to be kept.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use pascal_case_with_options instead")]
 #[inline(always)]
@@ -128,11 +240,12 @@ pub fn pascal_case_with_keep(input: &str, kept: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: kept,
+        ..Default::default()
     };
     pascal_case_with_options(input, &opts)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_pascal_case {
     use super::*;
 
@@ -209,7 +322,153 @@ mod tests_of_pascal_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_pascal_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_pascal_case() {
+        assert!(matches!(
+            pascal_case_cow("FooBar100Baz"),
+            Cow::Borrowed("FooBar100Baz")
+        ));
+        assert!(matches!(pascal_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_pascal_case() {
+        let result = pascal_case_cow("foo_bar100_baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "FooBar100Baz");
+
+        let result = pascal_case_cow("FooBar100Baz_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, pascal_case("FooBar100Baz_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["FooBar100Baz", "foo_bar100_baz", "FooBar100Baz_", ""].iter() {
+            let result = pascal_case_cow_with_options(input, &opts);
+            assert_eq!(result, pascal_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_pascal_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        pascal_case_into("fooBar100Baz", &opts, &mut out).unwrap();
+        pascal_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "FooBar100Baz");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        pascal_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, pascal_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(PascalCase("fooBar100Baz").to_string(), "FooBar100Baz");
+        assert_eq!(format!("<{}>", PascalCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_pascal_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = pascal_case_to_slice("fooBar100Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("FooBar100Baz"));
+
+        let result = pascal_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 12];
+        let result = pascal_case_to_slice("fooBar100Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("FooBar100Baz"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 11];
+        let result = pascal_case_to_slice("fooBar100Baz", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 12 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = pascal_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(pascal_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_pascal_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_pascal_case() {
+        assert!(is_pascal_case("FooBar100Baz"));
+        assert!(is_pascal_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_pascal_case() {
+        assert!(!is_pascal_case("fooBar"));
+        assert!(!is_pascal_case("Foo_Bar"));
+        assert!(!is_pascal_case("FOOBar"));
+        assert!(!is_pascal_case("_FooBar100Baz"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_pascal_case_with_options("FooBar100Baz", &opts));
+        assert!(is_pascal_case_with_options("", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_pascal_case_with_options {
     use super::*;
 
@@ -223,6 +482,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
@@ -236,6 +496,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
@@ -249,6 +510,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc_def_ghi", &opts);
@@ -262,6 +524,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc-def-ghi", &opts);
@@ -275,6 +538,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
@@ -288,6 +552,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
@@ -301,6 +566,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
@@ -314,6 +580,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -327,6 +594,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -340,6 +608,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("123abc456def", &opts);
@@ -359,6 +628,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("", &opts);
@@ -376,6 +646,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
@@ -389,6 +660,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
@@ -402,6 +674,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc_def_ghi", &opts);
@@ -415,6 +688,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc-def-ghi", &opts);
@@ -428,6 +702,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
@@ -441,6 +716,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
@@ -454,6 +730,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
@@ -467,6 +744,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -480,6 +758,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -493,6 +772,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("123abc456def", &opts);
@@ -512,6 +792,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("", &opts);
@@ -529,6 +810,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
@@ -542,6 +824,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
@@ -555,6 +838,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc_def_ghi", &opts);
@@ -568,6 +852,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc-def-ghi", &opts);
@@ -581,6 +866,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
@@ -594,6 +880,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
@@ -607,6 +894,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
@@ -620,6 +908,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -633,6 +922,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -646,6 +936,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("123abc456def", &opts);
@@ -665,6 +956,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("", &opts);
@@ -682,6 +974,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
@@ -695,6 +988,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
@@ -708,6 +1002,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc_def_ghi", &opts);
@@ -721,6 +1016,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc-def-ghi", &opts);
@@ -734,6 +1030,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
@@ -747,6 +1044,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
@@ -760,6 +1058,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
@@ -773,6 +1072,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -786,6 +1086,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -799,6 +1100,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("123abc456def", &opts);
@@ -818,6 +1120,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("", &opts);
@@ -835,6 +1138,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
@@ -848,6 +1152,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
@@ -861,6 +1166,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc_def_ghi", &opts);
@@ -878,6 +1184,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc-def-ghi", &opts);
@@ -895,6 +1202,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
@@ -912,6 +1220,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
@@ -929,6 +1238,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
@@ -946,6 +1256,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
@@ -963,6 +1274,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
@@ -976,6 +1288,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("123abc456def", &opts);
@@ -995,6 +1308,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("", &opts);
@@ -1008,6 +1322,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
 
             let result = pascal_case_with_options("abc123def", &opts);
@@ -1025,6 +1340,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1037,6 +1353,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1049,6 +1366,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1065,6 +1383,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1081,6 +1400,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1097,6 +1417,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1113,6 +1434,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1129,6 +1451,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123456DefG89HiJklMn12");
@@ -1145,6 +1468,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".Abc~!Def#Ghi%JkLmNo?");
@@ -1157,6 +1481,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123Abc456Def");
@@ -1175,6 +1500,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1187,6 +1513,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123Def");
@@ -1203,6 +1530,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1215,6 +1543,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1227,6 +1556,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1243,6 +1573,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1259,6 +1590,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1275,6 +1607,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1291,6 +1624,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1307,6 +1641,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123456DefG89HiJklMn12");
@@ -1323,6 +1658,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".Abc~!Def#Ghi%JkLmNo?");
@@ -1335,6 +1671,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123Abc456Def");
@@ -1353,6 +1690,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1365,6 +1703,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123Def");
@@ -1381,6 +1720,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1393,6 +1733,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1405,6 +1746,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1421,6 +1763,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1437,6 +1780,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1453,6 +1797,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1469,6 +1814,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1485,6 +1831,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123456defG89hiJklMn12");
@@ -1501,6 +1848,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!Def#Ghi%JkLmNo?");
@@ -1513,6 +1861,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1531,6 +1880,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1543,6 +1893,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123def");
@@ -1559,6 +1910,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1571,6 +1923,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1583,6 +1936,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1599,6 +1953,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1615,6 +1970,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1631,6 +1987,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1647,6 +2004,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1663,6 +2021,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123456defG89hiJklMn12");
@@ -1679,6 +2038,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!Def#Ghi%JkLmNo?");
@@ -1691,6 +2051,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1709,6 +2070,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1725,6 +2087,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1737,6 +2100,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1749,6 +2113,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1765,6 +2130,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1781,6 +2147,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1797,6 +2164,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1813,6 +2181,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1829,6 +2198,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123456DefG89HiJklMn12");
@@ -1845,6 +2215,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".Abc~!Def#Ghi%JkLmNo?");
@@ -1857,6 +2228,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123Abc456Def");
@@ -1875,6 +2247,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = pascal_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1891,6 +2264,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1903,6 +2277,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -1915,6 +2290,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1931,6 +2307,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1947,6 +2324,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1963,6 +2341,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1979,6 +2358,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -1995,6 +2375,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123456DefG89HiJklMn12");
@@ -2011,6 +2392,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".Abc~!Def#Ghi%JkLmNo?");
@@ -2023,6 +2405,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123Abc456Def");
@@ -2041,6 +2424,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -2057,6 +2441,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -2069,6 +2454,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "AbcDefGhIjk");
@@ -2081,6 +2467,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -2097,6 +2484,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -2113,6 +2501,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -2129,6 +2518,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -2145,6 +2535,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "AbcDefGhi");
@@ -2161,6 +2552,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123456defG89hiJklMn12");
@@ -2177,6 +2569,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = pascal_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!Def#Ghi%JkLmNo?");
@@ -2189,6 +2582,7 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -2207,9 +2601,94 @@ mod tests_of_pascal_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = pascal_case_with_options("", &opts);
             assert_eq!(result, "");
         }
     }
+
+    mod unicode_letters {
+        use super::*;
+
+        #[test]
+        fn convert_non_ascii_letters_as_separators_by_default() {
+            let opts = Options {
+                unicode: false,
+                ..Default::default()
+            };
+            let result = pascal_case_with_options("straßeÜberweisung", &opts);
+            assert_eq!(result, "StraEBerweisung");
+        }
+
+        #[test]
+        fn convert_non_ascii_letters() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = pascal_case_with_options("straßeÜberweisung", &opts);
+            assert_eq!(result, "StraßeÜberweisung");
+
+            let result = pascal_case_with_options("ÉtatCivil", &opts);
+            assert_eq!(result, "ÉtatCivil");
+
+            let result = pascal_case_with_options("ÉTATCivil", &opts);
+            assert_eq!(result, "ÉtatCivil");
+        }
+
+        #[test]
+        fn convert_with_one_to_many_case_mappings() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = pascal_case_with_options("İSTANBULİçin", &opts);
+            assert_eq!(result, "İstanbulİçin");
+        }
+
+        #[test]
+        fn convert_letters_without_case_and_non_ascii_digits() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = pascal_case_with_options("日本語_fooBar٣baz", &opts);
+            assert_eq!(result, "日本語FooBar٣Baz");
+        }
+    }
+
+    mod acronyms {
+        use super::*;
+
+        #[test]
+        fn convert_with_acronyms_capitalized() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                ..Default::default()
+            };
+            let result = pascal_case_with_options("XMLHTTPRequest", &opts);
+            assert_eq!(result, "XmlHttpRequest");
+
+            let result = pascal_case_with_options("http_server_id", &opts);
+            assert_eq!(result, "HttpServerId");
+        }
+
+        #[test]
+        fn convert_with_acronyms_uppercased() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                uppercase_acronyms: true,
+                ..Default::default()
+            };
+            let result = pascal_case_with_options("XMLHTTPRequest", &opts);
+            assert_eq!(result, "XMLHTTPRequest");
+
+            let result = pascal_case_with_options("http_server_id", &opts);
+            assert_eq!(result, "HTTPServerID");
+
+            let result = pascal_case_with_options("Identity_Idea", &opts);
+            assert_eq!(result, "IdentityIdea");
+        }
+    }
 }
@@ -0,0 +1,488 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+// Rendering of the words of an input string into each case style.
+//
+// All conversions are written by rendering words into a `Sink`, which is a `String` to build the
+// result, a `FmtSink` to write the result into a `fmt::Write`, a `SliceSink` to write the result
+// into a byte buffer, or a `Matcher` to check whether the result is equal to the input without
+// allocating.
+
+use crate::case::Case;
+use crate::options::Options;
+use crate::words::{push_capitalized_word, push_lower_word, push_upper_word, words};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
+
+// A destination of rendered characters.
+pub(crate) trait Sink {
+    fn push(&mut self, ch: char);
+}
+
+#[cfg(feature = "alloc")]
+impl Sink for String {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        String::push(self, ch);
+    }
+}
+
+// A sink which compares pushed characters with a string, from its head.
+pub(crate) struct Matcher<'a> {
+    rest: &'a str,
+    matched: bool,
+}
+
+impl<'a> Matcher<'a> {
+    pub(crate) fn new(s: &'a str) -> Self {
+        Self {
+            rest: s,
+            matched: true,
+        }
+    }
+
+    // Returns `true` if all pushed characters are equal to the whole of the string.
+    pub(crate) fn is_matched(&self) -> bool {
+        self.matched && self.rest.is_empty()
+    }
+}
+
+impl Sink for Matcher<'_> {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        if !self.matched {
+            return;
+        }
+        let mut chars = self.rest.chars();
+        if chars.next() == Some(ch) {
+            self.rest = chars.as_str();
+        } else {
+            self.matched = false;
+        }
+    }
+}
+
+// A sink which writes pushed characters into a `fmt::Write`, and keeps the first error.
+pub(crate) struct FmtSink<'w, W: fmt::Write + ?Sized> {
+    out: &'w mut W,
+    result: fmt::Result,
+}
+
+impl<W: fmt::Write + ?Sized> Sink for FmtSink<'_, W> {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        if self.result.is_ok() {
+            self.result = self.out.write_char(ch);
+        }
+    }
+}
+
+/// An error which is returned when a buffer is too small to hold a converted string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferTooSmall {
+    /// The byte length of the converted string, which is required for the buffer.
+    pub required_len: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer too small: {} bytes are required",
+            self.required_len
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {}
+
+// A sink which encodes pushed characters into a byte buffer as UTF-8, and counts the total byte
+// length even after the buffer is full.
+pub(crate) struct SliceSink<'b> {
+    buf: &'b mut [u8],
+    len: usize,
+}
+
+impl Sink for SliceSink<'_> {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        let end = self.len + ch.len_utf8();
+        if end <= self.buf.len() {
+            ch.encode_utf8(&mut self.buf[self.len..end]);
+        }
+        self.len = end;
+    }
+}
+
+// A sink which only counts the byte length of pushed characters.
+pub(crate) struct LenCounter(usize);
+
+impl Sink for LenCounter {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        self.0 += ch.len_utf8();
+    }
+}
+
+// A sink which only counts pushed characters.
+pub(crate) struct CharCounter(usize);
+
+impl Sink for CharCounter {
+    #[inline(always)]
+    fn push(&mut self, _: char) {
+        self.0 += 1;
+    }
+}
+
+// A sink which passes at most `n` characters to another sink.
+pub(crate) struct Take<'s, S: Sink> {
+    sink: &'s mut S,
+    n: usize,
+}
+
+impl<S: Sink> Sink for Take<'_, S> {
+    #[inline(always)]
+    fn push(&mut self, ch: char) {
+        if self.n > 0 {
+            self.n -= 1;
+            self.sink.push(ch);
+        }
+    }
+}
+
+// How the letters of a word are converted.
+#[derive(Clone, Copy)]
+pub(crate) enum WordCase {
+    Lower,
+    Upper,
+    Capitalized,
+}
+
+// A case style, which consists of the cases of the first word and the other words and the
+// character to join words, and the `Case` if the style is one of them.
+#[derive(Clone, Copy)]
+pub(crate) struct Style {
+    pub(crate) head: WordCase,
+    pub(crate) tail: WordCase,
+    pub(crate) joiner: Option<char>,
+    pub(crate) case: Option<Case>,
+}
+
+impl Style {
+    pub(crate) const fn joined(word_case: WordCase, joiner: char) -> Self {
+        Self {
+            head: word_case,
+            tail: word_case,
+            joiner: Some(joiner),
+            case: None,
+        }
+    }
+
+    const fn of(self, case: Case) -> Self {
+        Self {
+            case: Some(case),
+            ..self
+        }
+    }
+}
+
+pub(crate) const ADA: Style = Style::joined(WordCase::Capitalized, '_').of(Case::Ada);
+pub(crate) const CAMEL: Style = Style {
+    head: WordCase::Lower,
+    tail: WordCase::Capitalized,
+    joiner: None,
+    case: Some(Case::Camel),
+};
+pub(crate) const COBOL: Style = Style::joined(WordCase::Upper, '-').of(Case::Cobol);
+pub(crate) const KEBAB: Style = Style::joined(WordCase::Lower, '-').of(Case::Kebab);
+pub(crate) const MACRO: Style = Style::joined(WordCase::Upper, '_').of(Case::Macro);
+pub(crate) const PASCAL: Style = Style {
+    head: WordCase::Capitalized,
+    tail: WordCase::Capitalized,
+    joiner: None,
+    case: Some(Case::Pascal),
+};
+pub(crate) const SNAKE: Style = Style::joined(WordCase::Lower, '_').of(Case::Snake);
+pub(crate) const TITLE: Style = Style::joined(WordCase::Capitalized, ' ').of(Case::Title);
+pub(crate) const TRAIN: Style = Style::joined(WordCase::Capitalized, '-').of(Case::Train);
+
+pub(crate) fn render<S: Sink>(s: &mut S, input: &str, opts: &Options, style: Style) {
+    let uppercase_acronyms = opts.uppercases_acronyms(style.case);
+    for (i, word) in words(input, opts).enumerate() {
+        let word_case = if i == 0 {
+            style.head
+        } else {
+            if let Some(joiner) = style.joiner {
+                s.push(joiner);
+            }
+            style.tail
+        };
+        match word_case {
+            WordCase::Lower => push_lower_word(s, word.text, opts.unicode),
+            WordCase::Upper => push_upper_word(s, word.text, opts.unicode),
+            WordCase::Capitalized => push_capitalized_word(s, word.text, opts, uppercase_acronyms),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn render_string(input: &str, opts: &Options, style: Style) -> String {
+    let mut result = if style.joiner.is_some() {
+        String::with_capacity(input.len() + input.len() / 2)
+    } else {
+        String::with_capacity(input.len())
+    };
+    // .len returns byte count but ok in this case!
+
+    render(&mut result, input, opts, style);
+    result
+}
+
+pub(crate) fn render_fmt<W: fmt::Write + ?Sized>(
+    out: &mut W,
+    input: &str,
+    opts: &Options,
+    style: Style,
+) -> fmt::Result {
+    let mut sink = FmtSink {
+        out,
+        result: Ok(()),
+    };
+    render(&mut sink, input, opts, style);
+    sink.result
+}
+
+// Writes the rendered string into a formatter, padded with its width, fill, and alignment and
+// truncated with its precision in the same way as `str`, without allocating.
+pub(crate) fn render_display(
+    f: &mut fmt::Formatter<'_>,
+    input: &str,
+    opts: &Options,
+    style: Style,
+) -> fmt::Result {
+    if f.width().is_none() && f.precision().is_none() {
+        return render_fmt(f, input, opts, style);
+    }
+
+    let mut counter = CharCounter(0);
+    render(&mut counter, input, opts, style);
+    let n = f.precision().map_or(counter.0, |p| p.min(counter.0));
+    let pad = f.width().map_or(0, |w| w.saturating_sub(n));
+    let (pre, post) = match f.align() {
+        Some(fmt::Alignment::Right) => (pad, 0),
+        Some(fmt::Alignment::Center) => (pad / 2, pad - pad / 2),
+        _ => (0, pad),
+    };
+    let fill = f.fill();
+
+    for _ in 0..pre {
+        fmt::Write::write_char(f, fill)?;
+    }
+    let mut sink = FmtSink {
+        out: &mut *f,
+        result: Ok(()),
+    };
+    render(&mut Take { sink: &mut sink, n }, input, opts, style);
+    sink.result?;
+    for _ in 0..post {
+        fmt::Write::write_char(f, fill)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn render_slice<'b>(
+    buf: &'b mut [u8],
+    input: &str,
+    opts: &Options,
+    style: Style,
+) -> Result<&'b str, BufferTooSmall> {
+    let mut sink = SliceSink { buf, len: 0 };
+    render(&mut sink, input, opts, style);
+    let SliceSink { buf, len } = sink;
+    if len > buf.len() {
+        return Err(BufferTooSmall { required_len: len });
+    }
+    // The written bytes are always valid UTF-8, because they are encoded from chars.
+    Ok(core::str::from_utf8(&buf[..len]).expect("rendered bytes should be valid UTF-8"))
+}
+
+pub(crate) fn render_len(input: &str, opts: &Options, style: Style) -> usize {
+    let mut counter = LenCounter(0);
+    render(&mut counter, input, opts, style);
+    counter.0
+}
+
+// Checks whether rendering `input` in `style` returns `input` itself, without allocating.
+pub(crate) fn is_rendered(input: &str, opts: &Options, style: Style) -> bool {
+    is_rendered_as(input, input, opts, style)
+}
+
+// Checks whether rendering `input` in `style` returns `expected`, without allocating.
+pub(crate) fn is_rendered_as(input: &str, expected: &str, opts: &Options, style: Style) -> bool {
+    let mut matcher = Matcher::new(expected);
+    render(&mut matcher, input, opts, style);
+    matcher.is_matched()
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn render_cow<'a>(input: &'a str, opts: &Options, style: Style) -> Cow<'a, str> {
+    if is_rendered(input, opts, style) {
+        Cow::Borrowed(input)
+    } else {
+        Cow::Owned(render_string(input, opts, style))
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_render {
+    use super::*;
+
+    #[test]
+    fn test_of_matcher() {
+        let mut m = Matcher::new("foo");
+        assert!(!m.is_matched());
+        m.push('f');
+        m.push('o');
+        assert!(!m.is_matched());
+        m.push('o');
+        assert!(m.is_matched());
+        m.push('o');
+        assert!(!m.is_matched());
+
+        let mut m = Matcher::new("foo");
+        m.push('F');
+        m.push('o');
+        m.push('o');
+        assert!(!m.is_matched());
+
+        let m = Matcher::new("");
+        assert!(m.is_matched());
+    }
+
+    #[test]
+    fn test_of_render_string() {
+        let opts = Options::default();
+        let input = "fooBar100%BAZQux";
+        assert_eq!(render_string(input, &opts, ADA), "Foo_Bar100_Baz_Qux");
+        assert_eq!(render_string(input, &opts, CAMEL), "fooBar100BazQux");
+        assert_eq!(render_string(input, &opts, COBOL), "FOO-BAR100-BAZ-QUX");
+        assert_eq!(render_string(input, &opts, KEBAB), "foo-bar100-baz-qux");
+        assert_eq!(render_string(input, &opts, MACRO), "FOO_BAR100_BAZ_QUX");
+        assert_eq!(render_string(input, &opts, PASCAL), "FooBar100BazQux");
+        assert_eq!(render_string(input, &opts, SNAKE), "foo_bar100_baz_qux");
+        assert_eq!(render_string(input, &opts, TITLE), "Foo Bar100 Baz Qux");
+        assert_eq!(render_string(input, &opts, TRAIN), "Foo-Bar100-Baz-Qux");
+    }
+
+    #[test]
+    fn test_of_render_fmt() {
+        let opts = Options::default();
+        let mut s = String::from("> ");
+        assert!(render_fmt(&mut s, "fooBar100%BAZQux", &opts, TRAIN).is_ok());
+        assert_eq!(s, "> Foo-Bar100-Baz-Qux");
+    }
+
+    #[test]
+    fn test_of_render_fmt_with_error() {
+        struct Limited(usize);
+        impl fmt::Write for Limited {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                if s.len() > self.0 {
+                    return Err(fmt::Error);
+                }
+                self.0 -= s.len();
+                Ok(())
+            }
+        }
+
+        let opts = Options::default();
+        let mut out = Limited(3);
+        assert!(render_fmt(&mut out, "fooBar", &opts, SNAKE).is_err());
+        assert_eq!(out.0, 0);
+    }
+
+    #[test]
+    fn test_of_render_slice() {
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        let mut buf = [0u8; 16];
+        assert_eq!(
+            render_slice(&mut buf, "fooBar", &opts, SNAKE),
+            Ok("foo_bar")
+        );
+        assert_eq!(
+            render_slice(&mut buf, "straße", &opts, MACRO),
+            Ok("STRASSE")
+        );
+        assert_eq!(render_slice(&mut buf, "", &opts, MACRO), Ok(""));
+
+        let mut buf = [0u8; 7];
+        assert_eq!(
+            render_slice(&mut buf, "éclairÉté", &opts, KEBAB),
+            Err(BufferTooSmall { required_len: 13 })
+        );
+
+        // A multibyte character which does not fit in the rest of the buffer is not written.
+        let mut buf = [0u8; 2];
+        assert_eq!(
+            render_slice(&mut buf, "aé", &opts, SNAKE),
+            Err(BufferTooSmall { required_len: 3 })
+        );
+        assert_eq!(buf, [b'a', 0]);
+    }
+
+    #[test]
+    fn test_of_render_len() {
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        assert_eq!(render_len("fooBar", &opts, SNAKE), 7);
+        assert_eq!(render_len("straße", &opts, MACRO), 7);
+        assert_eq!(render_len("", &opts, TITLE), 0);
+    }
+
+    #[test]
+    fn test_of_buffer_too_small() {
+        let err = BufferTooSmall { required_len: 12 };
+        assert_eq!(err.to_string(), "buffer too small: 12 bytes are required");
+    }
+
+    #[test]
+    fn test_of_is_rendered() {
+        let opts = Options::default();
+        assert!(is_rendered("foo_bar100_baz", &opts, SNAKE));
+        assert!(!is_rendered("foo_bar100_baz_", &opts, SNAKE));
+        assert!(!is_rendered("foo_bar100_bAz", &opts, SNAKE));
+        assert!(!is_rendered("foo_bar100_ba", &opts, KEBAB));
+        assert!(is_rendered("", &opts, TITLE));
+    }
+
+    #[test]
+    fn test_of_is_rendered_as() {
+        let opts = Options::default();
+        assert!(is_rendered_as("foo_bar", "fooBar", &opts, CAMEL));
+        assert!(is_rendered_as("fooBar", "FOO-BAR", &opts, COBOL));
+        assert!(!is_rendered_as("foo_bar", "fooBarBaz", &opts, CAMEL));
+        assert!(!is_rendered_as("foo_bar_baz", "fooBar", &opts, CAMEL));
+        assert!(is_rendered_as("", "", &opts, SNAKE));
+    }
+
+    #[test]
+    fn test_of_render_cow() {
+        let opts = Options::default();
+        assert!(matches!(
+            render_cow("foo_bar100_baz", &opts, SNAKE),
+            Cow::Borrowed("foo_bar100_baz")
+        ));
+        let result = render_cow("fooBar100Baz", &opts, SNAKE);
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "foo_bar100_baz");
+    }
+}
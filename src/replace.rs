@@ -0,0 +1,321 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::case::{Case, DETECTION_ORDER};
+use crate::options::Options;
+use crate::render::{is_rendered, render_string};
+use crate::words::{words, Word};
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A struct that represents a replacement made by [`replace_all_variants`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement<'a> {
+    /// The byte range of the replaced text in the input text.
+    pub range: Range<usize>,
+
+    /// The case style in which the replaced text was found.
+    pub case: Case,
+
+    /// The replaced text, which is a case variant of the searched string.
+    pub from: &'a str,
+
+    /// The text which replaced `from`, which is the replacing string in the same case style.
+    pub to: String,
+}
+
+/// Replaces all case variants of a string in a text with the same case variants of another string.
+///
+/// This function splits `from` into words in the same way as [`words`], and searches `text` for
+/// the string which is `from` converted into each case style, like `user_account`,
+/// `userAccount`, `UserAccount`, `USER_ACCOUNT`, `user-account`, and `User Account`.
+/// Each found string is replaced with `to` converted into the same case style.
+///
+/// A found string must consist of whole words of `text`, so `user_account` in
+/// `super_user_account_id` and `UserAccount` in `getUserAccount` are replaced, but
+/// `user_account` in `superuser_account` is not.
+/// If a found string is in multiple case styles, for example a single word like `user` is in
+/// snake case, kebab case, and camel case, the case style is decided by the identifier around
+/// it, like camel case for `user` in `userName`, Pascal case for `User` in `getUserName`, and
+/// macro case for `USER` in `USER_NAME`.
+/// If the identifier around it is not in any case style, the first of them in the same order as
+/// [`detect_case_with_options`](crate::detect_case_with_options) is used.
+///
+/// # Parameters
+///
+/// - `text`: The string slice (`&str`) in which the case variants are replaced.
+/// - `from`: The string slice (`&str`) whose case variants are searched for.
+/// - `to`: The string slice (`&str`) whose case variants replace the found strings.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - A tuple of the replaced text and the list of the replacements made, in the order of their
+///   positions in `text`.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{replace_all_variants, Case, Options};
+///
+/// let opts = Options::default();
+/// let text = "let user_account = UserAccount::new(); // USER_ACCOUNT, user-account";
+/// let (result, replacements) =
+///     replace_all_variants(text, "user account", "customer profile", &opts);
+/// assert_eq!(
+///     result,
+///     "let customer_profile = CustomerProfile::new(); // CUSTOMER_PROFILE, customer-profile"
+/// );
+/// assert_eq!(replacements.len(), 4);
+/// assert_eq!(replacements[1].range, 19..30);
+/// assert_eq!(replacements[1].case, Case::Pascal);
+/// assert_eq!(replacements[1].from, "UserAccount");
+/// assert_eq!(replacements[1].to, "CustomerProfile");
+/// ```
+pub fn replace_all_variants<'a>(
+    text: &'a str,
+    from: &str,
+    to: &str,
+    opts: &Options,
+) -> (String, Vec<Replacement<'a>>) {
+    let n = words(from, opts).count();
+    if n == 0 {
+        return (String::from(text), Vec::new());
+    }
+
+    let variants: Vec<(Case, String)> = DETECTION_ORDER
+        .iter()
+        .map(|case| (*case, render_string(from, opts, case.style())))
+        .collect();
+
+    let found: Vec<Word> = words(text, opts).collect();
+    let mut replacements = Vec::new();
+    let mut i = 0;
+    while i + n <= found.len() {
+        let range = found[i].start..found[i + n - 1].end;
+        let s = &text[range.clone()];
+        let cases: Vec<Case> = variants
+            .iter()
+            .filter(|(_, v)| v == s)
+            .map(|(case, _)| *case)
+            .collect();
+        if cases.is_empty() {
+            i += 1;
+            continue;
+        }
+        let case = if cases.len() == 1 {
+            cases[0]
+        } else {
+            case_in_context(text, &found, i, i + n, &cases, opts)
+        };
+        replacements.push(Replacement {
+            range,
+            case,
+            from: s,
+            to: render_string(to, opts, case.style()),
+        });
+        i += n;
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut last = 0;
+    for r in replacements.iter() {
+        result.push_str(&text[last..r.range.start]);
+        result.push_str(&r.to);
+        last = r.range.end;
+    }
+    result.push_str(&text[last..]);
+    (result, replacements)
+}
+
+// Decides the case style of the words `found[start..end]`, which is in all of `cases`, by the
+// identifier which consists of them and the words joined to them.
+fn case_in_context(
+    text: &str,
+    found: &[Word],
+    start: usize,
+    end: usize,
+    cases: &[Case],
+    opts: &Options,
+) -> Case {
+    // Checks whether two adjacent words are joined into an identifier.
+    let joined = |i: usize| {
+        let gap = &text[found[i - 1].end..found[i].start];
+        gap.is_empty() || matches!(gap, "_" | "-" | " ")
+    };
+    let prev = if start > 0 && joined(start) {
+        start - 1
+    } else {
+        start
+    };
+    let next = if end < found.len() && joined(end) {
+        end + 1
+    } else {
+        end
+    };
+
+    for (first, last) in [(prev, next), (start, next), (prev, end)].iter() {
+        if (*first, *last) == (start, end) {
+            continue;
+        }
+        let identifier = &text[found[*first].start..found[*last - 1].end];
+        for case in DETECTION_ORDER.iter() {
+            if !is_rendered(identifier, opts, case.style()) {
+                continue;
+            }
+            // The words after the head of a camel case identifier are in Pascal case.
+            let case = match case {
+                Case::Camel if *first < start => Case::Pascal,
+                _ => *case,
+            };
+            if cases.contains(&case) {
+                return case;
+            }
+        }
+    }
+    cases[0]
+}
+
+#[cfg(test)]
+mod tests_of_replace_all_variants {
+    use super::*;
+
+    fn replace(text: &str, from: &str, to: &str) -> String {
+        replace_all_variants(text, from, to, &Options::default()).0
+    }
+
+    #[test]
+    fn replace_each_case_variant() {
+        let from = "user account";
+        let to = "customer profile";
+        assert_eq!(replace("user_account", from, to), "customer_profile");
+        assert_eq!(replace("user-account", from, to), "customer-profile");
+        assert_eq!(replace("userAccount", from, to), "customerProfile");
+        assert_eq!(replace("USER_ACCOUNT", from, to), "CUSTOMER_PROFILE");
+        assert_eq!(replace("USER-ACCOUNT", from, to), "CUSTOMER-PROFILE");
+        assert_eq!(replace("UserAccount", from, to), "CustomerProfile");
+        assert_eq!(replace("User_Account", from, to), "Customer_Profile");
+        assert_eq!(replace("User-Account", from, to), "Customer-Profile");
+        assert_eq!(replace("User Account", from, to), "Customer Profile");
+    }
+
+    #[test]
+    fn replace_in_longer_identifiers() {
+        let from = "user_account";
+        let to = "customerProfile";
+        assert_eq!(
+            replace("super_user_account_id", from, to),
+            "super_customer_profile_id"
+        );
+        assert_eq!(
+            replace("getUserAccountId", from, to),
+            "getCustomerProfileId"
+        );
+        assert_eq!(replace("superuser_account", from, to), "superuser_account");
+        assert_eq!(replace("user_accounts", from, to), "user_accounts");
+    }
+
+    #[test]
+    fn not_replace_mixed_case_styles() {
+        let from = "user account";
+        let to = "customer profile";
+        assert_eq!(replace("user_Account", from, to), "user_Account");
+        assert_eq!(replace("user Account", from, to), "user Account");
+        assert_eq!(replace("user\taccount", from, to), "user\taccount");
+    }
+
+    #[test]
+    fn replace_single_words() {
+        let (result, replacements) =
+            replace_all_variants("user User USER", "user", "member id", &Options::default());
+        assert_eq!(result, "member_id MemberId MEMBER_ID");
+        let cases: Vec<Case> = replacements.iter().map(|r| r.case).collect();
+        assert_eq!(cases, [Case::Snake, Case::Pascal, Case::Macro]);
+    }
+
+    #[test]
+    fn replace_single_words_in_identifiers() {
+        let (result, replacements) = replace_all_variants(
+            "userName = 1; USER_NAME; getUser(UserName, user-name)",
+            "user",
+            "customer profile",
+            &Options::default(),
+        );
+        assert_eq!(
+            result,
+            "customerProfileName = 1; CUSTOMER_PROFILE_NAME; \
+             getCustomerProfile(CustomerProfileName, customer-profile-name)"
+        );
+        let cases: Vec<Case> = replacements.iter().map(|r| r.case).collect();
+        assert_eq!(
+            cases,
+            [
+                Case::Camel,
+                Case::Macro,
+                Case::Pascal,
+                Case::Pascal,
+                Case::Kebab
+            ]
+        );
+    }
+
+    #[test]
+    fn list_replacements() {
+        let (result, replacements) =
+            replace_all_variants("fooBar, foo-bar!", "foo bar", "baz", &Options::default());
+        assert_eq!(result, "baz, baz!");
+        assert_eq!(
+            replacements,
+            [
+                Replacement {
+                    range: 0..6,
+                    case: Case::Camel,
+                    from: "fooBar",
+                    to: String::from("baz"),
+                },
+                Replacement {
+                    range: 8..15,
+                    case: Case::Kebab,
+                    from: "foo-bar",
+                    to: String::from("baz"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn replace_nothing() {
+        let opts = Options::default();
+        let (result, replacements) = replace_all_variants("foo_bar", "", "baz", &opts);
+        assert_eq!(result, "foo_bar");
+        assert!(replacements.is_empty());
+
+        let (result, replacements) = replace_all_variants("", "foo", "baz", &opts);
+        assert_eq!(result, "");
+        assert!(replacements.is_empty());
+    }
+
+    #[test]
+    fn replace_with_options() {
+        let acronyms = ["HTTP"];
+        let opts = Options {
+            acronyms: &acronyms,
+            uppercase_acronyms: true,
+            ..Default::default()
+        };
+        let (result, _) =
+            replace_all_variants("HTTPServer http_server", "http server", "api client", &opts);
+        assert_eq!(result, "ApiClient api_client");
+
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        let (result, _) = replace_all_variants("foo_bar_2", "bar 2", "baz 3", &opts);
+        assert_eq!(result, "foo_baz_3");
+    }
+}
@@ -0,0 +1,1304 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+// The examples in this module are not run as doctests, because serde_json and serde_derive are
+// not dev-dependencies of this crate. They are run in serde-tests/tests/doc_test.rs instead.
+
+//! Serde integration which renames struct fields and map keys into a case style at runtime.
+//!
+//! `CaseKeys` wraps a `Serializer` or a `Deserializer` of any data format, and converts the
+//! names of struct fields and the string keys of maps into the `Case` given at runtime, for
+//! example from a configuration.
+//! This is useful when the case style of keys is not known at compile time, which
+//! `#[serde(rename_all = "...")]` requires.
+//!
+//! ```rust,ignore
+//! use serde::{Deserialize, Serialize};
+//! use stringcase::serde::CaseKeys;
+//! use stringcase::Case;
+//!
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Config {
+//!     max_retry_count: u32,
+//!     base_url: String,
+//! }
+//!
+//! fn main() {
+//!     let config = Config { max_retry_count: 3, base_url: "http://x".to_string() };
+//!
+//!     let mut buf = Vec::new();
+//!     let mut ser = serde_json::Serializer::new(&mut buf);
+//!     config.serialize(CaseKeys::new(&mut ser, Case::Camel)).unwrap();
+//!     let json = String::from_utf8(buf).unwrap();
+//!     assert_eq!(json, r#"{"maxRetryCount":3,"baseUrl":"http://x"}"#);
+//!
+//!     let mut de = serde_json::Deserializer::from_str(&json);
+//!     let back = Config::deserialize(CaseKeys::new(&mut de, Case::Camel)).unwrap();
+//!     assert_eq!(back, config);
+//! }
+//! ```
+//!
+//! On deserialization, keys of structs are mapped back to the field names which are converted
+//! to them, and keys of maps are left as they are.
+//!
+//! Since serde requires the names of struct fields to be `&'static str`, converted field names
+//! are allocated once in the process, shared by all threads, and kept until the end of the
+//! program.
+//! The memory used for them is bounded by the number of distinct field names and case styles.
+
+use crate::case::Case;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{is_rendered, is_rendered_as, render_string};
+use crate::words::{words, Word};
+use crate::{CobolCase, SnakeCase};
+
+use serde_crate::de::{self, Deserialize, DeserializeSeed, Deserializer, Visitor};
+use serde_crate::ser::{self, Serialize, Serializer};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+/// A wrapper of a `Serializer` or a `Deserializer` which converts the names of struct fields and
+/// the keys of maps into a case style.
+///
+/// `CaseKeys` also wraps the values passed through serde, like `SerializeStruct` or `MapAccess`,
+/// to convert the keys of nested structs and maps.
+pub struct CaseKeys<'o, T> {
+    inner: T,
+    conv: Conv<'o>,
+    key: bool,
+}
+
+impl<T> CaseKeys<'static, T> {
+    /// Creates a `CaseKeys` which converts keys into the specified case style with the default
+    /// options.
+    pub fn new(inner: T, case: Case) -> Self {
+        Self::with_options(inner, case, &DEFAULT_OPTIONS)
+    }
+}
+
+impl<'o, T> CaseKeys<'o, T> {
+    /// Creates a `CaseKeys` which converts keys into the specified case style with the specified
+    /// options.
+    pub fn with_options(inner: T, case: Case, opts: &'o Options<'o>) -> Self {
+        Conv { case, opts }.wrap(inner)
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns the case style into which keys are converted.
+    pub fn case(&self) -> Case {
+        self.conv.case
+    }
+
+    fn visitor<V>(&self, inner: V) -> CaseVisitor<'o, V> {
+        self.conv.visitor(inner, Mode::Value)
+    }
+}
+
+// The case style and the options to convert keys, which are passed to every wrapper.
+#[derive(Clone, Copy)]
+struct Conv<'o> {
+    case: Case,
+    opts: &'o Options<'o>,
+}
+
+impl<'o> Conv<'o> {
+    fn wrap<T>(self, inner: T) -> CaseKeys<'o, T> {
+        CaseKeys {
+            inner,
+            conv: self,
+            key: false,
+        }
+    }
+
+    fn value<T: ?Sized>(self, value: &T) -> Value<'_, 'o, T> {
+        Value {
+            value,
+            conv: self,
+            key: false,
+        }
+    }
+
+    fn key<T: ?Sized>(self, key: &T) -> Value<'_, 'o, T> {
+        Value {
+            value: key,
+            conv: self,
+            key: true,
+        }
+    }
+
+    fn visitor<V>(self, inner: V, mode: Mode) -> CaseVisitor<'o, V> {
+        CaseVisitor {
+            inner,
+            conv: self,
+            mode,
+        }
+    }
+
+    fn fields<T>(self, inner: T, fields: &'static [&'static str]) -> Fields<'o, T> {
+        Fields {
+            inner,
+            conv: self,
+            fields,
+        }
+    }
+
+    // Returns the converted name of a field.
+    fn rename(self, name: &'static str) -> &'static str {
+        let style = self.case.style();
+        if is_rendered(name, self.opts, style) {
+            return name;
+        }
+        intern(render_string(name, self.opts, style))
+    }
+
+    // Returns the name of the field which is converted into `key`.
+    fn field(self, fields: &'static [&'static str], key: &str) -> Option<&'static str> {
+        let style = self.case.style();
+        fields
+            .iter()
+            .find(|field| is_rendered_as(field, key, self.opts, style))
+            .copied()
+    }
+}
+
+// The names leaked by `intern`, which are shared by all threads so that each name is leaked only
+// once in the process.
+// This is initialized lazily with an atomic pointer, because `Mutex::new` is not `const` in the
+// minimum supported Rust version.
+static NAMES: AtomicPtr<Mutex<BTreeSet<&'static str>>> = AtomicPtr::new(ptr::null_mut());
+
+fn names() -> &'static Mutex<BTreeSet<&'static str>> {
+    let mut names = NAMES.load(Ordering::Acquire);
+    if names.is_null() {
+        let new = Box::into_raw(Box::new(Mutex::new(BTreeSet::new())));
+        names =
+            match NAMES.compare_exchange(ptr::null_mut(), new, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => new,
+                Err(current) => {
+                    // SAFETY: `new` was created by `Box::into_raw` above and has not been shared.
+                    drop(unsafe { Box::from_raw(new) });
+                    current
+                }
+            };
+    }
+    // SAFETY: a non-null pointer in `NAMES` is created by `Box::into_raw` and is never freed.
+    unsafe { &*names }
+}
+
+// Returns a `&'static str` equal to `name`, which is leaked only at the first time in the process.
+fn intern(name: String) -> &'static str {
+    let mut names = names().lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(interned) = names.get(name.as_str()) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.into_boxed_str());
+    names.insert(interned);
+    interned
+}
+
+// A reference to a value, or a key of a map, to be serialized with `CaseKeys`.
+struct Value<'a, 'o, T: ?Sized> {
+    value: &'a T,
+    conv: Conv<'o>,
+    key: bool,
+}
+
+impl<T: Serialize + ?Sized> Serialize for Value<'_, '_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(CaseKeys {
+            inner: serializer,
+            conv: self.conv,
+            key: self.key,
+        })
+    }
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<Self::Ok, Self::Error> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'o, S: Serializer> Serializer for CaseKeys<'o, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = CaseKeys<'o, S::SerializeSeq>;
+    type SerializeTuple = CaseKeys<'o, S::SerializeTuple>;
+    type SerializeTupleStruct = CaseKeys<'o, S::SerializeTupleStruct>;
+    type SerializeTupleVariant = CaseKeys<'o, S::SerializeTupleVariant>;
+    type SerializeMap = CaseKeys<'o, S::SerializeMap>;
+    type SerializeStruct = CaseKeys<'o, S::SerializeStruct>;
+    type SerializeStructVariant = CaseKeys<'o, S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(bool)
+        serialize_i8(i8)
+        serialize_i16(i16)
+        serialize_i32(i32)
+        serialize_i64(i64)
+        serialize_i128(i128)
+        serialize_u8(u8)
+        serialize_u16(u16)
+        serialize_u32(u32)
+        serialize_u64(u64)
+        serialize_u128(u128)
+        serialize_f32(f32)
+        serialize_f64(f64)
+        serialize_char(char)
+        serialize_bytes(&[u8])
+        serialize_unit_struct(&'static str)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        if self.key {
+            let Conv { case, opts } = self.conv;
+            if !is_rendered(v, opts, case.style()) {
+                return self
+                    .inner
+                    .serialize_str(&render_string(v, opts, case.style()));
+            }
+        }
+        self.inner.serialize_str(v)
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        let value = self.conv.value(value);
+        self.inner.serialize_some(&value)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = self.conv.value(value);
+        self.inner.serialize_newtype_struct(name, &value)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        let value = self.conv.value(value);
+        self.inner
+            .serialize_newtype_variant(name, variant_index, variant, &value)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        let seq = self.inner.serialize_seq(len)?;
+        Ok(self.conv.wrap(seq))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        let tuple = self.inner.serialize_tuple(len)?;
+        Ok(self.conv.wrap(tuple))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        let tuple = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(self.conv.wrap(tuple))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        let tuple = self
+            .inner
+            .serialize_tuple_variant(name, variant_index, variant, len)?;
+        Ok(self.conv.wrap(tuple))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        let map = self.inner.serialize_map(len)?;
+        Ok(self.conv.wrap(map))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        let st = self.inner.serialize_struct(name, len)?;
+        Ok(self.conv.wrap(st))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        let st = self
+            .inner
+            .serialize_struct_variant(name, variant_index, variant, len)?;
+        Ok(self.conv.wrap(st))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+impl<S: ser::SerializeSeq> ser::SerializeSeq for CaseKeys<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.conv.value(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S: ser::SerializeTuple> ser::SerializeTuple for CaseKeys<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.conv.value(value);
+        self.inner.serialize_element(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S: ser::SerializeTupleStruct> ser::SerializeTupleStruct for CaseKeys<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.conv.value(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S: ser::SerializeTupleVariant> ser::SerializeTupleVariant for CaseKeys<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.conv.value(value);
+        self.inner.serialize_field(&value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S: ser::SerializeMap> ser::SerializeMap for CaseKeys<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), S::Error> {
+        let key = self.conv.key(key);
+        self.inner.serialize_key(&key)
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), S::Error> {
+        let value = self.conv.value(value);
+        self.inner.serialize_value(&value)
+    }
+
+    fn serialize_entry<K: Serialize + ?Sized, V: Serialize + ?Sized>(
+        &mut self,
+        key: &K,
+        value: &V,
+    ) -> Result<(), S::Error> {
+        let key = self.conv.key(key);
+        let value = self.conv.value(value);
+        self.inner.serialize_entry(&key, &value)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S: ser::SerializeStruct> ser::SerializeStruct for CaseKeys<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        let key = self.conv.rename(key);
+        let value = self.conv.value(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        let key = self.conv.rename(key);
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+impl<S: ser::SerializeStructVariant> ser::SerializeStructVariant for CaseKeys<'_, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), S::Error> {
+        let key = self.conv.rename(key);
+        let value = self.conv.value(value);
+        self.inner.serialize_field(key, &value)
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), S::Error> {
+        let key = self.conv.rename(key);
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<S::Ok, S::Error> {
+        self.inner.end()
+    }
+}
+
+// What a `CaseVisitor` visits.
+#[derive(Clone, Copy)]
+enum Mode {
+    // Any value.
+    Value,
+    // A struct which has the fields.
+    Struct(&'static [&'static str]),
+    // A key of a struct which has the fields.
+    Key(&'static [&'static str]),
+}
+
+// A visitor which wraps the deserializers passed to the inner visitor with `CaseKeys`, and maps
+// keys of structs back to their field names.
+struct CaseVisitor<'o, V> {
+    inner: V,
+    conv: Conv<'o>,
+    mode: Mode,
+}
+
+impl<V> CaseVisitor<'_, V> {
+    // Returns the name of the field which is converted into `key`, if this visits a key.
+    fn field(&self, key: &str) -> Option<&'static str> {
+        match self.mode {
+            Mode::Key(fields) => self.conv.field(fields, key),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty))*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<Self::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CaseVisitor<'_, V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_bool(bool)
+        visit_i8(i8)
+        visit_i16(i16)
+        visit_i32(i32)
+        visit_i64(i64)
+        visit_i128(i128)
+        visit_u8(u8)
+        visit_u16(u16)
+        visit_u32(u32)
+        visit_u64(u64)
+        visit_u128(u128)
+        visit_f32(f32)
+        visit_f64(f64)
+        visit_char(char)
+        visit_bytes(&[u8])
+        visit_borrowed_bytes(&'de [u8])
+        visit_byte_buf(Vec<u8>)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        match self.field(v) {
+            Some(field) => self.inner.visit_str(field),
+            None => self.inner.visit_str(v),
+        }
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        match self.field(v) {
+            Some(field) => self.inner.visit_borrowed_str(field),
+            None => self.inner.visit_borrowed_str(v),
+        }
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        match self.field(&v) {
+            Some(field) => self.inner.visit_str(field),
+            None => self.inner.visit_string(v),
+        }
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let deserializer = self.conv.wrap(deserializer);
+        self.inner.visit_some(deserializer)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        let deserializer = self.conv.wrap(deserializer);
+        self.inner.visit_newtype_struct(deserializer)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<Self::Value, A::Error> {
+        let seq = self.conv.wrap(seq);
+        self.inner.visit_seq(seq)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        match self.mode {
+            Mode::Struct(fields) => {
+                let map = self.conv.fields(map, fields);
+                self.inner.visit_map(map)
+            }
+            _ => {
+                let map = self.conv.wrap(map);
+                self.inner.visit_map(map)
+            }
+        }
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let data = self.conv.wrap(data);
+        self.inner.visit_enum(data)
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*))*) => {
+        $(
+            fn $method<V: Visitor<'de>>(
+                self,
+                $($arg: $ty,)*
+                visitor: V,
+            ) -> Result<V::Value, Self::Error> {
+                let visitor = self.visitor(visitor);
+                self.inner.$method($($arg,)* visitor)
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for CaseKeys<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        deserialize_identifier()
+        deserialize_ignored_any()
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = self.conv.visitor(visitor, Mode::Struct(fields));
+        self.inner.deserialize_struct(name, fields, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for CaseKeys<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        let deserializer = self.conv.wrap(deserializer);
+        self.inner.deserialize(deserializer)
+    }
+}
+
+impl<'de, A: de::SeqAccess<'de>> de::SeqAccess<'de> for CaseKeys<'_, A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        let seed = self.conv.wrap(seed);
+        self.inner.next_element_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for CaseKeys<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let seed = self.conv.wrap(seed);
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, A::Error> {
+        let seed = self.conv.wrap(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, 'o, A: de::EnumAccess<'de>> de::EnumAccess<'de> for CaseKeys<'o, A> {
+    type Error = A::Error;
+    type Variant = CaseKeys<'o, A::Variant>;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<(T::Value, Self::Variant), A::Error> {
+        let (value, variant) = self.inner.variant_seed(seed)?;
+        Ok((value, self.conv.wrap(variant)))
+    }
+}
+
+impl<'de, A: de::VariantAccess<'de>> de::VariantAccess<'de> for CaseKeys<'_, A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, A::Error> {
+        let seed = self.conv.wrap(seed);
+        self.inner.newtype_variant_seed(seed)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, A::Error> {
+        let visitor = self.visitor(visitor);
+        self.inner.tuple_variant(len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        let visitor = self.conv.visitor(visitor, Mode::Struct(fields));
+        self.inner.struct_variant(fields, visitor)
+    }
+}
+
+// A wrapper of a `MapAccess` of a struct, and of the `DeserializeSeed` and the `Deserializer` of
+// its keys, which maps the keys back to the names of the fields.
+struct Fields<'o, T> {
+    inner: T,
+    conv: Conv<'o>,
+    fields: &'static [&'static str],
+}
+
+impl<'o, T> Fields<'o, T> {
+    fn visitor<V>(&self, inner: V) -> CaseVisitor<'o, V> {
+        self.conv.visitor(inner, Mode::Key(self.fields))
+    }
+}
+
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for Fields<'_, A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        let seed = self.conv.fields(seed, self.fields);
+        self.inner.next_key_seed(seed)
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, A::Error> {
+        let seed = self.conv.wrap(seed);
+        self.inner.next_value_seed(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Fields<'_, S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        let deserializer = self.conv.fields(deserializer, self.fields);
+        self.inner.deserialize(deserializer)
+    }
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Fields<'_, D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        deserialize_identifier()
+        deserialize_struct(name: &'static str, fields: &'static [&'static str])
+        deserialize_ignored_any()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+/// A wrapper which deserializes an enum from a variant name in any case style.
+///
+/// An incoming variant name is accepted if it consists of the same words as a variant name of the
+/// enum, ignoring the case of letters and separators, like `in_progress`, `IN-PROGRESS`,
+/// `inProgress`, or `in progress` for a variant `InProgress`.
+/// Only the variant names of the enum itself are matched, and the variants of enums nested in it
+/// are not.
+///
+/// ```rust,ignore
+/// use serde::Deserialize;
+/// use stringcase::serde::AnyCase;
+///
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// enum Status {
+///     InProgress,
+///     Done,
+/// }
+///
+/// fn main() {
+///     for s in [r#""in_progress""#, r#""IN-PROGRESS""#, r#""in progress""#] {
+///         let status: AnyCase<Status> = serde_json::from_str(s).unwrap();
+///         assert_eq!(status.0, Status::InProgress);
+///     }
+/// }
+/// ```
+///
+/// The function [`any_case_variant`] does the same for a field with
+/// `#[serde(deserialize_with = "stringcase::serde::any_case_variant")]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AnyCase<T>(pub T);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for AnyCase<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        any_case_variant(deserializer).map(AnyCase)
+    }
+}
+
+impl<T: Serialize> Serialize for AnyCase<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes an enum from a variant name in any case style.
+///
+/// This function is to be specified with `#[serde(deserialize_with = "...")]`.
+/// See [`AnyCase`] for the variant names which are accepted.
+/// If an incoming variant name does not match any variant, the error lists the variant names.
+///
+/// ```rust,ignore
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, Debug, PartialEq)]
+/// enum Status {
+///     InProgress,
+///     Done,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Task {
+///     #[serde(deserialize_with = "stringcase::serde::any_case_variant")]
+///     status: Status,
+/// }
+///
+/// fn main() {
+///     let task: Task = serde_json::from_str(r#"{"status":"InProgress"}"#).unwrap();
+///     assert_eq!(task.status, Status::InProgress);
+///     let task: Task = serde_json::from_str(r#"{"status":"DONE"}"#).unwrap();
+///     assert_eq!(task.status, Status::Done);
+///
+///     let err = serde_json::from_str::<Task>(r#"{"status":"todo"}"#).err().unwrap();
+///     assert!(err.to_string().starts_with(
+///         "unknown variant `todo`, expected one of `InProgress`, `Done` in any case style"
+///     ));
+/// }
+/// ```
+pub fn any_case_variant<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(AnyCaseEnum {
+        inner: deserializer,
+    })
+}
+
+// A deserializer which passes the variant names of an enum to `Variants`.
+struct AnyCaseEnum<D> {
+    inner: D,
+}
+
+impl<D> AnyCaseEnum<D> {
+    // Values other than enums are deserialized as they are.
+    fn visitor<V>(&self, inner: V) -> V {
+        inner
+    }
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for AnyCaseEnum<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_identifier()
+        deserialize_struct(name: &'static str, fields: &'static [&'static str])
+        deserialize_ignored_any()
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        let visitor = Variants {
+            inner: visitor,
+            variants,
+        };
+        self.inner.deserialize_enum(name, variants, visitor)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+// A wrapper of the `Visitor` and the `EnumAccess` of an enum, and of the `DeserializeSeed` and the
+// `Deserializer` of its variant name, which maps the variant name to a variant in any case style.
+struct Variants<T> {
+    inner: T,
+    variants: &'static [&'static str],
+}
+
+impl<T> Variants<T> {
+    fn wrap<U>(&self, inner: U) -> Variants<U> {
+        Variants {
+            inner,
+            variants: self.variants,
+        }
+    }
+
+    fn visitor<V>(&self, inner: V) -> VariantName<V> {
+        VariantName {
+            inner,
+            variants: self.variants,
+        }
+    }
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for Variants<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let data = self.wrap(data);
+        self.inner.visit_enum(data)
+    }
+}
+
+impl<'de, A: de::EnumAccess<'de>> de::EnumAccess<'de> for Variants<A> {
+    type Error = A::Error;
+    type Variant = A::Variant;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<(T::Value, A::Variant), A::Error> {
+        let seed = self.wrap(seed);
+        self.inner.variant_seed(seed)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for Variants<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        let deserializer = self.wrap(deserializer);
+        self.inner.deserialize(deserializer)
+    }
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for Variants<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any()
+        deserialize_bool()
+        deserialize_i8()
+        deserialize_i16()
+        deserialize_i32()
+        deserialize_i64()
+        deserialize_i128()
+        deserialize_u8()
+        deserialize_u16()
+        deserialize_u32()
+        deserialize_u64()
+        deserialize_u128()
+        deserialize_f32()
+        deserialize_f64()
+        deserialize_char()
+        deserialize_str()
+        deserialize_string()
+        deserialize_bytes()
+        deserialize_byte_buf()
+        deserialize_option()
+        deserialize_unit()
+        deserialize_unit_struct(name: &'static str)
+        deserialize_newtype_struct(name: &'static str)
+        deserialize_seq()
+        deserialize_tuple(len: usize)
+        deserialize_tuple_struct(name: &'static str, len: usize)
+        deserialize_map()
+        deserialize_enum(name: &'static str, variants: &'static [&'static str])
+        deserialize_identifier()
+        deserialize_struct(name: &'static str, fields: &'static [&'static str])
+        deserialize_ignored_any()
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+// A visitor of a variant name, which passes the name of the variant consisting of the same words
+// to the inner visitor.
+struct VariantName<V> {
+    inner: V,
+    variants: &'static [&'static str],
+}
+
+impl<V> VariantName<V> {
+    fn variant<E: de::Error>(&self, name: &str) -> Result<&'static str, E> {
+        if let Some(variant) = self.variants.iter().find(|v| **v == name) {
+            return Ok(variant);
+        }
+        match self
+            .variants
+            .iter()
+            .find(|v| same_words(v, name, &DEFAULT_OPTIONS))
+        {
+            Some(variant) => Ok(variant),
+            None => Err(E::custom(format_args!(
+                "unknown variant `{}`, {}",
+                name,
+                ExpectedVariants(self.variants)
+            ))),
+        }
+    }
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for VariantName<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.expecting(f)
+    }
+
+    forward_visit! {
+        visit_u64(u64)
+        visit_bytes(&[u8])
+        visit_borrowed_bytes(&'de [u8])
+        visit_byte_buf(Vec<u8>)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let variant = self.variant(v)?;
+        self.inner.visit_str(variant)
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        let variant = self.variant(v)?;
+        self.inner.visit_borrowed_str(variant)
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        let variant = self.variant(&v)?;
+        self.inner.visit_str(variant)
+    }
+}
+
+// Checks whether two strings consist of the same words, ignoring the case of letters.
+fn same_words(a: &str, b: &str, opts: &Options) -> bool {
+    fn lower(w: Word<'_>) -> impl Iterator<Item = char> + '_ {
+        w.text.chars().flat_map(char::to_lowercase)
+    }
+    let mut a = words(a, opts);
+    let mut b = words(b, opts);
+    loop {
+        match (a.next(), b.next()) {
+            (Some(x), Some(y)) => {
+                if !lower(x).eq(lower(y)) {
+                    return false;
+                }
+            }
+            (None, None) => return true,
+            _ => return false,
+        }
+    }
+}
+
+// Displays the expected variant names in an error message.
+struct ExpectedVariants(&'static [&'static str]);
+
+impl fmt::Display for ExpectedVariants {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variants = self.0;
+        if variants.is_empty() {
+            return f.write_str("there are no variants");
+        }
+        f.write_str("expected one of ")?;
+        for (i, variant) in variants.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{}`", variant)?;
+        }
+        write!(
+            f,
+            " in any case style, like `{}` or `{}`",
+            SnakeCase(variants[0]),
+            CobolCase(variants[0])
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests_of_serde {
+    use super::*;
+
+    #[test]
+    fn test_of_intern() {
+        let a = intern("fooBar".to_string());
+        let b = intern("fooBar".to_string());
+        assert_eq!(a, "fooBar");
+        assert!(core::ptr::eq(a, b));
+
+        let c = std::thread::spawn(|| intern("fooBar".to_string()))
+            .join()
+            .unwrap();
+        assert!(core::ptr::eq(a, c));
+    }
+
+    #[test]
+    fn test_of_rename() {
+        let opts = Options::default();
+        let conv = Conv {
+            case: Case::Camel,
+            opts: &opts,
+        };
+        assert_eq!(conv.rename("foo_bar"), "fooBar");
+        let name = "fooBar";
+        assert!(core::ptr::eq(conv.rename(name), name));
+    }
+
+    #[test]
+    fn test_of_field() {
+        let opts = Options::default();
+        let conv = Conv {
+            case: Case::Kebab,
+            opts: &opts,
+        };
+        let fields = &["foo_bar", "baz"];
+        assert_eq!(conv.field(fields, "foo-bar"), Some("foo_bar"));
+        assert_eq!(conv.field(fields, "baz"), Some("baz"));
+        assert_eq!(conv.field(fields, "foo_bar"), None);
+        assert_eq!(conv.field(fields, "qux"), None);
+    }
+
+    #[test]
+    fn test_of_same_words() {
+        let opts = Options::default();
+        assert!(same_words("InProgress", "in_progress", &opts));
+        assert!(same_words("InProgress", "IN PROGRESS", &opts));
+        assert!(same_words("", "", &opts));
+        assert!(!same_words("InProgress", "inprogress", &opts));
+        assert!(!same_words("InProgress", "in_progress_now", &opts));
+        assert!(!same_words("InProgress", "in", &opts));
+    }
+
+    #[test]
+    fn test_of_expected_variants() {
+        assert_eq!(
+            ExpectedVariants(&["FooBar", "Baz"]).to_string(),
+            "expected one of `FooBar`, `Baz` in any case style, like `foo_bar` or `FOO-BAR`"
+        );
+        assert_eq!(ExpectedVariants(&[]).to_string(), "there are no variants");
+    }
+}
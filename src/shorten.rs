@@ -0,0 +1,325 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use crate::batch::short_hash;
+use crate::case::Case;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{render_string, Style, WordCase};
+use crate::words::{push_capitalized_word, push_lower_word, push_upper_word, words};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Converts the input string to the specified case style, and shortens the result to at most the
+/// specified byte length.
+///
+/// This function behaves in the same way as [`shorten_with_options`] with the default options.
+///
+/// ```rust
+/// use stringcase::{shorten, Case};
+///
+/// let input = "customerAccountBillingAddressId";
+/// assert_eq!(shorten(input, Case::Snake, 31).unwrap(), "cstmr_accn_blln_addrs_id_e934b1");
+/// assert_eq!(shorten(input, Case::Snake, 24).unwrap(), "cst_acc_bl_add_id_e934b1");
+/// assert_eq!(shorten(input, Case::Snake, 7), None);
+/// ```
+pub fn shorten(input: &str, case: Case, max_len: usize) -> Option<String> {
+    shorten_with_options(input, case, max_len, &DEFAULT_OPTIONS)
+}
+
+/// Converts the input string to the specified case style with the specified options, and shortens
+/// the result to at most the specified byte length.
+///
+/// Databases and other systems limit the byte lengths of names, like 63 bytes in PostgreSQL and
+/// Kubernetes labels or 30 bytes in Oracle, and long names which are simply truncated often
+/// collide.
+/// This function shortens the words produced by the same splitting as the `〜_case_with_options`
+/// functions, from the middle words to the outer words, so that the first and the last words
+/// remain readable as long as possible:
+///
+/// 1. The vowels (`a`, `e`, `i`, `o`, and `u`) except the head of each word are dropped, one word
+///    at a time, until the result fits in `max_len`.
+/// 2. If the result is still too long, the longest word is trimmed by a character from its end,
+///    repeatedly, leaving at least the head of each word.
+/// 3. If the result is still too long, the middle words are dropped.
+///
+/// Whenever the result is shortened, a short hash of the converted string before shortening is
+/// appended as the last word.
+/// The hash is the same on all platforms and in all versions of this crate, so the same input is
+/// always shortened into the same string, and different inputs which would be shortened into the
+/// same words get different hashes in most cases.
+///
+/// The hash is 6 characters long, so a shortened result needs at least the head of the first word,
+/// a joiner, and the hash, like 8 bytes in snake case.
+/// If `max_len` is less than that, this function returns `None` instead of truncating the hash,
+/// because truncated results could collide with the results of other inputs.
+/// If the converted string fits in `max_len`, it is returned as it is.
+///
+/// # Parameters
+///
+/// - `input`: The string slice (`&str`) to be converted.
+/// - `case`: The [`Case`] into which the string is converted.
+/// - `max_len`: The maximum byte length of the result.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - `Some(String)` containing the converted and shortened string.
+/// - `None` if the converted string does not fit in `max_len` and `max_len` cannot hold the head of
+///   the first word, a joiner, and the hash.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{shorten_with_options, Case, Options};
+///
+/// let opts = Options::default();
+/// let input = "order_line_item_discount_reason_code";
+/// let name = shorten_with_options(input, Case::Pascal, 40, &opts);
+/// assert_eq!(name.as_deref(), Some("OrderLineItemDiscountReasonCode"));
+/// let name = shorten_with_options(input, Case::Pascal, 30, &opts);
+/// assert_eq!(name.as_deref(), Some("OrderLnItmDscntRsnCode65a843"));
+/// let name = shorten_with_options(input, Case::Macro, 20, &opts);
+/// assert_eq!(name.as_deref(), Some("OR_L_I_D_R_CD_60AD5D"));
+/// let name = shorten_with_options(input, Case::Macro, 7, &opts);
+/// assert_eq!(name, None);
+/// ```
+pub fn shorten_with_options(
+    input: &str,
+    case: Case,
+    max_len: usize,
+    opts: &Options,
+) -> Option<String> {
+    let style = case.style();
+    let full = render_string(input, opts, style);
+    if full.len() <= max_len {
+        return Some(full);
+    }
+
+    let mut ws: Vec<String> = words(input, opts).map(|w| w.text.to_string()).collect();
+    let order = middle_out(ws.len());
+
+    let hash = short_hash(&full);
+    for i in order.iter() {
+        drop_vowels(&mut ws[*i]);
+        let s = join(&ws, Some(&hash), style, opts);
+        if s.len() <= max_len {
+            return Some(s);
+        }
+    }
+
+    loop {
+        let mut longest: Option<(usize, usize)> = None;
+        for i in order.iter() {
+            let n = ws[*i].chars().count();
+            if n > 1 && longest.map_or(true, |(_, m)| n > m) {
+                longest = Some((*i, n));
+            }
+        }
+        let i = match longest {
+            Some((i, _)) => i,
+            None => break,
+        };
+        ws[i].pop();
+        let s = join(&ws, Some(&hash), style, opts);
+        if s.len() <= max_len {
+            return Some(s);
+        }
+    }
+
+    while ws.len() > 1 {
+        ws.remove(ws.len() / 2);
+        let s = join(&ws, Some(&hash), style, opts);
+        if s.len() <= max_len {
+            return Some(s);
+        }
+    }
+
+    None
+}
+
+// Returns the indices of `n` words in the order from the middle to the outside. Of two words at
+// the same distance from the middle, the former comes first.
+fn middle_out(n: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|i| (2 * i + 1).max(n) - (2 * i + 1).min(n));
+    order
+}
+
+// Drops ASCII vowels except the head of a word.
+fn drop_vowels(word: &mut String) {
+    let mut chars = word.chars();
+    let mut result = String::with_capacity(word.len());
+    if let Some(ch) = chars.next() {
+        result.push(ch);
+    }
+    result
+        .extend(chars.filter(|ch| !matches!(ch.to_ascii_lowercase(), 'a' | 'e' | 'i' | 'o' | 'u')));
+    *word = result;
+}
+
+// Joins words, and a hash as the last word if any, in a case style.
+fn join(ws: &[String], hash: Option<&str>, style: Style, opts: &Options) -> String {
+    let uppercase_acronyms = opts.uppercases_acronyms(style.case);
+    let mut s = String::new();
+    for (i, word) in ws.iter().map(String::as_str).chain(hash).enumerate() {
+        let word_case = if i == 0 {
+            style.head
+        } else {
+            if let Some(joiner) = style.joiner {
+                s.push(joiner);
+            }
+            style.tail
+        };
+        match word_case {
+            WordCase::Lower => push_lower_word(&mut s, word, opts.unicode),
+            WordCase::Upper => push_upper_word(&mut s, word, opts.unicode),
+            WordCase::Capitalized => push_capitalized_word(&mut s, word, opts, uppercase_acronyms),
+        }
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests_of_shorten {
+    use super::*;
+
+    #[test]
+    fn not_shorten_if_fits() {
+        assert_eq!(
+            shorten("fooBarBaz", Case::Snake, 11).unwrap(),
+            "foo_bar_baz"
+        );
+        assert_eq!(
+            shorten("fooBarBaz", Case::Snake, 100).unwrap(),
+            "foo_bar_baz"
+        );
+        assert_eq!(shorten("", Case::Snake, 0).unwrap(), "");
+    }
+
+    #[test]
+    fn drop_vowels_from_middle_words_with_hash() {
+        let input = "customer_account_billing_address_identifier";
+        assert_eq!(short_hash(input), "9dc543");
+        assert_eq!(shorten(input, Case::Snake, 43).unwrap(), input);
+        assert_eq!(
+            shorten(input, Case::Snake, 42).unwrap(),
+            "cstmr_accnt_bllng_addrss_identifier_9dc543"
+        );
+        assert_eq!(
+            shorten(input, Case::Snake, 40).unwrap(),
+            "cstmr_accnt_bllng_addrss_idntfr_9dc543"
+        );
+    }
+
+    #[test]
+    fn append_hash_to_avoid_collisions_of_dropped_vowels() {
+        assert_eq!(
+            shorten("user_bat_name", Case::Snake, 12).unwrap(),
+            "u_b_n_0328f9"
+        );
+        assert_eq!(
+            shorten("user_bet_name", Case::Snake, 12).unwrap(),
+            "u_b_n_9c1474"
+        );
+        assert_ne!(
+            shorten("order_status_code", Case::Snake, 15).unwrap(),
+            shorten("order_stats_code", Case::Snake, 15).unwrap()
+        );
+    }
+
+    #[test]
+    fn trim_words_with_hash() {
+        let input = "customer_account_billing_address_id";
+        let hash = short_hash(input);
+        assert_eq!(hash, "e934b1");
+        assert_eq!(
+            shorten(input, Case::Snake, 26).unwrap(),
+            "cstm_acc_bll_add_id_e934b1"
+        );
+        assert_eq!(
+            shorten(input, Case::Snake, 24).unwrap(),
+            "cst_acc_bl_add_id_e934b1"
+        );
+        assert_eq!(
+            shorten(input, Case::Snake, 20).unwrap(),
+            "cs_ac_b_ad_id_e934b1"
+        );
+
+        assert_ne!(
+            shorten("customer_account_billing_address_id", Case::Snake, 20).unwrap(),
+            shorten("customer_account_billing_addresses_id", Case::Snake, 20).unwrap()
+        );
+    }
+
+    #[test]
+    fn drop_middle_words() {
+        let input = "a_b_c_d_e_f_g";
+        assert_eq!(short_hash(input), "a3d27b");
+        assert_eq!(shorten(input, Case::Snake, 13).unwrap(), input);
+        assert_eq!(shorten(input, Case::Snake, 12).unwrap(), "a_b_g_a3d27b");
+        assert_eq!(shorten(input, Case::Snake, 8).unwrap(), "a_a3d27b");
+    }
+
+    #[test]
+    fn fail_if_max_len_cannot_hold_hash() {
+        let input = "a_b_c_d_e_f_g";
+        assert_eq!(shorten(input, Case::Snake, 7), None);
+        assert_eq!(shorten(input, Case::Snake, 5), None);
+        assert_eq!(shorten(input, Case::Snake, 2), None);
+        assert_eq!(shorten(input, Case::Snake, 0), None);
+        assert_eq!(shorten("a_b_c_d", Case::Title, 2), None);
+        assert_eq!(shorten("aB", Case::Camel, 1), None);
+    }
+
+    #[test]
+    fn shorten_in_each_case_style() {
+        let input = "customer_account_billing_address_id";
+        assert_eq!(
+            shorten(input, Case::Camel, 24).unwrap(),
+            "cstmAccnBllnAddrId9a1359"
+        );
+        assert_eq!(
+            shorten(input, Case::Pascal, 24).unwrap(),
+            "CstmAccnBllnAddrId2d3586"
+        );
+        assert_eq!(
+            shorten(input, Case::Kebab, 28).unwrap(),
+            "cstm-accn-bll-addr-id-9d2f60"
+        );
+        assert_eq!(
+            shorten(input, Case::Title, 28).unwrap(),
+            "Cstm Accn Bll Addr Id 1c9e26"
+        );
+
+        let hash = short_hash("CUSTOMER_ACCOUNT_BILLING_ADDRESS_ID").to_uppercase();
+        assert_eq!(
+            shorten(input, Case::Macro, 24).unwrap(),
+            alloc::format!("CST_ACC_BL_ADD_ID_{}", hash)
+        );
+    }
+
+    #[test]
+    fn shorten_with_options() {
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        let name = super::shorten_with_options("überlange_bezeichnung_äöü", Case::Snake, 10, &opts)
+            .unwrap();
+        assert!(name.len() <= 10);
+        assert!(name.starts_with('ü'));
+    }
+
+    #[test]
+    fn test_of_middle_out() {
+        assert_eq!(middle_out(0), []);
+        assert_eq!(middle_out(1), [0]);
+        assert_eq!(middle_out(2), [0, 1]);
+        assert_eq!(middle_out(3), [1, 0, 2]);
+        assert_eq!(middle_out(4), [1, 2, 0, 3]);
+        assert_eq!(middle_out(5), [2, 1, 3, 0, 4]);
+    }
+}
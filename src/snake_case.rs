@@ -2,8 +2,16 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
+#[cfg(feature = "alloc")]
 use crate::lowerize::lowerize;
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+#[cfg(feature = "alloc")]
+use crate::render::render_cow;
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, SNAKE};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
 /// Converts the input string to snake case with the specified options.
 ///
@@ -13,10 +21,12 @@ use crate::options::Options;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let snake = stringcase::snake_case_with_options("fooBar123Baz", &opts);
 ///     assert_eq!(snake, "foo_bar_123_baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn snake_case_with_options(input: &str, opts: &Options) -> String {
     lowerize::<'_'>(input, opts)
@@ -31,6 +41,7 @@ pub fn snake_case_with_options(input: &str, opts: &Options) -> String {
 ///     let snake = stringcase::snake_case("fooBar123Baz");
 ///     assert_eq!(snake, "foo_bar123_baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn snake_case(input: &str) -> String {
     let opts = Options {
@@ -38,11 +49,173 @@ pub fn snake_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     lowerize::<'_'>(input, &opts)
 }
 
+/// Converts the input string to snake case with the specified options, without allocating a new
+/// string if the input string is already in snake case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`snake_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     let snake = stringcase::snake_case_cow_with_options("foo_bar_100_baz", &opts);
+///     assert!(matches!(snake, Cow::Borrowed("foo_bar_100_baz")));
+///
+///     let snake = stringcase::snake_case_cow_with_options("foo_bar100_baz", &opts);
+///     assert!(matches!(snake, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn snake_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, SNAKE)
+}
+
+/// Converts the input string to snake case, without allocating a new string if the input string
+/// is already in snake case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`snake_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let snake = stringcase::snake_case_cow("foo_bar100_baz");
+///     assert!(matches!(snake, Cow::Borrowed("foo_bar100_baz")));
+///
+///     let snake = stringcase::snake_case_cow("fooBar100Baz");
+///     assert_eq!(snake, stringcase::snake_case("fooBar100Baz"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn snake_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, SNAKE)
+}
+
+/// Converts the input string to snake case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`snake_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::snake_case_into("fooBar100Baz", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: foo_bar100_baz");
+/// ```
+#[inline(always)]
+pub fn snake_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, SNAKE)
+}
+
+/// Converts the input string to snake case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`snake_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::snake_case_to_slice("fooBar100Baz", &opts, &mut buf);
+///     assert_eq!(result, Ok("foo_bar100_baz"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::snake_case_to_slice("fooBar100Baz", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 14);
+/// ```
+#[inline(always)]
+pub fn snake_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, SNAKE)
+}
+
+/// A wrapper of a string slice which is displayed in snake case.
+///
+/// It converts the string while formatting it in the same way as [`snake_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::SnakeCase;
+///
+///     let s = format!("name: {}", SnakeCase("fooBar100Baz"));
+///     assert_eq!(s, "name: foo_bar100_baz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnakeCase<'a>(pub &'a str);
+
+impl fmt::Display for SnakeCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, SNAKE)
+    }
+}
+
+/// Checks whether the input string is in snake case with the specified options.
+///
+/// It returns `true` if converting the input string with [`snake_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_snake_case_with_options("foo_bar_100_baz", &opts));
+///     assert!(!stringcase::is_snake_case_with_options("foo_bar100_baz", &opts));
+/// ```
+#[inline(always)]
+pub fn is_snake_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, SNAKE)
+}
+
+/// Checks whether the input string is in snake case.
+///
+/// It returns `true` if converting the input string with [`snake_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_snake_case("foo_bar100_baz"));
+///     assert!(!stringcase::is_snake_case("fooBar100Baz"));
+/// ```
+#[inline(always)]
+pub fn is_snake_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, SNAKE)
+}
+
 /// Converts the input string to snake case with the specified separator characters.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use snake_case_with_options instead")]
 #[inline(always)]
@@ -52,11 +225,13 @@ pub fn snake_case_with_sep(input: &str, seps: &str) -> String {
         separate_after_non_alphabets: true,
         separators: seps,
         keep: "",
+        ..Default::default()
     };
     lowerize::<'_'>(input, &opts)
 }
 
 /// Converts the input string to snake case with the specified characters to be kept.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use snake_case_with_options instead")]
 #[inline(always)]
@@ -66,6 +241,7 @@ pub fn snake_case_with_keep(input: &str, kept: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: kept,
+        ..Default::default()
     };
     lowerize::<'_'>(input, &opts)
 }
@@ -74,6 +250,7 @@ pub fn snake_case_with_keep(input: &str, kept: &str) -> String {
 ///
 /// It treats the beginning and the end of a sequence of non-alphabetic characters as a word
 /// boundary.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use snake_case_with_options instead")]
 #[inline(always)]
@@ -83,11 +260,12 @@ pub fn snake_case_with_nums_as_word(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
     lowerize::<'_'>(input, &opts)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_snake_case {
     use super::*;
 
@@ -164,7 +342,154 @@ mod tests_of_snake_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_snake_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_snake_case() {
+        assert!(matches!(
+            snake_case_cow("foo_bar100_baz"),
+            Cow::Borrowed("foo_bar100_baz")
+        ));
+        assert!(matches!(snake_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_snake_case() {
+        let result = snake_case_cow("fooBar100Baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "foo_bar100_baz");
+
+        let result = snake_case_cow("foo_bar100_baz_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, snake_case("foo_bar100_baz_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["foo_bar100_baz", "fooBar100Baz", "foo_bar100_baz_", ""].iter() {
+            let result = snake_case_cow_with_options(input, &opts);
+            assert_eq!(result, snake_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_snake_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        snake_case_into("fooBar100Baz", &opts, &mut out).unwrap();
+        snake_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "foo_bar100_baz");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        snake_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, snake_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(SnakeCase("fooBar100Baz").to_string(), "foo_bar100_baz");
+        assert_eq!(format!("<{}>", SnakeCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_snake_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = snake_case_to_slice("fooBar100Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("foo_bar100_baz"));
+
+        let result = snake_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 14];
+        let result = snake_case_to_slice("fooBar100Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("foo_bar100_baz"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 13];
+        let result = snake_case_to_slice("fooBar100Baz", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 14 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = snake_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(snake_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_snake_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_snake_case() {
+        assert!(is_snake_case("foo_bar100_baz"));
+        assert!(is_snake_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_snake_case() {
+        assert!(!is_snake_case("foo-bar"));
+        assert!(!is_snake_case("fooBar"));
+        assert!(!is_snake_case("Foo_Bar"));
+        assert!(!is_snake_case("_foo_bar100_baz"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_snake_case_with_options("foo_bar_100_baz", &opts));
+        assert!(is_snake_case_with_options("", &opts));
+        assert!(!is_snake_case_with_options("foo_bar100_baz", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_cobol_case_with_options {
     use super::*;
 
@@ -178,6 +503,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -190,6 +516,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -202,6 +529,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -214,6 +542,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -226,6 +555,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -238,6 +568,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -250,6 +581,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -262,6 +594,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc_123_456def_g_89hi_jkl_mn_12");
@@ -274,6 +607,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "abc_def_ghi_jk_lm_no");
@@ -286,6 +620,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc_456def");
@@ -304,6 +639,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -320,6 +656,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -332,6 +669,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -344,6 +682,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -356,6 +695,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -368,6 +708,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -380,6 +721,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -392,6 +734,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -404,6 +747,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123_456_def_g89_hi_jkl_mn12");
@@ -416,6 +760,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "abc_def_ghi_jk_lm_no");
@@ -428,6 +773,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_abc456_def");
@@ -446,6 +792,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -462,6 +809,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -474,6 +822,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -486,6 +835,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -498,6 +848,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -510,6 +861,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -522,6 +874,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -534,6 +887,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -546,6 +900,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc_123_456_def_g_89_hi_jkl_mn_12");
@@ -558,6 +913,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "abc_def_ghi_jk_lm_no");
@@ -570,6 +926,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_abc_456_def");
@@ -588,6 +945,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -604,6 +962,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -616,6 +975,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -628,6 +988,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -640,6 +1001,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -652,6 +1014,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -664,6 +1027,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -676,6 +1040,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -688,6 +1053,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123_456def_g89hi_jkl_mn12");
@@ -700,6 +1066,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "abc_def_ghi_jk_lm_no");
@@ -712,6 +1079,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -730,6 +1098,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -746,6 +1115,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -758,6 +1128,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -770,6 +1141,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -786,6 +1158,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -802,6 +1175,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -818,6 +1192,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -834,6 +1209,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -850,6 +1226,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc_123_456def_g_89hi_jkl_mn_12");
@@ -866,6 +1243,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc_~!_def_#_ghi_%_jk_lm_no_?");
@@ -878,6 +1256,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc_456def");
@@ -893,6 +1272,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -905,6 +1285,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123def", &opts);
             assert_eq!(result, "abc_123def");
@@ -921,6 +1302,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -933,6 +1315,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -945,6 +1328,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -961,6 +1345,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -977,6 +1362,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -993,6 +1379,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1009,6 +1396,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1025,6 +1413,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123_456_def_g89_hi_jkl_mn12");
@@ -1041,6 +1430,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._abc~!_def#_ghi%_jk_lm_no_?");
@@ -1053,6 +1443,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_abc456_def");
@@ -1068,6 +1459,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1080,6 +1472,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123def", &opts);
             assert_eq!(result, "abc123_def");
@@ -1096,6 +1489,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1108,6 +1502,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1120,6 +1515,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1136,6 +1532,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1152,6 +1549,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1168,6 +1566,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1184,6 +1583,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1200,6 +1600,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc_123_456_def_g_89_hi_jkl_mn_12");
@@ -1216,6 +1617,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._abc_~!_def_#_ghi_%_jk_lm_no_?");
@@ -1228,6 +1630,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_abc_456_def");
@@ -1243,6 +1646,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1255,6 +1659,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123def", &opts);
             assert_eq!(result, "abc_123_def");
@@ -1271,6 +1676,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1283,6 +1689,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1295,6 +1702,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1311,6 +1719,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1327,6 +1736,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1343,6 +1753,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1359,6 +1770,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1375,6 +1787,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123_456def_g89hi_jkl_mn12");
@@ -1391,6 +1804,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!_def#_ghi%_jk_lm_no_?");
@@ -1403,6 +1817,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1418,6 +1833,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1430,6 +1846,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123def", &opts);
             assert_eq!(result, "abc123def");
@@ -1446,6 +1863,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1458,6 +1876,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1470,6 +1889,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1486,6 +1906,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1502,6 +1923,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1518,6 +1940,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1534,6 +1957,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1550,6 +1974,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc_123_456def_g_89hi_jkl_mn_12");
@@ -1566,6 +1991,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc_456def");
@@ -1581,6 +2007,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc_~!_def_#_ghi_%_jk_lm_no_?");
@@ -1593,6 +2020,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1609,6 +2037,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1621,6 +2050,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1633,6 +2063,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1649,6 +2080,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1665,6 +2097,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1681,6 +2114,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1697,6 +2131,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1713,6 +2148,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123_456_def_g89_hi_jkl_mn12");
@@ -1729,6 +2165,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_abc456_def");
@@ -1745,6 +2182,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._abc~!_def#_ghi%_jk_lm_no_?");
@@ -1757,6 +2195,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1772,6 +2211,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1784,6 +2224,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1796,6 +2237,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1812,6 +2254,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1828,6 +2271,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1844,6 +2288,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1860,6 +2305,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1876,6 +2322,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc_123_456_def_g_89_hi_jkl_mn_12");
@@ -1892,6 +2339,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123_abc_456_def");
@@ -1907,6 +2355,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "._abc_~!_def_#_ghi_%_jk_lm_no_?");
@@ -1919,6 +2368,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1935,6 +2385,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1947,6 +2398,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "abc_def_gh_ijk");
@@ -1959,6 +2411,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1975,6 +2428,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -1991,6 +2445,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -2007,6 +2462,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -2023,6 +2479,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "abc_def_ghi");
@@ -2039,6 +2496,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = snake_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "abc123_456def_g89hi_jkl_mn12");
@@ -2055,6 +2513,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = snake_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -2070,6 +2529,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = snake_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!_def#_ghi%_jk_lm_no_?");
@@ -2082,6 +2542,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = snake_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -0,0 +1,284 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+//! Conversions of the identifiers of `proc_macro2` and `syn` into case styles.
+//!
+//! Converting an identifier with `snake_case(&ident.to_string())` loses its span, and breaks on
+//! raw identifiers like `r#type`, because the prefix `r#` is treated as a part of the name.
+//! The functions of this module and `IdentCaser` remove the prefix `r#` of an identifier before
+//! conversion, escape the result as a raw identifier if it is a Rust keyword, and keep the span
+//! of the original identifier for error messages.
+//!
+//! ```rust
+//! use proc_macro2::{Ident, Span};
+//! use stringcase::syn::IdentCaser;
+//!
+//! fn main() {
+//!     let ident = Ident::new("UserId", Span::call_site());
+//!     assert_eq!(ident.to_snake_case_ident().unwrap(), "user_id");
+//!
+//!     let ident = Ident::new_raw("type", Span::call_site());
+//!     assert_eq!(ident.to_pascal_case_ident().unwrap(), "Type");
+//!
+//!     let ident = Ident::new("Type", Span::call_site());
+//!     assert_eq!(ident.to_snake_case_ident().unwrap(), "r#type");
+//! }
+//! ```
+
+use crate::case::Case;
+use crate::keywords;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::render_string;
+
+use proc_macro2::{Ident, Span};
+use std::fmt;
+use syn_crate::ext::IdentExt;
+use syn_crate::parse::Parser;
+
+/// An error which is returned when a converted name cannot be an identifier.
+#[derive(Debug, Clone)]
+pub struct IdentError {
+    name: String,
+    span: Span,
+    kind: IdentErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IdentErrorKind {
+    Invalid,
+    NonRawKeyword,
+}
+
+impl IdentError {
+    /// Returns the name which cannot be an identifier.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the span of the original identifier.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl fmt::Display for IdentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            IdentErrorKind::Invalid => write!(f, "`{}` is not a valid identifier", self.name),
+            IdentErrorKind::NonRawKeyword => {
+                write!(f, "`{}` cannot be a raw identifier", self.name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IdentError {}
+
+impl From<IdentError> for syn_crate::Error {
+    fn from(err: IdentError) -> Self {
+        syn_crate::Error::new(err.span, &err)
+    }
+}
+
+/// Checks whether a string is a keyword of Rust which is escaped as a raw identifier.
+///
+/// The keywords `crate`, `self`, `Self`, and `super`, which cannot be raw identifiers, are not
+/// contained.
+pub fn is_keyword(name: &str) -> bool {
+    keywords::RUST.contains(&name) && !keywords::RUST_NON_RAW.contains(&name)
+}
+
+/// Returns the name of an identifier without the prefix `r#` of a raw identifier.
+pub fn ident_name(ident: &Ident) -> String {
+    ident.unraw().to_string()
+}
+
+/// Creates an identifier with the specified name and span, which is a raw identifier if the name is
+/// a keyword.
+///
+/// If the name is not a valid identifier, or is a keyword which cannot be a raw identifier, this
+/// function returns an error.
+pub fn new_ident(name: &str, span: Span) -> Result<Ident, IdentError> {
+    let error = |kind| IdentError {
+        name: name.to_string(),
+        span,
+        kind,
+    };
+    if keywords::RUST_NON_RAW.contains(&name) {
+        return Err(error(IdentErrorKind::NonRawKeyword));
+    }
+    if Parser::parse_str(Ident::parse_any, name).is_err() {
+        return Err(error(IdentErrorKind::Invalid));
+    }
+    if is_keyword(name) {
+        Ok(Ident::new_raw(name, span))
+    } else {
+        Ok(Ident::new(name, span))
+    }
+}
+
+/// Converts an identifier into the specified case style with the specified options.
+///
+/// The prefix `r#` of a raw identifier is removed before conversion, and the result is escaped as
+/// a raw identifier if it is a keyword.
+/// The result has the span of the original identifier.
+///
+/// Since a name in COBOL, kebab, title, or train case is not an identifier unless it is a single
+/// word, this function returns an error for such a name.
+pub fn convert_ident(ident: &Ident, case: Case, opts: &Options) -> Result<Ident, IdentError> {
+    let name = render_string(&ident_name(ident), opts, case.style());
+    new_ident(&name, ident.span())
+}
+
+/// Provides methods to convert identifiers into the case styles which are valid identifiers.
+///
+/// ```rust
+/// use proc_macro2::{Ident, Span};
+/// use stringcase::syn::IdentCaser;
+/// use stringcase::Case;
+///
+/// fn main() {
+///     let ident = Ident::new("max_retry_count", Span::call_site());
+///     assert_eq!(ident.to_camel_case_ident().unwrap(), "maxRetryCount");
+///     assert_eq!(ident.to_macro_case_ident().unwrap(), "MAX_RETRY_COUNT");
+///     assert_eq!(ident.to_case_ident(Case::Ada).unwrap(), "Max_Retry_Count");
+///
+///     let err = ident.to_case_ident(Case::Kebab).unwrap_err();
+///     assert_eq!(err.to_string(), "`max-retry-count` is not a valid identifier");
+/// }
+/// ```
+pub trait IdentCaser {
+    /// Converts this identifier into Ada case.
+    fn to_ada_case_ident(&self) -> Result<Ident, IdentError>;
+
+    /// Converts this identifier into camel case.
+    fn to_camel_case_ident(&self) -> Result<Ident, IdentError>;
+
+    /// Converts this identifier into macro case.
+    fn to_macro_case_ident(&self) -> Result<Ident, IdentError>;
+
+    /// Converts this identifier into pascal case.
+    fn to_pascal_case_ident(&self) -> Result<Ident, IdentError>;
+
+    /// Converts this identifier into snake case.
+    fn to_snake_case_ident(&self) -> Result<Ident, IdentError>;
+
+    /// Converts this identifier into the specified case style.
+    fn to_case_ident(&self, case: Case) -> Result<Ident, IdentError>;
+
+    /// Converts this identifier into the specified case style with the specified options.
+    fn to_case_ident_with_options(&self, case: Case, opts: &Options) -> Result<Ident, IdentError>;
+}
+
+impl IdentCaser for Ident {
+    fn to_ada_case_ident(&self) -> Result<Ident, IdentError> {
+        convert_ident(self, Case::Ada, &DEFAULT_OPTIONS)
+    }
+
+    fn to_camel_case_ident(&self) -> Result<Ident, IdentError> {
+        convert_ident(self, Case::Camel, &DEFAULT_OPTIONS)
+    }
+
+    fn to_macro_case_ident(&self) -> Result<Ident, IdentError> {
+        convert_ident(self, Case::Macro, &DEFAULT_OPTIONS)
+    }
+
+    fn to_pascal_case_ident(&self) -> Result<Ident, IdentError> {
+        convert_ident(self, Case::Pascal, &DEFAULT_OPTIONS)
+    }
+
+    fn to_snake_case_ident(&self) -> Result<Ident, IdentError> {
+        convert_ident(self, Case::Snake, &DEFAULT_OPTIONS)
+    }
+
+    fn to_case_ident(&self, case: Case) -> Result<Ident, IdentError> {
+        convert_ident(self, case, &DEFAULT_OPTIONS)
+    }
+
+    fn to_case_ident_with_options(&self, case: Case, opts: &Options) -> Result<Ident, IdentError> {
+        convert_ident(self, case, opts)
+    }
+}
+
+#[cfg(test)]
+mod tests_of_syn {
+    use super::*;
+
+    fn ident(name: &str) -> Ident {
+        Ident::new(name, Span::call_site())
+    }
+
+    #[test]
+    fn test_of_is_keyword() {
+        assert!(is_keyword("type"));
+        assert!(is_keyword("async"));
+        assert!(!is_keyword("self"));
+        assert!(!is_keyword("Type"));
+        assert!(!is_keyword("union"));
+    }
+
+    #[test]
+    fn test_of_ident_name() {
+        assert_eq!(ident_name(&ident("foo")), "foo");
+        assert_eq!(
+            ident_name(&Ident::new_raw("type", Span::call_site())),
+            "type"
+        );
+    }
+
+    #[test]
+    fn test_of_new_ident() {
+        assert_eq!(new_ident("foo", Span::call_site()).unwrap(), "foo");
+        assert_eq!(
+            new_ident("type", Span::call_site()).unwrap().to_string(),
+            "r#type"
+        );
+
+        let err = new_ident("self", Span::call_site()).unwrap_err();
+        assert_eq!(err.name(), "self");
+        assert_eq!(err.to_string(), "`self` cannot be a raw identifier");
+
+        let err = new_ident("1foo", Span::call_site()).unwrap_err();
+        assert_eq!(err.to_string(), "`1foo` is not a valid identifier");
+        assert!(new_ident("", Span::call_site()).is_err());
+        assert!(new_ident("Foo Bar", Span::call_site()).is_err());
+    }
+
+    #[test]
+    fn test_of_convert_ident() {
+        let opts = Options::default();
+        let raw = Ident::new_raw("type", Span::call_site());
+        assert_eq!(
+            convert_ident(&raw, Case::Pascal, &opts)
+                .unwrap()
+                .to_string(),
+            "Type"
+        );
+        assert_eq!(
+            convert_ident(&raw, Case::Snake, &opts).unwrap().to_string(),
+            "r#type"
+        );
+        assert_eq!(
+            convert_ident(&ident("HTTPServer"), Case::Snake, &opts)
+                .unwrap()
+                .to_string(),
+            "http_server"
+        );
+        assert_eq!(
+            convert_ident(&ident("Foo"), Case::Kebab, &opts)
+                .unwrap()
+                .to_string(),
+            "foo"
+        );
+        assert!(convert_ident(&ident("FooBar"), Case::Kebab, &opts).is_err());
+        assert!(convert_ident(&ident("SELF"), Case::Snake, &opts).is_err());
+    }
+
+    #[test]
+    fn test_of_syn_error() {
+        let err = new_ident("super", Span::call_site()).unwrap_err();
+        let err: syn_crate::Error = err.into();
+        assert_eq!(err.to_string(), "`super` cannot be a raw identifier");
+    }
+}
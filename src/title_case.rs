@@ -2,8 +2,14 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
-use crate::capitalize::capitalize;
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, TITLE};
+#[cfg(feature = "alloc")]
+use crate::render::{render_cow, render_string};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
 /// Converts the input string to title case with the specified options.
 ///
@@ -13,13 +19,15 @@ use crate::options::Options;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let title = stringcase::title_case_with_options("fooBar123Baz", &opts);
 ///     assert_eq!(title, "Foo Bar 123 Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn title_case_with_options(input: &str, opts: &Options) -> String {
-    capitalize::<' '>(input, opts)
+    render_string(input, opts, TITLE)
 }
 
 /// Converts the input string to title case.
@@ -31,6 +39,7 @@ pub fn title_case_with_options(input: &str, opts: &Options) -> String {
 ///     let title = stringcase::title_case("fooBar123Baz");
 ///     assert_eq!(title, "Foo Bar123 Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn title_case(input: &str) -> String {
     let opts = Options {
@@ -38,11 +47,172 @@ pub fn title_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
-    capitalize::<' '>(input, &opts)
+    render_string(input, &opts, TITLE)
 }
 
-#[cfg(test)]
+/// Converts the input string to title case with the specified options, without allocating a new
+/// string if the input string is already in title case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`title_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     let title = stringcase::title_case_cow_with_options("Foo Bar 100 Baz", &opts);
+///     assert!(matches!(title, Cow::Borrowed("Foo Bar 100 Baz")));
+///
+///     let title = stringcase::title_case_cow_with_options("Foo Bar100 Baz", &opts);
+///     assert!(matches!(title, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn title_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, TITLE)
+}
+
+/// Converts the input string to title case, without allocating a new string if the input string
+/// is already in title case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`title_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let title = stringcase::title_case_cow("Foo Bar100 Baz");
+///     assert!(matches!(title, Cow::Borrowed("Foo Bar100 Baz")));
+///
+///     let title = stringcase::title_case_cow("Foo_Bar100_Baz");
+///     assert_eq!(title, stringcase::title_case("Foo_Bar100_Baz"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn title_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, TITLE)
+}
+
+/// Converts the input string to title case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`title_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::title_case_into("Foo_Bar100_Baz", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: Foo Bar100 Baz");
+/// ```
+#[inline(always)]
+pub fn title_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, TITLE)
+}
+
+/// Converts the input string to title case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`title_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::title_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+///     assert_eq!(result, Ok("Foo Bar100 Baz"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::title_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 14);
+/// ```
+#[inline(always)]
+pub fn title_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, TITLE)
+}
+
+/// A wrapper of a string slice which is displayed in title case.
+///
+/// It converts the string while formatting it in the same way as [`title_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::TitleCase;
+///
+///     let s = format!("name: {}", TitleCase("Foo_Bar100_Baz"));
+///     assert_eq!(s, "name: Foo Bar100 Baz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TitleCase<'a>(pub &'a str);
+
+impl fmt::Display for TitleCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, TITLE)
+    }
+}
+
+/// Checks whether the input string is in title case with the specified options.
+///
+/// It returns `true` if converting the input string with [`title_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_title_case_with_options("Foo Bar 100 Baz", &opts));
+///     assert!(!stringcase::is_title_case_with_options("Foo Bar100 Baz", &opts));
+/// ```
+#[inline(always)]
+pub fn is_title_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, TITLE)
+}
+
+/// Checks whether the input string is in title case.
+///
+/// It returns `true` if converting the input string with [`title_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_title_case("Foo Bar100 Baz"));
+///     assert!(!stringcase::is_title_case("Foo_Bar100_Baz"));
+/// ```
+#[inline(always)]
+pub fn is_title_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    is_rendered(input, &opts, TITLE)
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_title_case {
     use super::*;
 
@@ -131,7 +301,154 @@ mod tests_of_title_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_title_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_title_case() {
+        assert!(matches!(
+            title_case_cow("Foo Bar100 Baz"),
+            Cow::Borrowed("Foo Bar100 Baz")
+        ));
+        assert!(matches!(title_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_title_case() {
+        let result = title_case_cow("fooBar100Baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "Foo Bar100 Baz");
+
+        let result = title_case_cow("Foo Bar100 Baz_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, title_case("Foo Bar100 Baz_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["Foo Bar100 Baz", "fooBar100Baz", "Foo Bar100 Baz_", ""].iter() {
+            let result = title_case_cow_with_options(input, &opts);
+            assert_eq!(result, title_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_title_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        title_case_into("Foo_Bar100_Baz", &opts, &mut out).unwrap();
+        title_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "Foo Bar100 Baz");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        title_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, title_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(TitleCase("Foo_Bar100_Baz").to_string(), "Foo Bar100 Baz");
+        assert_eq!(format!("<{}>", TitleCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_title_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = title_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("Foo Bar100 Baz"));
+
+        let result = title_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 14];
+        let result = title_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("Foo Bar100 Baz"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 13];
+        let result = title_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 14 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = title_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(title_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_title_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_title_case() {
+        assert!(is_title_case("Foo Bar100 Baz"));
+        assert!(is_title_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_title_case() {
+        assert!(!is_title_case("foo bar"));
+        assert!(!is_title_case("Foo_Bar"));
+        assert!(!is_title_case("FooBar"));
+        assert!(!is_title_case("_Foo Bar100 Baz"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_title_case_with_options("Foo Bar 100 Baz", &opts));
+        assert!(is_title_case_with_options("", &opts));
+        assert!(!is_title_case_with_options("Foo Bar100 Baz", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_title_case_with_options {
     use super::*;
 
@@ -145,6 +462,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -157,6 +475,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -169,6 +488,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -181,6 +501,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -193,6 +514,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -205,6 +527,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -217,6 +540,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -229,6 +553,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -241,6 +566,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -253,6 +579,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc 123 456def G 89hi Jkl Mn 12");
@@ -265,6 +592,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc Def Ghi Jk Lm No");
@@ -277,6 +605,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc 456def");
@@ -295,6 +624,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -311,6 +641,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -323,6 +654,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -335,6 +667,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -347,6 +680,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -359,6 +693,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -371,6 +706,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -383,6 +719,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -395,6 +732,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -407,6 +745,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -419,6 +758,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123 456 Def G89 Hi Jkl Mn12");
@@ -431,6 +771,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc Def Ghi Jk Lm No");
@@ -443,6 +784,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123 Abc456 Def");
@@ -461,6 +803,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -477,6 +820,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -489,6 +833,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -501,6 +846,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -513,6 +859,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -525,6 +872,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -537,6 +885,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -549,6 +898,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -561,6 +911,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -573,6 +924,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -585,6 +937,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc 123 456 Def G 89 Hi Jkl Mn 12");
@@ -597,6 +950,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc Def Ghi Jk Lm No");
@@ -609,6 +963,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123 Abc 456 Def");
@@ -627,6 +982,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -643,6 +999,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -655,6 +1012,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -667,6 +1025,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -679,6 +1038,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -691,6 +1051,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -703,6 +1064,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -715,6 +1077,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -727,6 +1090,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -739,6 +1103,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -751,6 +1116,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123 456def G89hi Jkl Mn12");
@@ -763,6 +1129,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc Def Ghi Jk Lm No");
@@ -775,6 +1142,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -793,6 +1161,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -809,6 +1178,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -821,6 +1191,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -833,6 +1204,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -849,6 +1221,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -865,6 +1238,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -881,6 +1255,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: " ",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -897,6 +1272,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -913,6 +1289,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -929,6 +1306,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -945,6 +1323,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc 123 456def G 89hi Jkl Mn 12");
@@ -961,6 +1340,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc ~! Def # Ghi % Jk Lm No ?");
@@ -973,6 +1353,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc 456def");
@@ -991,6 +1372,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1003,6 +1385,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc 123def");
@@ -1019,6 +1402,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1031,6 +1415,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1043,6 +1428,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1059,6 +1445,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1075,6 +1462,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1091,6 +1479,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: " ",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1107,6 +1496,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1123,6 +1513,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1139,6 +1530,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1155,6 +1547,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123 456 Def G89 Hi Jkl Mn12");
@@ -1171,6 +1564,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ". Abc~! Def# Ghi% Jk Lm No ?");
@@ -1183,6 +1577,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123 Abc456 Def");
@@ -1201,6 +1596,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1213,6 +1609,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123 Def");
@@ -1229,6 +1626,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1241,6 +1639,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1253,6 +1652,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1269,6 +1669,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1285,6 +1686,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1301,6 +1703,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: " ",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1317,6 +1720,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1333,6 +1737,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1349,6 +1754,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1365,6 +1771,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc 123 456 Def G 89 Hi Jkl Mn 12");
@@ -1381,6 +1788,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ". Abc ~! Def # Ghi % Jk Lm No ?");
@@ -1393,6 +1801,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123 Abc 456 Def");
@@ -1411,6 +1820,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1423,6 +1833,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc 123 Def");
@@ -1439,6 +1850,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1451,6 +1863,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1463,6 +1876,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1479,6 +1893,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1495,6 +1910,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1511,6 +1927,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: " ",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1527,6 +1944,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1543,6 +1961,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1559,6 +1978,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123 456def G89hi Jkl Mn12");
@@ -1575,6 +1995,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~! Def# Ghi% Jk Lm No ?");
@@ -1587,6 +2008,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1605,6 +2027,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1617,6 +2040,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123def");
@@ -1633,6 +2057,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1645,6 +2070,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1657,6 +2083,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1673,6 +2100,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1689,6 +2117,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1705,6 +2134,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1721,6 +2151,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1737,6 +2168,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1753,6 +2185,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1769,6 +2202,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc 123 456def G 89hi Jkl Mn 12");
@@ -1785,6 +2219,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc ~! Def # Ghi % Jk Lm No ?");
@@ -1797,6 +2232,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc 456def");
@@ -1815,6 +2251,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1827,6 +2264,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-b2",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc 123def");
@@ -1843,6 +2281,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1855,6 +2294,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -1867,6 +2307,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1883,6 +2324,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1899,6 +2341,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1915,6 +2358,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1931,6 +2375,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1947,6 +2392,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1963,6 +2409,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -1979,6 +2426,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123 456 Def G89 Hi Jkl Mn12");
@@ -1995,6 +2443,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ". Abc~! Def# Ghi% Jk Lm No ?");
@@ -2007,6 +2456,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123 Abc456 Def");
@@ -2025,6 +2475,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -2041,6 +2492,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -2053,6 +2505,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -2065,6 +2518,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2081,6 +2535,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2097,6 +2552,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2113,6 +2569,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2129,6 +2586,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2145,6 +2603,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2161,6 +2620,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2177,6 +2637,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc 123 456 Def G 89 Hi Jkl Mn 12");
@@ -2193,6 +2654,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ". Abc ~! Def # Ghi % Jk Lm No ?");
@@ -2205,6 +2667,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123 Abc 456 Def");
@@ -2223,6 +2686,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -2239,6 +2703,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -2251,6 +2716,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc Def Gh Ijk");
@@ -2263,6 +2729,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2279,6 +2746,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2295,6 +2763,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2311,6 +2780,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc Def Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2327,6 +2797,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("Abc_Def_Ghi", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2343,6 +2814,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2359,6 +2831,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc Def Ghi");
@@ -2375,6 +2848,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = title_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123 456def G89hi Jkl Mn12");
@@ -2391,6 +2865,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = title_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~! Def# Ghi% Jk Lm No ?");
@@ -2403,6 +2878,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -2421,6 +2897,7 @@ mod tests_of_title_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = title_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -2,8 +2,14 @@
 // This program is free software under MIT License.
 // See the file LICENSE in this distribution for more details.
 
-use crate::capitalize::capitalize;
-use crate::options::Options;
+use crate::options::{Options, DEFAULT_OPTIONS};
+use crate::render::{is_rendered, render_display, render_fmt, render_slice, BufferTooSmall, TRAIN};
+#[cfg(feature = "alloc")]
+use crate::render::{render_cow, render_string};
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String};
+use core::fmt;
 
 /// Converts the input string to train case with the specified options.
 ///
@@ -13,13 +19,15 @@ use crate::options::Options;
 ///       separate_after_non_alphabets: true,
 ///       separators: "",
 ///       keep: "",
+///       ..Default::default()
 ///     };
 ///     let train = stringcase::train_case_with_options("fooBar123Baz", &opts);
 ///     assert_eq!(train, "Foo-Bar-123-Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn train_case_with_options(input: &str, opts: &Options) -> String {
-    capitalize::<'-'>(input, opts)
+    render_string(input, opts, TRAIN)
 }
 
 /// Converts the input string to train case.
@@ -31,6 +39,7 @@ pub fn train_case_with_options(input: &str, opts: &Options) -> String {
 ///     let train = stringcase::train_case("fooBar123Baz");
 ///     assert_eq!(train, "Foo-Bar123-Baz");
 /// ```
+#[cfg(feature = "alloc")]
 #[inline(always)]
 pub fn train_case(input: &str) -> String {
     let opts = Options {
@@ -38,11 +47,173 @@ pub fn train_case(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
+    };
+    render_string(input, &opts, TRAIN)
+}
+
+/// Converts the input string to train case with the specified options, without allocating a new
+/// string if the input string is already in train case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with
+/// [`train_case_with_options`] and the same options returns the same string, and otherwise returns
+/// [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     let train = stringcase::train_case_cow_with_options("Foo-Bar-100-Baz", &opts);
+///     assert!(matches!(train, Cow::Borrowed("Foo-Bar-100-Baz")));
+///
+///     let train = stringcase::train_case_cow_with_options("Foo-Bar100-Baz", &opts);
+///     assert!(matches!(train, Cow::Owned(_)));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn train_case_cow_with_options<'a>(input: &'a str, opts: &Options) -> Cow<'a, str> {
+    render_cow(input, opts, TRAIN)
+}
+
+/// Converts the input string to train case, without allocating a new string if the input string
+/// is already in train case.
+///
+/// It returns [`Cow::Borrowed`] with the input string if converting it with [`train_case`] returns
+/// the same string, and otherwise returns [`Cow::Owned`] with the converted string.
+///
+/// ```rust
+///     use std::borrow::Cow;
+///
+///     let train = stringcase::train_case_cow("Foo-Bar100-Baz");
+///     assert!(matches!(train, Cow::Borrowed("Foo-Bar100-Baz")));
+///
+///     let train = stringcase::train_case_cow("Foo_Bar100_Baz");
+///     assert_eq!(train, stringcase::train_case("Foo_Bar100_Baz"));
+/// ```
+#[cfg(feature = "alloc")]
+#[inline(always)]
+pub fn train_case_cow(input: &str) -> Cow<'_, str> {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
+    };
+    render_cow(input, &opts, TRAIN)
+}
+
+/// Converts the input string to train case with the specified options, and writes the result
+/// into the specified [`fmt::Write`] without allocating a new string.
+///
+/// It writes the same string as [`train_case_with_options`] returns with the same options.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut out = String::from("name: ");
+///     stringcase::train_case_into("Foo_Bar100_Baz", &opts, &mut out).unwrap();
+///     assert_eq!(out, "name: Foo-Bar100-Baz");
+/// ```
+#[inline(always)]
+pub fn train_case_into<W: fmt::Write + ?Sized>(
+    input: &str,
+    opts: &Options,
+    out: &mut W,
+) -> fmt::Result {
+    render_fmt(out, input, opts, TRAIN)
+}
+
+/// Converts the input string to train case with the specified options, and writes the result
+/// into the specified byte buffer without allocating memory.
+///
+/// It returns the written part of the buffer as a string slice, which is the same string as
+/// [`train_case_with_options`] returns with the same options.
+/// If the buffer is shorter than the result, it returns [`BufferTooSmall`] which holds the
+/// required length, and the content of the buffer is unspecified.
+///
+/// ```rust
+///     let opts = stringcase::Options::default();
+///     let mut buf = [0u8; 32];
+///     let result = stringcase::train_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+///     assert_eq!(result, Ok("Foo-Bar100-Baz"));
+///
+///     let mut buf = [0u8; 4];
+///     let result = stringcase::train_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+///     assert_eq!(result.unwrap_err().required_len, 14);
+/// ```
+#[inline(always)]
+pub fn train_case_to_slice<'b>(
+    input: &str,
+    opts: &Options,
+    buf: &'b mut [u8],
+) -> Result<&'b str, BufferTooSmall> {
+    render_slice(buf, input, opts, TRAIN)
+}
+
+/// A wrapper of a string slice which is displayed in train case.
+///
+/// It converts the string while formatting it in the same way as [`train_case`], without allocating
+/// a new string.
+///
+/// ```rust
+///     use stringcase::TrainCase;
+///
+///     let s = format!("name: {}", TrainCase("Foo_Bar100_Baz"));
+///     assert_eq!(s, "name: Foo-Bar100-Baz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrainCase<'a>(pub &'a str);
+
+impl fmt::Display for TrainCase<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        render_display(f, self.0, &DEFAULT_OPTIONS, TRAIN)
+    }
+}
+
+/// Checks whether the input string is in train case with the specified options.
+///
+/// It returns `true` if converting the input string with [`train_case_with_options`] and the
+/// same options returns the same string.
+///
+/// ```rust
+///     let opts = stringcase::Options{
+///       separate_before_non_alphabets: true,
+///       ..Default::default()
+///     };
+///     assert!(stringcase::is_train_case_with_options("Foo-Bar-100-Baz", &opts));
+///     assert!(!stringcase::is_train_case_with_options("Foo-Bar100-Baz", &opts));
+/// ```
+#[inline(always)]
+pub fn is_train_case_with_options(input: &str, opts: &Options) -> bool {
+    is_rendered(input, opts, TRAIN)
+}
+
+/// Checks whether the input string is in train case.
+///
+/// It returns `true` if converting the input string with [`train_case`] returns the same
+/// string.
+///
+/// ```rust
+///     assert!(stringcase::is_train_case("Foo-Bar100-Baz"));
+///     assert!(!stringcase::is_train_case("Foo_Bar100_Baz"));
+/// ```
+#[inline(always)]
+pub fn is_train_case(input: &str) -> bool {
+    let opts = Options {
+        separate_before_non_alphabets: false,
+        separate_after_non_alphabets: true,
+        separators: "",
+        keep: "",
+        ..Default::default()
     };
-    capitalize::<'-'>(input, &opts)
+    is_rendered(input, &opts, TRAIN)
 }
 
 /// Converts the input string to train case with the specified separator characters.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use train_case_with_options instead")]
 #[inline(always)]
@@ -52,11 +223,13 @@ pub fn train_case_with_sep(input: &str, seps: &str) -> String {
         separate_after_non_alphabets: true,
         separators: seps,
         keep: "",
+        ..Default::default()
     };
-    capitalize::<'-'>(input, &opts)
+    render_string(input, &opts, TRAIN)
 }
 
 /// Converts the input string to train case with the specified characters to be kept.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use train_case_with_options instead")]
 #[inline(always)]
@@ -66,14 +239,16 @@ pub fn train_case_with_keep(input: &str, kept: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: kept,
+        ..Default::default()
     };
-    capitalize::<'-'>(input, &opts)
+    render_string(input, &opts, TRAIN)
 }
 
 /// Converts the input string to train case.
 ///
 /// It treats the beginning and the end of a sequence of non-alphabetic characters as a word
 /// boundary.
+#[cfg(feature = "alloc")]
 #[doc(hidden)]
 #[deprecated(since = "0.4.0", note = "Should use train_case_with_options instead")]
 #[inline(always)]
@@ -83,11 +258,12 @@ pub fn train_case_with_nums_as_word(input: &str) -> String {
         separate_after_non_alphabets: true,
         separators: "",
         keep: "",
+        ..Default::default()
     };
-    capitalize::<'-'>(input, &opts)
+    render_string(input, &opts, TRAIN)
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_train_case {
     use super::*;
 
@@ -164,7 +340,154 @@ mod tests_of_train_case {
     }
 }
 
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_train_case_cow {
+    use super::*;
+
+    #[test]
+    fn it_should_borrow_a_string_in_train_case() {
+        assert!(matches!(
+            train_case_cow("Foo-Bar100-Baz"),
+            Cow::Borrowed("Foo-Bar100-Baz")
+        ));
+        assert!(matches!(train_case_cow(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn it_should_convert_a_string_not_in_train_case() {
+        let result = train_case_cow("fooBar100Baz");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, "Foo-Bar100-Baz");
+
+        let result = train_case_cow("Foo-Bar100-Baz_");
+        assert!(matches!(result, Cow::Owned(_)));
+        assert_eq!(result, train_case("Foo-Bar100-Baz_"));
+    }
+
+    #[test]
+    fn it_should_convert_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        for input in ["Foo-Bar100-Baz", "fooBar100Baz", "Foo-Bar100-Baz_", ""].iter() {
+            let result = train_case_cow_with_options(input, &opts);
+            assert_eq!(result, train_case_with_options(input, &opts));
+            assert_eq!(matches!(result, Cow::Borrowed(_)), result == *input);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_train_case_into {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::default();
+        let mut out = String::new();
+        train_case_into("Foo_Bar100_Baz", &opts, &mut out).unwrap();
+        train_case_into("", &opts, &mut out).unwrap();
+        assert_eq!(out, "Foo-Bar100-Baz");
+    }
+
+    #[test]
+    fn it_should_write_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut out = String::new();
+        train_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        assert_eq!(out, train_case_with_options("foo_bar100%BAZQux", &opts));
+    }
+
+    #[test]
+    fn it_should_display() {
+        assert_eq!(TrainCase("Foo_Bar100_Baz").to_string(), "Foo-Bar100-Baz");
+        assert_eq!(format!("<{}>", TrainCase("")), "<>");
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_train_case_to_slice {
+    use super::*;
+
+    #[test]
+    fn it_should_write_into_a_slice() {
+        let opts = Options::default();
+        let mut buf = [0u8; 32];
+        let result = train_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("Foo-Bar100-Baz"));
+
+        let result = train_case_to_slice("", &opts, &mut buf);
+        assert_eq!(result, Ok(""));
+    }
+
+    #[test]
+    fn it_should_write_into_a_slice_of_just_the_required_length() {
+        let opts = Options::default();
+        let mut buf = [0u8; 14];
+        let result = train_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+        assert_eq!(result, Ok("Foo-Bar100-Baz"));
+    }
+
+    #[test]
+    fn it_should_fail_if_a_slice_is_too_small() {
+        let opts = Options::default();
+        let mut buf = [0u8; 13];
+        let result = train_case_to_slice("Foo_Bar100_Baz", &opts, &mut buf);
+        assert_eq!(result, Err(BufferTooSmall { required_len: 14 }));
+    }
+
+    #[test]
+    fn it_should_write_the_same_string_as_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            keep: "%",
+            ..Default::default()
+        };
+        let mut buf = [0u8; 32];
+        let result = train_case_to_slice("foo_bar100%BAZQux", &opts, &mut buf);
+        assert_eq!(
+            result,
+            Ok(train_case_with_options("foo_bar100%BAZQux", &opts).as_str())
+        );
+    }
+}
+
 #[cfg(test)]
+mod tests_of_is_train_case {
+    use super::*;
+
+    #[test]
+    fn it_should_check_a_string_in_train_case() {
+        assert!(is_train_case("Foo-Bar100-Baz"));
+        assert!(is_train_case(""));
+    }
+
+    #[test]
+    fn it_should_check_a_string_not_in_train_case() {
+        assert!(!is_train_case("foo-bar"));
+        assert!(!is_train_case("Foo_Bar"));
+        assert!(!is_train_case("FOO-BAR"));
+        assert!(!is_train_case("_Foo-Bar100-Baz"));
+    }
+
+    #[test]
+    fn it_should_check_with_options() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            ..Default::default()
+        };
+        assert!(is_train_case_with_options("Foo-Bar-100-Baz", &opts));
+        assert!(is_train_case_with_options("", &opts));
+        assert!(!is_train_case_with_options("Foo-Bar100-Baz", &opts));
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
 mod tests_of_cobol_case_with_options {
     use super::*;
 
@@ -178,6 +501,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -190,6 +514,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -202,6 +527,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -214,6 +540,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -226,6 +553,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -238,6 +566,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -250,6 +579,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -262,6 +592,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc-123-456def-G-89hi-Jkl-Mn-12");
@@ -274,6 +605,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc-Def-Ghi-Jk-Lm-No");
@@ -286,6 +618,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc-456def");
@@ -304,6 +637,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -320,6 +654,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -332,6 +667,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -344,6 +680,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -356,6 +693,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -368,6 +706,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -380,6 +719,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -392,6 +732,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -404,6 +745,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123-456-Def-G89-Hi-Jkl-Mn12");
@@ -416,6 +758,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc-Def-Ghi-Jk-Lm-No");
@@ -428,6 +771,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-Abc456-Def");
@@ -446,6 +790,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -462,6 +807,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -474,6 +820,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -486,6 +833,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -498,6 +846,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -510,6 +859,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -522,6 +872,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -534,6 +885,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -546,6 +898,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc-123-456-Def-G-89-Hi-Jkl-Mn-12");
@@ -558,6 +911,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc-Def-Ghi-Jk-Lm-No");
@@ -570,6 +924,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-Abc-456-Def");
@@ -588,6 +943,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -604,6 +960,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -616,6 +973,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -628,6 +986,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -640,6 +999,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -652,6 +1012,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -664,6 +1025,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -676,6 +1038,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -688,6 +1051,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123-456def-G89hi-Jkl-Mn12");
@@ -700,6 +1064,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "Abc-Def-Ghi-Jk-Lm-No");
@@ -712,6 +1077,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -730,6 +1096,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -746,6 +1113,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -758,6 +1126,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -770,6 +1139,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -786,6 +1156,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -802,6 +1173,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -818,6 +1190,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -834,6 +1207,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -850,6 +1224,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc-123-456def-G-89hi-Jkl-Mn-12");
@@ -866,6 +1241,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc-~!-Def-#-Ghi-%-Jk-Lm-No-?");
@@ -878,6 +1254,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc-456def");
@@ -896,6 +1273,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -908,6 +1286,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc-123def");
@@ -924,6 +1303,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -936,6 +1316,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -948,6 +1329,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -964,6 +1346,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -980,6 +1363,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -996,6 +1380,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1012,6 +1397,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1028,6 +1414,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123-456-Def-G89-Hi-Jkl-Mn12");
@@ -1044,6 +1431,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-Abc~!-Def#-Ghi%-Jk-Lm-No-?");
@@ -1056,6 +1444,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-Abc456-Def");
@@ -1074,6 +1463,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1086,6 +1476,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123-Def");
@@ -1102,6 +1493,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1114,6 +1506,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1126,6 +1519,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1142,6 +1536,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1158,6 +1553,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1174,6 +1570,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1190,6 +1587,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1206,6 +1604,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc-123-456-Def-G-89-Hi-Jkl-Mn-12");
@@ -1222,6 +1621,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-Abc-~!-Def-#-Ghi-%-Jk-Lm-No-?");
@@ -1234,6 +1634,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-Abc-456-Def");
@@ -1252,6 +1653,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1264,6 +1666,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc-123-Def");
@@ -1280,6 +1683,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1292,6 +1696,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1304,6 +1709,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1320,6 +1726,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1336,6 +1743,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1352,6 +1760,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1368,6 +1777,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123-456def-G89hi-Jkl-Mn12");
@@ -1384,6 +1794,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!-Def#-Ghi%-Jk-Lm-No-?");
@@ -1396,6 +1807,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -1414,6 +1826,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1426,6 +1839,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "-b2",
                 keep: "",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc123def");
@@ -1442,6 +1856,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1454,6 +1869,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1466,6 +1882,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1482,6 +1899,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1498,6 +1916,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1514,6 +1933,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1530,6 +1950,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1546,6 +1967,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let mut result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc-123-456def-G-89hi-Jkl-Mn-12");
@@ -1562,6 +1984,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc-~!-Def-#-Ghi-%-Jk-Lm-No-?");
@@ -1574,6 +1997,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let mut result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc-456def");
@@ -1592,6 +2016,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1604,6 +2029,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-b2",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123def", &opts);
             assert_eq!(result, "Abc-123def");
@@ -1620,6 +2046,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1632,6 +2059,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1644,6 +2072,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1660,6 +2089,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1676,6 +2106,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1692,6 +2123,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1708,6 +2140,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1724,6 +2157,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123-456-Def-G89-Hi-Jkl-Mn12");
@@ -1740,6 +2174,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-Abc~!-Def#-Ghi%-Jk-Lm-No-?");
@@ -1752,6 +2187,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-Abc456-Def");
@@ -1770,6 +2206,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1786,6 +2223,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1798,6 +2236,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1810,6 +2249,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1826,6 +2266,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1842,6 +2283,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1858,6 +2300,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1874,6 +2317,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1890,6 +2334,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc-123-456-Def-G-89-Hi-Jkl-Mn-12");
@@ -1906,6 +2351,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".-Abc-~!-Def-#-Ghi-%-Jk-Lm-No-?");
@@ -1918,6 +2364,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123-Abc-456-Def");
@@ -1936,6 +2383,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -1952,6 +2400,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("abcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1964,6 +2413,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("AbcDefGHIjk", &opts);
             assert_eq!(result, "Abc-Def-Gh-Ijk");
@@ -1976,6 +2426,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = train_case_with_options("abc_def_ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -1992,6 +2443,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("abc-def-ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -2008,6 +2460,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("Abc-Def-Ghi", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -2024,6 +2477,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC_DEF_GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -2040,6 +2494,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("ABC-DEF-GHI", &opts);
             assert_eq!(result, "Abc-Def-Ghi");
@@ -2056,6 +2511,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = train_case_with_options("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "Abc123-456def-G89hi-Jkl-Mn12");
@@ -2072,6 +2528,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = train_case_with_options(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".abc~!-Def#-Ghi%-Jk-Lm-No-?");
@@ -2084,6 +2541,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("123abc456def", &opts);
             assert_eq!(result, "123abc456def");
@@ -2102,6 +2560,7 @@ mod tests_of_cobol_case_with_options {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = train_case_with_options("", &opts);
             assert_eq!(result, "");
@@ -3,6 +3,9 @@
 // See the file LICENSE in this distribution for more details.
 
 use crate::options::Options;
+use crate::render::{render_string, Style, WordCase};
+
+use alloc::string::String;
 
 /// A generic function that converts string cases into an uppercased format joined by a specified
 /// joiner character.
@@ -11,13 +14,15 @@ use crate::options::Options;
 /// non-alphabetic character rules defined in `opts`, converts alphabetic characters to uppercase,
 /// and joins the words using the const generic character `JOINER`.
 ///
-/// It operates by iterating character by character through `input` using an internal state
-/// machine. It handles ASCII uppercase, ASCII lowercase, and non-alphabetic characters (digits and
+/// It splits `input` into words in the same way as the [`words`](crate::words) function.
+/// It handles ASCII uppercase, ASCII lowercase, and non-alphabetic characters (digits and
 /// symbols) according to options such as `opts.separators`, `opts.keep`,
 /// `opts.separate_before_non_alphabets`, and `opts.separate_after_non_alphabets` to determine
 /// word boundaries, upperizing characters, and insert the `JOINER` character.
 /// If a character is specified in both `opts.separators` and `opts.keep`, the character in
 /// `opts.separators` takes precedence and the character in `opts.keep` is ignored.
+/// If `opts.unicode` is `true`, it handles Unicode uppercase and lowercase letters instead of
+/// ASCII ones and converts them with full Unicode case mappings.
 ///
 /// # Parameters
 ///
@@ -41,99 +46,13 @@ use crate::options::Options;
 ///     separate_after_non_alphabets: true,
 ///     separators: "",
 ///     keep: "",
+///     ..Default::default()
 /// };
 /// let result = upperize::<'.' >("foo_bar_100_baz", &opts);
 /// assert_eq!(result, "FOO.BAR.100.BAZ");
 /// ```
 pub fn upperize<const JOINER: char>(input: &str, opts: &Options) -> String {
-    let mut result = String::with_capacity(input.len() + input.len() / 2);
-    // .len returns byte count but ok in this case!
-
-    #[derive(PartialEq)]
-    enum ChIs {
-        FirstOfStr,
-        NextOfUpper,
-        NextOfContdUpper,
-        NextOfSepMark,
-        NextOfKeptMark,
-        Other,
-    }
-
-    let mut flag = ChIs::FirstOfStr;
-
-    for ch in input.chars() {
-        if ch.is_ascii_uppercase() {
-            if flag == ChIs::FirstOfStr {
-                result.push(ch);
-                flag = ChIs::NextOfUpper;
-            } else if flag == ChIs::NextOfUpper
-                || flag == ChIs::NextOfContdUpper
-                || (!opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(ch);
-                flag = ChIs::NextOfContdUpper;
-            } else {
-                result.push(JOINER);
-                result.push(ch);
-                flag = ChIs::NextOfUpper;
-            }
-        } else if ch.is_ascii_lowercase() {
-            if flag == ChIs::NextOfContdUpper {
-                if let Some(prev) = result.pop() {
-                    result.push(JOINER);
-                    result.push(prev);
-                    result.push(ch.to_ascii_uppercase());
-                }
-            } else if flag == ChIs::NextOfSepMark
-                || (opts.separate_after_non_alphabets && flag == ChIs::NextOfKeptMark)
-            {
-                result.push(JOINER);
-                result.push(ch.to_ascii_uppercase());
-            } else {
-                result.push(ch.to_ascii_uppercase());
-            }
-            flag = ChIs::Other;
-        } else {
-            let mut is_kept_char = false;
-            if ch.is_ascii_digit() {
-                is_kept_char = true;
-            } else if !opts.separators.is_empty() {
-                if !opts.separators.contains(ch) {
-                    is_kept_char = true;
-                }
-            } else if !opts.keep.is_empty() {
-                #[allow(clippy::collapsible_if)]
-                if opts.keep.contains(ch) {
-                    is_kept_char = true;
-                }
-            }
-
-            if is_kept_char {
-                if opts.separate_before_non_alphabets {
-                    if flag == ChIs::FirstOfStr || flag == ChIs::NextOfKeptMark {
-                        result.push(ch);
-                    } else {
-                        result.push(JOINER);
-                        result.push(ch);
-                    }
-                } else {
-                    if flag != ChIs::NextOfSepMark {
-                        result.push(ch);
-                    } else {
-                        result.push(JOINER);
-                        result.push(ch);
-                    }
-                }
-                flag = ChIs::NextOfKeptMark;
-            } else {
-                if flag != ChIs::FirstOfStr {
-                    flag = ChIs::NextOfSepMark;
-                }
-            }
-        }
-    }
-
-    result
+    render_string(input, opts, Style::joined(WordCase::Upper, JOINER))
 }
 
 #[cfg(test)]
@@ -150,6 +69,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -162,6 +82,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -174,6 +95,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -186,6 +108,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -198,6 +121,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -210,6 +134,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -222,6 +147,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -234,6 +160,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC.123.456DEF.G.89HI.JKL.MN.12");
@@ -246,6 +173,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC.DEF.GHI.JK.LM.NO");
@@ -258,6 +186,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123ABC.456DEF");
@@ -276,6 +205,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -292,6 +222,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -304,6 +235,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -316,6 +248,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -328,6 +261,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -340,6 +274,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -352,6 +287,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -364,6 +300,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -376,6 +313,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123.456.DEF.G89.HI.JKL.MN12");
@@ -388,6 +326,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC.DEF.GHI.JK.LM.NO");
@@ -400,6 +339,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.ABC456.DEF");
@@ -418,6 +358,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -434,6 +375,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -446,6 +388,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -458,6 +401,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -470,6 +414,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -482,6 +427,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -494,6 +440,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -506,6 +453,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -518,6 +466,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC.123.456.DEF.G.89.HI.JKL.MN.12");
@@ -530,6 +479,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC.DEF.GHI.JK.LM.NO");
@@ -542,6 +492,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.ABC.456.DEF");
@@ -560,6 +511,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -576,6 +528,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -588,6 +541,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -600,6 +554,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -612,6 +567,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -624,6 +580,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -636,6 +593,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -648,6 +606,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -660,6 +619,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123.456DEF.G89HI.JKL.MN12");
@@ -672,6 +632,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "ABC.DEF.GHI.JK.LM.NO");
@@ -684,6 +645,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -702,6 +664,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -718,6 +681,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -730,6 +694,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -742,6 +707,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -758,6 +724,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -774,6 +741,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -790,6 +758,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -806,6 +775,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -822,6 +792,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC.123.456DEF.G.89HI.JKL.MN.12");
@@ -838,6 +809,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC.~!.DEF.#.GHI.%.JK.LM.NO.?");
@@ -850,6 +822,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123ABC.456DEF");
@@ -868,6 +841,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -884,6 +858,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -896,6 +871,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -908,6 +884,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -924,6 +901,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -940,6 +918,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -956,6 +935,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -972,6 +952,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -988,6 +969,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123.456.DEF.G89.HI.JKL.MN12");
@@ -1004,6 +986,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..ABC~!.DEF#.GHI%.JK.LM.NO.?");
@@ -1016,6 +999,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.ABC456.DEF");
@@ -1034,6 +1018,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1050,6 +1035,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1062,6 +1048,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1074,6 +1061,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1090,6 +1078,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1106,6 +1095,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1122,6 +1112,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1138,6 +1129,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1154,6 +1146,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC.123.456.DEF.G.89.HI.JKL.MN.12");
@@ -1170,6 +1163,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..ABC.~!.DEF.#.GHI.%.JK.LM.NO.?");
@@ -1182,6 +1176,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.ABC.456.DEF");
@@ -1200,6 +1195,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1216,6 +1212,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1228,6 +1225,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1240,6 +1238,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1256,6 +1255,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1272,6 +1272,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1288,6 +1289,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1304,6 +1306,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1320,6 +1323,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123.456DEF.G89HI.JKL.MN12");
@@ -1336,6 +1340,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: ":@$&()/",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC~!.DEF#.GHI%.JK.LM.NO.?");
@@ -1348,6 +1353,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -1366,6 +1372,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "-_",
                 keep: "",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1382,6 +1389,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1394,6 +1402,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1406,6 +1415,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC._DEF._GHI");
@@ -1422,6 +1432,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1438,6 +1449,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1454,6 +1466,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1470,6 +1483,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1486,6 +1500,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC.123.456DEF.G.89HI.JKL.MN.12");
@@ -1502,6 +1517,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC.~!.DEF.#.GHI.%.JK.LM.NO.?");
@@ -1514,6 +1530,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123ABC.456DEF");
@@ -1532,6 +1549,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1548,6 +1566,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1560,6 +1579,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1572,6 +1592,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1588,6 +1609,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1604,6 +1626,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1620,6 +1643,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1636,6 +1660,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1652,6 +1677,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123.456.DEF.G89.HI.JKL.MN12");
@@ -1668,6 +1694,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..ABC~!.DEF#.GHI%.JK.LM.NO.?");
@@ -1680,6 +1707,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.ABC456.DEF");
@@ -1698,6 +1726,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1714,6 +1743,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1726,6 +1756,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1738,6 +1769,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1754,6 +1786,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1770,6 +1803,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1786,6 +1820,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1802,6 +1837,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1818,6 +1854,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC.123.456.DEF.G.89.HI.JKL.MN.12");
@@ -1834,6 +1871,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, "..ABC.~!.DEF.#.GHI.%.JK.LM.NO.?");
@@ -1846,6 +1884,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123.ABC.456.DEF");
@@ -1864,6 +1903,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: true,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
@@ -1880,6 +1920,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1892,6 +1933,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("AbcDefGHIjk", &opts);
             assert_eq!(result, "ABC.DEF.GH.IJK");
@@ -1904,6 +1946,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc_def_ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1920,6 +1963,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc-def-ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1936,6 +1980,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("Abc-Def-Ghi", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1952,6 +1997,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC_DEF_GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1968,6 +2014,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("ABC-DEF-GHI", &opts);
             assert_eq!(result, "ABC.DEF.GHI");
@@ -1984,6 +2031,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("abc123-456defG89HIJklMN12", &opts);
             assert_eq!(result, "ABC123.456DEF.G89HI.JKL.MN12");
@@ -2000,6 +2048,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: ".~!#%?",
+                ..Default::default()
             };
             let result = upperize::<'.'>(":.abc~!@def#$ghi%&jk(lm)no/?", &opts);
             assert_eq!(result, ".ABC~!.DEF#.GHI%.JK.LM.NO.?");
@@ -2012,6 +2061,7 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("123abc456def", &opts);
             assert_eq!(result, "123ABC456DEF");
@@ -2030,9 +2080,78 @@ mod tests_of_upperize {
                 separate_after_non_alphabets: false,
                 separators: "",
                 keep: "-_",
+                ..Default::default()
             };
             let result = upperize::<'.'>("", &opts);
             assert_eq!(result, "");
         }
     }
+
+    mod unicode_letters {
+        use super::*;
+
+        #[test]
+        fn convert_non_ascii_letters_as_separators_by_default() {
+            let opts = Options {
+                unicode: false,
+                ..Default::default()
+            };
+            let result = upperize::<'.'>("straßeÜberweisung", &opts);
+            assert_eq!(result, "STRA.E.BERWEISUNG");
+        }
+
+        #[test]
+        fn convert_non_ascii_letters() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = upperize::<'.'>("straßeÜberweisung", &opts);
+            assert_eq!(result, "STRASSE.ÜBERWEISUNG");
+
+            let result = upperize::<'.'>("ÉtatCivil", &opts);
+            assert_eq!(result, "ÉTAT.CIVIL");
+
+            let result = upperize::<'.'>("ÉTATCivil", &opts);
+            assert_eq!(result, "ÉTAT.CIVIL");
+        }
+
+        #[test]
+        fn convert_with_one_to_many_case_mappings() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = upperize::<'.'>("İSTANBULİçin", &opts);
+            assert_eq!(result, "İSTANBUL.İÇIN");
+        }
+
+        #[test]
+        fn convert_letters_without_case_and_non_ascii_digits() {
+            let opts = Options {
+                unicode: true,
+                ..Default::default()
+            };
+            let result = upperize::<'.'>("日本語_fooBar٣baz", &opts);
+            assert_eq!(result, "日本語.FOO.BAR٣.BAZ");
+        }
+    }
+
+    mod acronyms {
+        use super::*;
+
+        #[test]
+        fn convert_with_acronyms() {
+            let opts = Options {
+                acronyms: &["HTTP", "ID", "XML"],
+                uppercase_acronyms: true,
+                ..Default::default()
+            };
+            let result = upperize::<'.'>("XMLHTTPRequest", &opts);
+            assert_eq!(result, "XML.HTTP.REQUEST");
+
+            let result = upperize::<'.'>("httpServerID", &opts);
+            assert_eq!(result, "HTTP.SERVER.ID");
+        }
+    }
 }
@@ -0,0 +1,663 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use core::ops::Range;
+use core::str::CharIndices;
+
+use crate::chars::{is_digit, is_lower, is_upper, push_lower, push_upper};
+use crate::options::Options;
+use crate::render::Sink;
+
+/// A struct that represents a word found in an input string by [`words`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Word<'a> {
+    /// The word as a slice of the input string.
+    pub text: &'a str,
+
+    /// The byte index of the beginning of the word in the input string.
+    pub start: usize,
+
+    /// The byte index of the end of the word in the input string.
+    pub end: usize,
+}
+
+impl<'a> Word<'a> {
+    /// Returns the byte range of the word in the input string.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl AsRef<str> for Word<'_> {
+    fn as_ref(&self) -> &str {
+        self.text
+    }
+}
+
+#[derive(PartialEq)]
+enum ChIs {
+    FirstOfStr,
+    NextOfUpper,
+    NextOfContdUpper,
+    NextOfSepMark,
+    NextOfKeptMark,
+    Other,
+}
+
+/// An iterator over the words of a string, which is created by [`words`].
+pub struct Words<'a, 'o> {
+    input: &'a str,
+    opts: &'o Options<'o>,
+    chars: CharIndices<'a>,
+    flag: ChIs,
+    in_word: bool,
+    start: usize,
+    end: usize,
+    last_upper: usize,
+    run_start: usize,
+    run_end: usize,
+    run_mask: u128,
+    acronym_end: usize,
+}
+
+/// Splits a string into words with the same rules as the case conversion functions.
+///
+/// It processes the input string `input`, identifies word boundaries based on character casing and
+/// non-alphabetic character rules defined in `opts`, and yields each word as a [`Word`] which has
+/// the slice of `input` and its byte range in `input`.
+/// Characters treated as word separators are not contained in any word.
+/// If `opts.acronyms` is not empty, a sequence of uppercase letters which consists only of the
+/// acronyms is split into each acronym.
+/// If a sequence can be split in more than one way, the longest acronym is taken at each position
+/// as long as the rest can be split.
+/// A sequence of 128 bytes or longer is split only by taking the longest acronym at each position.
+///
+/// The words are the same as the words which functions `capitalize`, `lowerize`, `upperize`, and
+/// all `〜_case_with_options` functions capitalize, lowercase, uppercase, and join.
+///
+/// # Parameters
+///
+/// - `input`: The target string slice (`&str`) to be split.
+/// - `opts`: A reference to [`Options`] defining separator rules, retained characters, and boundary
+///   behaviors.
+///
+/// # Returns
+///
+/// - Returns a [`Words`] iterator yielding the words in `input`.
+///
+/// # Examples
+///
+/// ```rust
+/// use stringcase::{words, Options};
+///
+/// let opts = Options::default();
+/// let result: Vec<&str> = words("fooBar123_HTTPServer", &opts).map(|w| w.text).collect();
+/// assert_eq!(result, ["foo", "Bar123", "HTTP", "Server"]);
+///
+/// let ranges: Vec<_> = words("foo-bar", &opts).map(|w| w.range()).collect();
+/// assert_eq!(ranges, [0..3, 4..7]);
+/// ```
+pub fn words<'a, 'o>(input: &'a str, opts: &'o Options<'o>) -> Words<'a, 'o> {
+    Words {
+        input,
+        opts,
+        chars: input.char_indices(),
+        flag: ChIs::FirstOfStr,
+        in_word: false,
+        start: 0,
+        end: 0,
+        last_upper: 0,
+        run_start: 0,
+        run_end: 0,
+        run_mask: 0,
+        acronym_end: 0,
+    }
+}
+
+impl<'a> Words<'a, '_> {
+    #[inline(always)]
+    fn word(&self, start: usize, end: usize) -> Word<'a> {
+        Word {
+            text: &self.input[start..end],
+            start,
+            end,
+        }
+    }
+
+    // Starts a new word and returns the previous word if exists.
+    #[inline(always)]
+    fn begin(&mut self, start: usize, end: usize) -> Option<Word<'a>> {
+        let prev = if self.in_word {
+            Some(self.word(self.start, self.end))
+        } else {
+            None
+        };
+        self.in_word = true;
+        self.start = start;
+        self.end = end;
+        prev
+    }
+
+    #[inline(always)]
+    fn extend(&mut self, start: usize, end: usize) -> Option<Word<'a>> {
+        if self.in_word {
+            self.end = end;
+            None
+        } else {
+            self.begin(start, end)
+        }
+    }
+
+    // Checks whether the sequence of uppercase letters starting at `start` consists only of
+    // acronyms, and if so, prepares to split it into each acronym.
+    // The last uppercase letter followed by a lowercase letter is not a part of the sequence
+    // because it is the head of the next word.
+    fn begin_upper_run(&mut self, start: usize) {
+        self.run_end = 0;
+        let opts = self.opts;
+        if opts.acronyms.is_empty() {
+            return;
+        }
+
+        let unicode = opts.unicode;
+        let mut end = start;
+        for (k, ch) in self.input[start..].char_indices() {
+            if is_upper(ch, unicode) {
+                end = start + k + ch.len_utf8();
+            } else {
+                if is_lower(ch, unicode) {
+                    end = self.input[..start + k]
+                        .char_indices()
+                        .next_back()
+                        .map_or(start, |(k, _)| k);
+                }
+                break;
+            }
+        }
+        if end <= start {
+            return;
+        }
+
+        let run = &self.input[start..end];
+        let mask = if run.len() < 128 {
+            // Each bit of `mask` indicates whether the sequence can be split into acronyms from
+            // the position.
+            let mut mask: u128 = 1 << run.len();
+            for (k, _) in run.char_indices().rev() {
+                for acronym in opts.acronyms {
+                    if let Some(n) = match_acronym(&run[k..], acronym, unicode) {
+                        if mask & (1 << (k + n)) != 0 {
+                            mask |= 1 << k;
+                            break;
+                        }
+                    }
+                }
+            }
+            mask
+        } else {
+            // A sequence which does not fit in the mask is split by the longest acronym at each
+            // position, and `mask` is `1` if the whole sequence is split so.
+            let mut k = 0;
+            while k < run.len() {
+                match longest_acronym(&run[k..], opts) {
+                    0 => break,
+                    n => k += n,
+                }
+            }
+            (k == run.len()) as u128
+        };
+
+        if mask & 1 != 0 {
+            self.run_start = start;
+            self.run_end = end;
+            self.run_mask = mask;
+            self.acronym_end = self.next_acronym_end(start);
+        }
+    }
+
+    // Returns the end of the longest acronym starting at `start` in the current sequence of
+    // uppercase letters.
+    fn next_acronym_end(&self, start: usize) -> usize {
+        let run = &self.input[start..self.run_end];
+        if self.run_end - self.run_start >= 128 {
+            return start + longest_acronym(run, self.opts);
+        }
+        let offset = start - self.run_start;
+        let mut len = 0;
+        for acronym in self.opts.acronyms {
+            if let Some(n) = match_acronym(run, acronym, self.opts.unicode) {
+                if n > len && self.run_mask & (1 << (offset + n)) != 0 {
+                    len = n;
+                }
+            }
+        }
+        start + len
+    }
+
+    #[inline(always)]
+    fn finish(&mut self) -> Option<Word<'a>> {
+        if self.in_word {
+            self.in_word = false;
+            Some(self.word(self.start, self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Iterator for Words<'a, '_> {
+    type Item = Word<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let opts = self.opts;
+        let unicode = opts.unicode;
+
+        while let Some((i, ch)) = self.chars.next() {
+            let j = i + ch.len_utf8();
+            let word;
+
+            if is_upper(ch, unicode) {
+                if i == self.acronym_end && i < self.run_end {
+                    word = self.begin(i, j);
+                    self.acronym_end = self.next_acronym_end(i);
+                    self.flag = ChIs::NextOfUpper;
+                } else if self.flag == ChIs::FirstOfStr {
+                    word = self.begin(i, j);
+                    self.begin_upper_run(i);
+                    self.flag = ChIs::NextOfUpper;
+                } else if self.flag == ChIs::NextOfUpper
+                    || self.flag == ChIs::NextOfContdUpper
+                    || (!opts.separate_after_non_alphabets && self.flag == ChIs::NextOfKeptMark)
+                {
+                    word = self.extend(i, j);
+                    self.last_upper = i;
+                    self.flag = ChIs::NextOfContdUpper;
+                } else {
+                    word = self.begin(i, j);
+                    self.begin_upper_run(i);
+                    self.flag = ChIs::NextOfUpper;
+                }
+            } else if is_lower(ch, unicode) {
+                if self.flag == ChIs::NextOfContdUpper {
+                    self.end = self.last_upper;
+                    word = self.begin(self.last_upper, j);
+                } else if self.flag == ChIs::NextOfSepMark
+                    || (opts.separate_after_non_alphabets && self.flag == ChIs::NextOfKeptMark)
+                {
+                    word = self.begin(i, j);
+                } else {
+                    word = self.extend(i, j);
+                }
+                self.flag = ChIs::Other;
+            } else {
+                let mut is_kept_char = false;
+                if is_digit(ch, unicode) {
+                    is_kept_char = true;
+                } else if !opts.separators.is_empty() {
+                    if !opts.separators.contains(ch) {
+                        is_kept_char = true;
+                    }
+                } else if !opts.keep.is_empty() {
+                    #[allow(clippy::collapsible_if)]
+                    if opts.keep.contains(ch) {
+                        is_kept_char = true;
+                    }
+                }
+
+                if is_kept_char {
+                    if opts.separate_before_non_alphabets {
+                        if self.flag == ChIs::FirstOfStr || self.flag == ChIs::NextOfKeptMark {
+                            word = self.extend(i, j);
+                        } else {
+                            word = self.begin(i, j);
+                        }
+                    } else {
+                        if self.flag != ChIs::NextOfSepMark {
+                            word = self.extend(i, j);
+                        } else {
+                            word = self.begin(i, j);
+                        }
+                    }
+                    self.flag = ChIs::NextOfKeptMark;
+                } else {
+                    word = self.finish();
+                    if self.flag != ChIs::FirstOfStr {
+                        self.flag = ChIs::NextOfSepMark;
+                    }
+                }
+            }
+
+            if word.is_some() {
+                return word;
+            }
+        }
+
+        self.finish()
+    }
+}
+
+// Returns the byte length of the head of `s` which is equal to `acronym` ignoring case.
+pub(crate) fn match_acronym(s: &str, acronym: &str, unicode: bool) -> Option<usize> {
+    if acronym.is_empty() {
+        return None;
+    }
+    let mut chars = s.char_indices();
+    for a in acronym.chars() {
+        match chars.next() {
+            Some((_, ch)) if eq_ignore_case(ch, a, unicode) => {}
+            _ => return None,
+        }
+    }
+    Some(chars.next().map_or(s.len(), |(k, _)| k))
+}
+
+#[inline(always)]
+fn eq_ignore_case(a: char, b: char, unicode: bool) -> bool {
+    if a.is_ascii() || b.is_ascii() || !unicode {
+        a.eq_ignore_ascii_case(&b)
+    } else {
+        a == b || a.to_lowercase().eq(b.to_lowercase())
+    }
+}
+
+// Returns the byte length of the longest acronym of `opts.acronyms` at the head of `s`, or `0`
+// if no acronym matches.
+fn longest_acronym(s: &str, opts: &Options) -> usize {
+    opts.acronyms
+        .iter()
+        .filter_map(|acronym| match_acronym(s, acronym, opts.unicode))
+        .max()
+        .unwrap_or(0)
+}
+
+// Checks whether `word` is equal to one of `opts.acronyms` ignoring case.
+pub(crate) fn is_acronym(word: &str, opts: &Options) -> bool {
+    opts.acronyms
+        .iter()
+        .any(|a| match_acronym(word, a, opts.unicode) == Some(word.len()))
+}
+
+pub(crate) fn push_lower_word<S: Sink>(s: &mut S, word: &str, unicode: bool) {
+    for ch in word.chars() {
+        if is_upper(ch, unicode) {
+            push_lower(s, ch, unicode);
+        } else {
+            s.push(ch);
+        }
+    }
+}
+
+pub(crate) fn push_upper_word<S: Sink>(s: &mut S, word: &str, unicode: bool) {
+    for ch in word.chars() {
+        if is_lower(ch, unicode) {
+            push_upper(s, ch, unicode);
+        } else {
+            s.push(ch);
+        }
+    }
+}
+
+// Pushes `word` with its head letter uppercased and other letters lowercased, or with all
+// letters uppercased if `word` is an acronym and `uppercase_acronyms` is `true`.
+pub(crate) fn push_capitalized_word<S: Sink>(
+    s: &mut S,
+    word: &str,
+    opts: &Options,
+    uppercase_acronyms: bool,
+) {
+    let unicode = opts.unicode;
+    if uppercase_acronyms && is_acronym(word, opts) {
+        push_upper_word(s, word, unicode);
+        return;
+    }
+    let mut chars = word.chars();
+    if let Some(ch) = chars.next() {
+        if is_lower(ch, unicode) {
+            push_upper(s, ch, unicode);
+        } else {
+            s.push(ch);
+        }
+    }
+    for ch in chars {
+        if is_upper(ch, unicode) {
+            push_lower(s, ch, unicode);
+        } else {
+            s.push(ch);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests_of_words {
+    use super::*;
+    use crate::capitalize::capitalize;
+    use crate::lowerize::lowerize;
+    use crate::upperize::upperize;
+
+    fn texts<'a>(input: &'a str, opts: &Options) -> Vec<&'a str> {
+        words(input, opts).map(|w| w.text).collect()
+    }
+
+    fn capitalize_word(word: &str) -> String {
+        let mut s = String::with_capacity(word.len());
+        for (i, ch) in word.chars().enumerate() {
+            if i == 0 {
+                s.push(ch.to_ascii_uppercase());
+            } else {
+                s.push(ch.to_ascii_lowercase());
+            }
+        }
+        s
+    }
+
+    #[test]
+    fn split_camel_case() {
+        let opts = Options::default();
+        assert_eq!(texts("abcDefGHIjk", &opts), ["abc", "Def", "GH", "Ijk"]);
+    }
+
+    #[test]
+    fn split_pascal_case() {
+        let opts = Options::default();
+        assert_eq!(texts("AbcDefGHIjk", &opts), ["Abc", "Def", "GH", "Ijk"]);
+    }
+
+    #[test]
+    fn split_snake_case_and_macro_case() {
+        let opts = Options::default();
+        assert_eq!(texts("abc_def_ghi", &opts), ["abc", "def", "ghi"]);
+        assert_eq!(texts("ABC_DEF_GHI", &opts), ["ABC", "DEF", "GHI"]);
+    }
+
+    #[test]
+    fn split_with_non_alphabets_as_tail_of_a_word() {
+        let opts = Options::default();
+        assert_eq!(
+            texts("abc123-456defG89HIJklMN12", &opts),
+            ["abc123", "456", "def", "G89", "HI", "Jkl", "MN12"]
+        );
+    }
+
+    #[test]
+    fn split_with_non_alphabets_as_head_of_a_word() {
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            separate_after_non_alphabets: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            texts("abc123-456defG89HIJklMN12", &opts),
+            ["abc", "123", "456def", "G", "89HI", "Jkl", "MN", "12"]
+        );
+    }
+
+    #[test]
+    fn split_with_separators() {
+        let opts = Options {
+            separators: "-",
+            ..Default::default()
+        };
+        assert_eq!(texts("abc_def-ghi", &opts), ["abc_", "def", "ghi"]);
+    }
+
+    #[test]
+    fn split_with_kept_characters() {
+        let opts = Options {
+            keep: "%",
+            ..Default::default()
+        };
+        assert_eq!(texts("abc%def-ghi", &opts), ["abc%", "def", "ghi"]);
+    }
+
+    #[test]
+    fn split_an_empty_string_and_only_separators() {
+        let opts = Options::default();
+        assert!(texts("", &opts).is_empty());
+        assert!(texts("-_ .", &opts).is_empty());
+    }
+
+    #[test]
+    fn split_with_unicode_letters() {
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        assert_eq!(texts("straßeÜberweisung", &opts), ["straße", "Überweisung"]);
+        assert_eq!(texts("ÉTATCivil", &opts), ["ÉTAT", "Civil"]);
+    }
+
+    #[test]
+    fn get_byte_ranges_of_words() {
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        let input = "__Über-XMLHttp";
+        let result: Vec<Word> = words(input, &opts).collect();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].range(), 2..7);
+        assert_eq!(result[1].range(), 8..11);
+        assert_eq!(result[2].range(), 11..15);
+        for w in result {
+            assert_eq!(&input[w.range()], w.text);
+        }
+    }
+
+    #[test]
+    fn split_into_same_words_as_conversion_functions() {
+        let inputs = [
+            "abcDefGHIjk",
+            "AbcDefGHIjk",
+            "abc_def_ghi",
+            "ABC-DEF-GHI",
+            "abc123-456defG89HIJklMN12",
+            ":.abc~!@def#$ghi%&jk(lm)no/?",
+            "123abc456def",
+            "123ABC456DEF",
+            "123Abc456Def",
+            "_-%abc__DEF%%ghi-",
+            "",
+        ];
+        let flags = [(false, false), (false, true), (true, false), (true, true)];
+        let seps_and_keeps = [("", ""), ("-_", ""), ("", "%#@"), ("-", "_")];
+
+        for input in inputs {
+            for (before, after) in flags {
+                for (seps, keep) in seps_and_keeps {
+                    let opts = Options {
+                        separate_before_non_alphabets: before,
+                        separate_after_non_alphabets: after,
+                        separators: seps,
+                        keep,
+                        ..Default::default()
+                    };
+                    let ws = texts(input, &opts);
+
+                    let lower: Vec<String> = ws.iter().map(|w| w.to_ascii_lowercase()).collect();
+                    assert_eq!(lower.join("."), lowerize::<'.'>(input, &opts));
+
+                    let upper: Vec<String> = ws.iter().map(|w| w.to_ascii_uppercase()).collect();
+                    assert_eq!(upper.join("."), upperize::<'.'>(input, &opts));
+
+                    let capital: Vec<String> = ws.iter().map(|w| capitalize_word(w)).collect();
+                    assert_eq!(capital.join("."), capitalize::<'.'>(input, &opts));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn split_with_acronyms() {
+        let opts = Options {
+            acronyms: &["HTTP", "HTTPS", "ID", "URL", "XML"],
+            ..Default::default()
+        };
+        assert_eq!(texts("XMLHTTPRequest", &opts), ["XML", "HTTP", "Request"]);
+        assert_eq!(texts("getHTTPSURL", &opts), ["get", "HTTPS", "URL"]);
+        assert_eq!(texts("HTTPServerID", &opts), ["HTTP", "Server", "ID"]);
+        assert_eq!(texts("http_server_id", &opts), ["http", "server", "id"]);
+    }
+
+    #[test]
+    fn not_split_uppercase_letters_not_consisting_only_of_acronyms() {
+        let opts = Options {
+            acronyms: &["ID", "AL"],
+            ..Default::default()
+        };
+        assert_eq!(texts("IDENTITY_VALID", &opts), ["IDENTITY", "VALID"]);
+        assert_eq!(texts("VALIDity", &opts), ["VALI", "Dity"]);
+    }
+
+    #[test]
+    fn split_with_acronyms_and_non_alphabets() {
+        let opts = Options {
+            acronyms: &["HTTP", "API"],
+            ..Default::default()
+        };
+        assert_eq!(texts("APIHTTP2Server", &opts), ["API", "HTTP2", "Server"]);
+
+        let opts = Options {
+            separate_before_non_alphabets: true,
+            acronyms: &["HTTP", "API"],
+            ..Default::default()
+        };
+        assert_eq!(
+            texts("APIHTTP2Server", &opts),
+            ["API", "HTTP", "2", "Server"]
+        );
+    }
+
+    #[test]
+    fn split_with_unicode_acronyms() {
+        let opts = Options {
+            unicode: true,
+            acronyms: &["éé", "ÜB"],
+            ..Default::default()
+        };
+        assert_eq!(texts("ÉÉÜBVerb", &opts), ["ÉÉ", "ÜB", "Verb"]);
+    }
+
+    #[test]
+    fn split_long_uppercase_letters_with_acronyms() {
+        let opts = Options {
+            acronyms: &["HTTP", "ID", "XML"],
+            ..Default::default()
+        };
+        for n in [31, 32, 33, 40] {
+            let input = alloc::format!("{}Server", "HTTP".repeat(n));
+            let ws = texts(&input, &opts);
+            assert_eq!(ws.len(), n + 1, "{}", n);
+            assert!(ws[..n].iter().all(|w| *w == "HTTP"));
+            assert_eq!(ws[n], "Server");
+        }
+
+        let input = alloc::format!("{}XMLID", "HTTP".repeat(40));
+        let ws = texts(&input, &opts);
+        assert_eq!(ws.len(), 42);
+        assert_eq!(ws[40..], ["XML", "ID"]);
+
+        let input = alloc::format!("{}X", "HTTP".repeat(40));
+        assert_eq!(texts(&input, &opts), [input.as_str()]);
+    }
+}
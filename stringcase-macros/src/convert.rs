@@ -0,0 +1,130 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use stringcase::{convert, Case, Options};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::{Error, Ident, LitStr, Token};
+
+// A name which is a string literal or an identifier.
+pub(crate) struct Name {
+    value: String,
+    span: Span,
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(LitStr) {
+            let lit: LitStr = input.parse()?;
+            Ok(Self {
+                value: lit.value(),
+                span: lit.span(),
+            })
+        } else if input.peek(Ident::peek_any) {
+            let ident = Ident::parse_any(input)?;
+            Ok(Self {
+                value: ident.unraw().to_string(),
+                span: ident.span(),
+            })
+        } else {
+            Err(input.error("expected a string literal or an identifier"))
+        }
+    }
+}
+
+// The input of `const_assert_case!`, which is a case style and a name.
+pub(crate) struct CaseAndName {
+    case: Case,
+    name: Name,
+}
+
+impl Parse for CaseAndName {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let case_name: Name = input.parse()?;
+        let case = case_name
+            .value
+            .parse::<Case>()
+            .map_err(|e| Error::new(case_name.span, e))?;
+        input.parse::<Token![,]>()?;
+        let name: Name = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { case, name })
+    }
+}
+
+// Parses a name with an optional trailing comma.
+fn parse_name(input: ParseStream) -> syn::Result<Name> {
+    let name: Name = input.parse()?;
+    input.parse::<Option<Token![,]>>()?;
+    Ok(name)
+}
+
+// Expands the conversion macros like `snake_case!` into a string literal.
+pub(crate) fn expand_case(input: TokenStream, case: Case) -> syn::Result<TokenStream> {
+    let name = syn::parse::Parser::parse2(parse_name, input)?;
+    let converted = convert(&name.value, case, &Options::default());
+    Ok(LitStr::new(&converted, name.span).into_token_stream())
+}
+
+// Expands `const_assert_case!` into nothing if the name is in the case style, or a compile error.
+pub(crate) fn expand_assert(input: CaseAndName) -> syn::Result<TokenStream> {
+    let CaseAndName { case, name } = input;
+    let converted = convert(&name.value, case, &Options::default());
+    if converted != name.value {
+        return Err(Error::new(
+            name.span,
+            format!(
+                "{:?} is not in {}, which would be {:?}",
+                name.value, case, converted
+            ),
+        ));
+    }
+    Ok(quote! { const _: () = (); })
+}
+
+#[cfg(test)]
+mod tests_of_convert {
+    use super::*;
+
+    #[test]
+    fn test_of_expand_case() {
+        let ts = expand_case(quote! { "UserAccount" }, Case::Snake).unwrap();
+        assert_eq!(ts.to_string(), "\"user_account\"");
+
+        let ts = expand_case(quote! { UserAccount, }, Case::Kebab).unwrap();
+        assert_eq!(ts.to_string(), "\"user-account\"");
+
+        let ts = expand_case(quote! { r#type }, Case::Macro).unwrap();
+        assert_eq!(ts.to_string(), "\"TYPE\"");
+
+        let err = expand_case(quote! { 123 }, Case::Snake).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "expected a string literal or an identifier"
+        );
+
+        assert!(expand_case(quote! { "a" "b" }, Case::Snake).is_err());
+    }
+
+    #[test]
+    fn test_of_expand_assert() {
+        let input: CaseAndName = syn::parse2(quote! { snake, "user_account" }).unwrap();
+        let ts = expand_assert(input).unwrap();
+        assert_eq!(ts.to_string(), "const _ : () = () ;");
+
+        let input: CaseAndName = syn::parse2(quote! { "kebab-case", user_account }).unwrap();
+        let err = expand_assert(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"user_account\" is not in kebab-case, which would be \"user-account\""
+        );
+
+        let err = syn::parse2::<CaseAndName>(quote! { sponge, "a" })
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "unknown case style: \"sponge\"");
+    }
+}
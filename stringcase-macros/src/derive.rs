@@ -0,0 +1,253 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use stringcase::{convert, macro_case, snake_case, Case, Options};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr};
+
+// Expands `#[derive(StringCase)]`.
+pub(crate) fn expand(input: DeriveInput) -> syn::Result<TokenStream> {
+    let (idents, kind) = match &input.data {
+        Data::Enum(data) => (
+            data.variants.iter().map(|v| v.ident.clone()).collect(),
+            "variants",
+        ),
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => (
+                fields
+                    .named
+                    .iter()
+                    .filter_map(|f| f.ident.clone())
+                    .collect::<Vec<Ident>>(),
+                "fields",
+            ),
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "StringCase can only be derived for enums and structs with named fields",
+                ))
+            }
+        },
+        Data::Union(_) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "StringCase cannot be derived for unions",
+            ))
+        }
+    };
+
+    let opts = Options::default();
+    let names: Vec<String> = idents.iter().map(|i| i.unraw().to_string()).collect();
+    let converted: Vec<Vec<String>> = Case::ALL
+        .iter()
+        .map(|case| names.iter().map(|n| convert(n, *case, &opts)).collect())
+        .collect();
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let len = names.len();
+
+    let consts = Case::ALL.iter().zip(&converted).map(|(case, names)| {
+        let ident = const_ident(*case);
+        let doc = format!("The names of the {} of `{}` in {}.", kind, ty, case);
+        quote! {
+            #[doc = #doc]
+            pub const #ident: [&'static str; #len] = [#(#names),*];
+        }
+    });
+
+    let arms = Case::ALL.iter().zip(&converted).map(|(case, names)| {
+        let variant = case_variant(*case);
+        quote! { ::stringcase::Case::#variant => &[#(#names),*], }
+    });
+    let case_names_doc = format!(
+        "Returns the names of the {} of `{}` in the specified case style, or an empty slice if \
+         the case style is newer than this derive.",
+        kind, ty
+    );
+    let case_names = quote! {
+        #[doc = #case_names_doc]
+        pub fn case_names(case: ::stringcase::Case) -> &'static [&'static str] {
+            match case {
+                #(#arms)*
+                _ => &[],
+            }
+        }
+    };
+
+    let enum_items = match &input.data {
+        Data::Enum(data) => {
+            let variants: Vec<&Ident> = data.variants.iter().map(|v| &v.ident).collect();
+            let methods = Case::ALL.iter().zip(&converted).map(|(case, names)| {
+                let method = method_ident(*case);
+                let doc = format!("Returns the name of this variant in {}.", case);
+                quote! {
+                    #[doc = #doc]
+                    pub const fn #method(&self) -> &'static str {
+                        match *self {
+                            #(Self::#variants { .. } => #names,)*
+                        }
+                    }
+                }
+            });
+            let arms = Case::ALL.iter().map(|case| {
+                let variant = case_variant(*case);
+                let method = method_ident(*case);
+                quote! { ::stringcase::Case::#variant => self.#method(), }
+            });
+            quote! {
+                #(#methods)*
+
+                /// Returns the name of this variant in the specified case style, or an empty
+                /// string if the case style is newer than this derive.
+                pub const fn as_case(&self, case: ::stringcase::Case) -> &'static str {
+                    match case {
+                        #(#arms)*
+                        _ => "",
+                    }
+                }
+            }
+        }
+        _ => TokenStream::new(),
+    };
+
+    let from_str = match &input.data {
+        Data::Enum(data) if data.variants.iter().all(|v| v.fields.is_empty()) => {
+            from_str_impl(&input, &names, &converted)?
+        }
+        _ => TokenStream::new(),
+    };
+
+    Ok(quote! {
+        impl #impl_generics #ty #ty_generics #where_clause {
+            #(#consts)*
+
+            #case_names
+
+            #enum_items
+        }
+
+        #from_str
+    })
+}
+
+// Generates a `FromStr` implementation which accepts the names of the variants in any case style.
+fn from_str_impl(
+    input: &DeriveInput,
+    names: &[String],
+    converted: &[Vec<String>],
+) -> syn::Result<TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => unreachable!(),
+    };
+
+    // Each spelling is accepted for only one variant, so that parsing is unambiguous.
+    let mut spellings: Vec<(String, usize)> = Vec::new();
+    let mut arms = Vec::new();
+    for (i, variant) in data.variants.iter().enumerate() {
+        let mut own: Vec<String> = Vec::new();
+        let candidates = std::iter::once(&names[i]).chain(converted.iter().map(|names| &names[i]));
+        for spelling in candidates {
+            if own.contains(spelling) {
+                continue;
+            }
+            if let Some((_, j)) = spellings.iter().find(|(s, _)| s == spelling) {
+                return Err(Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "`{}` is a name of both the variants `{}` and `{}`",
+                        spelling, names[*j], names[i]
+                    ),
+                ));
+            }
+            spellings.push((spelling.clone(), i));
+            own.push(spelling.clone());
+        }
+        let ident = &variant.ident;
+        arms.push(quote! { #(#own)|* => ::core::result::Result::Ok(Self::#ident), });
+    }
+
+    let ty = &input.ident;
+    let type_name = LitStr::new(&ty.unraw().to_string(), Span::call_site());
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::core::str::FromStr for #ty #ty_generics #where_clause {
+            type Err = ::stringcase::ParseVariantError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    #(#arms)*
+                    _ => ::core::result::Result::Err(
+                        ::stringcase::ParseVariantError::new(#type_name, s)
+                    ),
+                }
+            }
+        }
+    })
+}
+
+// Returns the name of the constant of the names in a case style, like `SNAKE_CASE_NAMES`.
+fn const_ident(case: Case) -> Ident {
+    format_ident!("{}_NAMES", macro_case(case.name()))
+}
+
+// Returns the name of the method which returns a name in a case style, like `as_snake_case`.
+fn method_ident(case: Case) -> Ident {
+    format_ident!("as_{}", snake_case(case.name()))
+}
+
+// Returns the variant name of `Case` for a case style.
+fn case_variant(case: Case) -> Ident {
+    Ident::new(&format!("{:?}", case), Span::call_site())
+}
+
+#[cfg(test)]
+mod tests_of_derive {
+    use super::*;
+
+    #[test]
+    fn test_of_const_ident() {
+        assert_eq!(const_ident(Case::Snake), "SNAKE_CASE_NAMES");
+        assert_eq!(const_ident(Case::Cobol), "COBOL_CASE_NAMES");
+        assert_eq!(const_ident(Case::Title), "TITLE_CASE_NAMES");
+    }
+
+    #[test]
+    fn test_of_method_ident() {
+        assert_eq!(method_ident(Case::Snake), "as_snake_case");
+        assert_eq!(method_ident(Case::Cobol), "as_cobol_case");
+        assert_eq!(method_ident(Case::Title), "as_title_case");
+    }
+
+    #[test]
+    fn test_of_case_variant() {
+        assert_eq!(case_variant(Case::Ada), "Ada");
+        assert_eq!(case_variant(Case::Train), "Train");
+    }
+
+    #[test]
+    fn fail_to_derive_for_tuple_structs() {
+        let input: DeriveInput = syn::parse_quote! { struct Foo(u8); };
+        let err = expand(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "StringCase can only be derived for enums and structs with named fields"
+        );
+    }
+
+    #[test]
+    fn fail_to_derive_for_ambiguous_variants() {
+        let input: DeriveInput = syn::parse_quote! {
+            enum Foo { FooBar, Foo_Bar }
+        };
+        let err = expand(input).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`Foo_Bar` is a name of both the variants `FooBar` and `Foo_Bar`"
+        );
+    }
+}
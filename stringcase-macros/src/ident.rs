@@ -0,0 +1,206 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+use proc_macro2::{Delimiter, Group, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use stringcase::syn::new_ident;
+use stringcase::{convert, Case, Options};
+use syn::ext::IdentExt;
+use syn::parse::{ParseStream, Parser};
+use syn::{Error, Ident, Lit, Token};
+
+// Expands `ident!` into an identifier.
+pub(crate) fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    Parser::parse2(parse, input)
+}
+
+// Expands `with_idents!` by replacing every `ident!(...)` in the input with an identifier.
+pub(crate) fn expand_all(input: TokenStream) -> syn::Result<TokenStream> {
+    let mut output = TokenStream::new();
+    let mut tokens = input.into_iter().peekable();
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Ident(ref ident) if ident == "ident" => {
+                let is_bang =
+                    matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '!');
+                if !is_bang {
+                    output.extend(Some(tt));
+                    continue;
+                }
+                let bang = tokens.next();
+                match tokens.next() {
+                    Some(TokenTree::Group(group))
+                        if group.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        output.extend(expand(group.stream())?);
+                    }
+                    next => {
+                        output.extend(Some(tt));
+                        output.extend(bang);
+                        output.extend(next);
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(group.delimiter(), expand_all(group.stream())?);
+                new_group.set_span(group.span());
+                output.extend(Some(TokenTree::Group(new_group)));
+            }
+            tt => output.extend(Some(tt)),
+        }
+    }
+    Ok(output)
+}
+
+fn parse(input: ParseStream) -> syn::Result<TokenStream> {
+    let case_ident = Ident::parse_any(input)?;
+    let case = case_ident
+        .unraw()
+        .to_string()
+        .parse::<Case>()
+        .map_err(|e| Error::new(case_ident.span(), e))?;
+
+    let mut name = String::new();
+    let mut span = None;
+    while !input.is_empty() {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+        let tt: TokenTree = input.parse()?;
+        push_segment(&mut name, &mut span, tt)?;
+    }
+    if name.is_empty() {
+        return Err(input.error("expected identifiers or literals to be concatenated"));
+    }
+
+    let converted = convert(&name, case, &Options::default());
+    let span = span.unwrap_or_else(Span::call_site);
+    let ident = new_ident(&converted, span)?;
+    Ok(ident.into_token_stream())
+}
+
+// Appends a token to the name, and keeps the span of the first identifier.
+fn push_segment(name: &mut String, span: &mut Option<Span>, tt: TokenTree) -> syn::Result<()> {
+    match tt {
+        TokenTree::Ident(ident) => {
+            if span.is_none() {
+                *span = Some(ident.span());
+            }
+            name.push_str(&ident.unraw().to_string());
+        }
+        TokenTree::Literal(lit) => match Lit::new(lit) {
+            Lit::Str(s) => name.push_str(&s.value()),
+            Lit::Int(i) => name.push_str(i.base10_digits()),
+            Lit::Char(c) => name.push(c.value()),
+            Lit::Bool(b) => name.push_str(if b.value { "true" } else { "false" }),
+            lit => return Err(Error::new(lit.span(), "unsupported literal")),
+        },
+        // A fragment of `macro_rules!`, like `$name:ident`, can be in an invisible group.
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+            for tt in group.stream() {
+                push_segment(name, span, tt)?;
+            }
+        }
+        tt => return Err(Error::new(tt.span(), "expected an identifier or a literal")),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests_of_ident {
+    use super::*;
+    use quote::quote;
+
+    fn expand_to_string(input: TokenStream) -> String {
+        match expand(input) {
+            Ok(ts) => ts.to_string(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn concatenate_and_convert() {
+        assert_eq!(
+            expand_to_string(quote! { snake, get_, UserId }),
+            "get_user_id"
+        );
+        assert_eq!(
+            expand_to_string(quote! { pascal, user_id, Builder }),
+            "UserIdBuilder"
+        );
+        assert_eq!(
+            expand_to_string(quote! { macro, max_, "retry", 3, }),
+            "MAX_RETRY3"
+        );
+        assert_eq!(
+            expand_to_string(quote! { camel, r#type, _name }),
+            "typeName"
+        );
+    }
+
+    #[test]
+    fn escape_keywords() {
+        assert_eq!(expand_to_string(quote! { snake, Type }), "r#type");
+        assert_eq!(expand_to_string(quote! { camel, "FN" }), "r#fn");
+        assert_eq!(
+            expand_to_string(quote! { snake, Self_ }),
+            "`self` cannot be a raw identifier"
+        );
+    }
+
+    #[test]
+    fn replace_all_idents() {
+        let ts = expand_all(quote! {
+            struct ident!(pascal, user_id, Builder);
+            impl ident!(pascal, user_id, Builder) {
+                fn ident!(snake, get_, UserId)(&self) -> [u8; 2] { [ident!(macro, max), 1] }
+            }
+            ident!{ not_replaced }
+        })
+        .unwrap();
+        assert_eq!(
+            ts.to_string(),
+            quote! {
+                struct UserIdBuilder;
+                impl UserIdBuilder {
+                    fn get_user_id(&self) -> [u8; 2] { [MAX, 1] }
+                }
+                ident!{ not_replaced }
+            }
+            .to_string()
+        );
+
+        let err = expand_all(quote! { fn ident!(kebab, foo_, bar)() {} }).unwrap_err();
+        assert_eq!(err.to_string(), "`foo-bar` is not a valid identifier");
+    }
+
+    #[test]
+    fn fail_with_invalid_input() {
+        assert_eq!(
+            expand_to_string(quote! { kebab, foo_, bar }),
+            "`foo-bar` is not a valid identifier"
+        );
+        assert_eq!(
+            expand_to_string(quote! { snake, 1, foo }),
+            "`1_foo` is not a valid identifier"
+        );
+        assert_eq!(
+            expand_to_string(quote! { sponge, foo }),
+            "unknown case style: \"sponge\""
+        );
+        assert_eq!(
+            expand_to_string(quote! { snake }),
+            "unexpected end of input, expected identifiers or literals to be concatenated"
+        );
+        assert_eq!(
+            expand_to_string(quote! { snake, foo + bar }),
+            "expected `,`"
+        );
+        assert_eq!(
+            expand_to_string(quote! { snake, [foo] }),
+            "expected an identifier or a literal"
+        );
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright (C) 2026 Takayuki Sato. All Rights Reserved.
+// This program is free software under MIT License.
+// See the file LICENSE in this distribution for more details.
+
+//! This crate provides procedural macros of [stringcase](https://docs.rs/stringcase), which
+//! convert names into case styles at compile time with the same rules as the functions of
+//! stringcase.
+//!
+//! The code generated by these macros refers to the crate `stringcase`, so it is also needed as a
+//! dependency.
+//!
+//! ```toml
+//! [dependencies]
+//! stringcase = "0.5.0"
+//! stringcase-macros = "0.5.0"
+//! ```
+//!
+//! All macros convert names with `Options::default()` of stringcase, so their results are the same
+//! as the results of the functions without options, like `stringcase::snake_case`.
+//! Since the `unicode` field of the default options is `false`, non-ASCII letters are not treated
+//! as letters of words, as with those functions.
+
+use proc_macro::TokenStream;
+use stringcase::Case;
+use syn::{parse_macro_input, DeriveInput};
+
+mod convert;
+mod derive;
+mod ident;
+
+/// Derives the names of the variants of an enum, or the names of the fields of a struct, in all
+/// case styles supported by stringcase.
+///
+/// This macro generates the following items in an `impl` block of the type:
+///
+/// - The constants `ADA_CASE_NAMES`, `CAMEL_CASE_NAMES`, `COBOL_CASE_NAMES`, `KEBAB_CASE_NAMES`,
+///   `MACRO_CASE_NAMES`, `PASCAL_CASE_NAMES`, `SNAKE_CASE_NAMES`, `TITLE_CASE_NAMES`, and
+///   `TRAIN_CASE_NAMES`, which are arrays of the names in declaration order.
+/// - The function `case_names`, which returns the names in a `stringcase::Case`.
+///
+/// And for an enum:
+///
+/// - The methods `as_ada_case`, `as_camel_case`, ..., and `as_train_case`, which return the name
+///   of the variant in each case style.
+/// - The method `as_case`, which returns the name of the variant in a `stringcase::Case`.
+/// - If all variants are unit variants, an implementation of `FromStr` which accepts the original
+///   name of a variant and its names in all case styles.
+///   If a name is shared by two variants, this macro reports an error.
+///
+/// The names are converted with the default `Options` of stringcase, and the prefix `r#` of raw
+/// identifiers is removed.
+///
+/// ```rust
+/// use stringcase::Case;
+/// use stringcase_macros::StringCase;
+///
+/// #[derive(StringCase, Debug, PartialEq)]
+/// enum Status {
+///     InProgress,
+///     Done,
+/// }
+///
+/// #[derive(StringCase)]
+/// struct Config {
+///     max_retry_count: u32,
+///     r#type: String,
+/// }
+///
+/// fn main() {
+///     assert_eq!(Status::InProgress.as_kebab_case(), "in-progress");
+///     assert_eq!(Status::Done.as_case(Case::Macro), "DONE");
+///     assert_eq!(Status::SNAKE_CASE_NAMES, ["in_progress", "done"]);
+///     assert_eq!("IN-PROGRESS".parse(), Ok(Status::InProgress));
+///     assert_eq!("In Progress".parse::<Status>(), Ok(Status::InProgress));
+///     assert!("todo".parse::<Status>().is_err());
+///
+///     assert_eq!(Config::CAMEL_CASE_NAMES, ["maxRetryCount", "type"]);
+///     assert_eq!(Config::case_names(Case::Cobol), ["MAX-RETRY-COUNT", "TYPE"]);
+/// }
+/// ```
+#[proc_macro_derive(StringCase)]
+pub fn derive_string_case(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive::expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into Ada_Case at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::ada_case;
+///
+/// const NAME: &str = ada_case!("UserAccount");
+/// assert_eq!(NAME, "User_Account");
+/// assert_eq!(ada_case!(UserAccount), "User_Account");
+/// ```
+#[proc_macro]
+pub fn ada_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Ada)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into camelCase at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::camel_case;
+///
+/// const NAME: &str = camel_case!("UserAccount");
+/// assert_eq!(NAME, "userAccount");
+/// assert_eq!(camel_case!(UserAccount), "userAccount");
+/// ```
+#[proc_macro]
+pub fn camel_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Camel)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into COBOL-CASE at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::cobol_case;
+///
+/// const NAME: &str = cobol_case!("UserAccount");
+/// assert_eq!(NAME, "USER-ACCOUNT");
+/// assert_eq!(cobol_case!(UserAccount), "USER-ACCOUNT");
+/// ```
+#[proc_macro]
+pub fn cobol_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Cobol)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into kebab-case at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::kebab_case;
+///
+/// const NAME: &str = kebab_case!("UserAccount");
+/// assert_eq!(NAME, "user-account");
+/// assert_eq!(kebab_case!(UserAccount), "user-account");
+/// ```
+#[proc_macro]
+pub fn kebab_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Kebab)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into MACRO_CASE at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::macro_case;
+///
+/// const NAME: &str = macro_case!("UserAccount");
+/// assert_eq!(NAME, "USER_ACCOUNT");
+/// assert_eq!(macro_case!(UserAccount), "USER_ACCOUNT");
+/// ```
+#[proc_macro]
+pub fn macro_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Macro)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into PascalCase at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::pascal_case;
+///
+/// const NAME: &str = pascal_case!("user_account");
+/// assert_eq!(NAME, "UserAccount");
+/// assert_eq!(pascal_case!(user_account), "UserAccount");
+/// ```
+#[proc_macro]
+pub fn pascal_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Pascal)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into snake_case at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::snake_case;
+///
+/// const NAME: &str = snake_case!("UserAccount");
+/// assert_eq!(NAME, "user_account");
+/// assert_eq!(snake_case!(UserAccount), "user_account");
+/// ```
+#[proc_macro]
+pub fn snake_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Snake)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into Title Case at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::title_case;
+///
+/// const NAME: &str = title_case!("UserAccount");
+/// assert_eq!(NAME, "User Account");
+/// assert_eq!(title_case!(UserAccount), "User Account");
+/// ```
+#[proc_macro]
+pub fn title_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Title)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal or an identifier into Train-Case at compile time, and expands into a
+/// string literal.
+///
+/// ```rust
+/// use stringcase_macros::train_case;
+///
+/// const NAME: &str = train_case!("UserAccount");
+/// assert_eq!(NAME, "User-Account");
+/// assert_eq!(train_case!(UserAccount), "User-Account");
+/// ```
+#[proc_macro]
+pub fn train_case(input: TokenStream) -> TokenStream {
+    convert::expand_case(input.into(), Case::Train)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Asserts at compile time that a string literal or an identifier is in a case style.
+///
+/// The first argument is the name of a case style, which is parsed in the same way as
+/// `stringcase::Case`, like `snake`, `kebab_case`, `SCREAMING_SNAKE`, or `"Title Case"`.
+/// If the second argument is not in the case style, this macro reports a compile error which shows
+/// the converted name.
+///
+/// ```rust
+/// use stringcase_macros::const_assert_case;
+///
+/// const_assert_case!(snake, "user_account");
+/// const_assert_case!(pascal, UserAccount);
+/// ```
+///
+/// ```compile_fail
+/// use stringcase_macros::const_assert_case;
+///
+/// const_assert_case!(snake, "userAccount");
+/// ```
+#[proc_macro]
+pub fn const_assert_case(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as convert::CaseAndName);
+    convert::expand_assert(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Concatenates identifiers and literals, and converts the result into an identifier in a case
+/// style, with the same rules as the functions of stringcase.
+///
+/// The first argument is the name of a case style, which is parsed in the same way as
+/// `stringcase::Case`, like `snake`, `pascal`, or `SCREAMING_SNAKE`.
+/// The rest arguments are identifiers, string literals, integer literals, or the fragments of
+/// `macro_rules!` like `$name`, which are concatenated without separators and then converted.
+/// The resulting identifier is escaped as a raw identifier if it is a keyword, and has the span of
+/// the first identifier in the arguments, so that it is resolved like that identifier.
+///
+/// Since Rust does not allow macro calls in the place of the name of an item, use this macro
+/// inside [`with_idents!`] to define items.
+///
+/// ```rust
+/// use stringcase_macros::ident;
+///
+/// fn get_user_id() -> u64 {
+///     3
+/// }
+///
+/// macro_rules! get {
+///     ($name:ident) => {
+///         ident!(snake, get_, $name)()
+///     };
+/// }
+///
+/// fn main() {
+///     assert_eq!(get!(UserId), 3);
+///
+///     let r#type = 1;
+///     assert_eq!(ident!(snake, "Type"), 1);
+/// }
+/// ```
+#[proc_macro]
+pub fn ident(input: TokenStream) -> TokenStream {
+    ident::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Replaces every `ident!(...)` in the input with the identifier which [`ident!`] creates, so that
+/// the identifiers can be used as the names of items.
+///
+/// ```rust
+/// use stringcase_macros::with_idents;
+///
+/// macro_rules! accessors {
+///     ($name:ident: $ty:ty) => {
+///         with_idents! {
+///             struct ident!(pascal, $name, Builder) {
+///                 $name: $ty,
+///             }
+///
+///             impl ident!(pascal, $name, Builder) {
+///                 fn ident!(snake, get_, $name)(&self) -> $ty {
+///                     self.$name
+///                 }
+///             }
+///         }
+///     };
+/// }
+///
+/// accessors!(user_id: u64);
+///
+/// fn main() {
+///     let b = UserIdBuilder { user_id: 3 };
+///     assert_eq!(b.get_user_id(), 3);
+/// }
+/// ```
+#[proc_macro]
+pub fn with_idents(input: TokenStream) -> TokenStream {
+    ident::expand_all(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,56 @@
+use stringcase_macros::{
+    ada_case, camel_case, cobol_case, const_assert_case, kebab_case, macro_case, pascal_case,
+    snake_case, title_case, train_case,
+};
+
+const TABLE: &str = snake_case!("UserAccount");
+
+const_assert_case!(snake, "user_account");
+const_assert_case!(SCREAMING_SNAKE, MAX_RETRY_COUNT);
+const_assert_case!("Title Case", "User Account");
+
+macro_rules! table_name {
+    ($ty:ident) => {
+        snake_case!($ty)
+    };
+}
+
+#[allow(dead_code)]
+struct UserAccount;
+
+#[test]
+fn it_should_convert_at_compile_time() {
+    assert_eq!(TABLE, "user_account");
+    assert_eq!(table_name!(UserAccount), "user_account");
+
+    assert_eq!(ada_case!("fooBar100%BAZQux"), "Foo_Bar100_Baz_Qux");
+    assert_eq!(camel_case!("fooBar100%BAZQux"), "fooBar100BazQux");
+    assert_eq!(cobol_case!("fooBar100%BAZQux"), "FOO-BAR100-BAZ-QUX");
+    assert_eq!(kebab_case!("fooBar100%BAZQux"), "foo-bar100-baz-qux");
+    assert_eq!(macro_case!("fooBar100%BAZQux"), "FOO_BAR100_BAZ_QUX");
+    assert_eq!(pascal_case!("fooBar100%BAZQux"), "FooBar100BazQux");
+    assert_eq!(snake_case!("fooBar100%BAZQux"), "foo_bar100_baz_qux");
+    assert_eq!(title_case!("fooBar100%BAZQux"), "Foo Bar100 Baz Qux");
+    assert_eq!(train_case!("fooBar100%BAZQux"), "Foo-Bar100-Baz-Qux");
+}
+
+#[test]
+fn it_should_convert_in_const_contexts() {
+    const NAMES: [&str; 2] = [kebab_case!(UserAccount), macro_case!(r#type)];
+    assert_eq!(NAMES, ["user-account", "TYPE"]);
+
+    const_assert_case!(kebab, "user-account");
+}
+
+#[test]
+fn it_should_be_same_as_runtime_conversion() {
+    assert_eq!(
+        snake_case!("HTTPServer2Go"),
+        stringcase::snake_case("HTTPServer2Go")
+    );
+    assert_eq!(
+        camel_case!("éclair_été"),
+        stringcase::camel_case("éclair_été")
+    );
+    assert_eq!(camel_case!("éclair_été"), "clairT");
+}
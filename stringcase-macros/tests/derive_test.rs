@@ -0,0 +1,137 @@
+use std::marker::PhantomData;
+use stringcase::{Case, ParseVariantError};
+use stringcase_macros::StringCase;
+
+#[derive(StringCase, Debug, PartialEq, Clone, Copy)]
+enum Status {
+    InProgress,
+    Done,
+    HTTPError,
+}
+
+#[derive(StringCase, Debug, PartialEq)]
+enum Event {
+    Started,
+    RetryAfter(u32),
+    FailedWith { error_code: u16 },
+}
+
+#[derive(StringCase)]
+#[allow(dead_code)]
+struct Config<T> {
+    max_retry_count: u32,
+    r#type: PhantomData<T>,
+}
+
+#[derive(StringCase)]
+#[allow(dead_code)]
+enum Empty {}
+
+#[test]
+fn it_should_generate_names_of_variants() {
+    assert_eq!(
+        Status::ADA_CASE_NAMES,
+        ["In_Progress", "Done", "Http_Error"]
+    );
+    assert_eq!(
+        Status::CAMEL_CASE_NAMES,
+        ["inProgress", "done", "httpError"]
+    );
+    assert_eq!(
+        Status::COBOL_CASE_NAMES,
+        ["IN-PROGRESS", "DONE", "HTTP-ERROR"]
+    );
+    assert_eq!(
+        Status::KEBAB_CASE_NAMES,
+        ["in-progress", "done", "http-error"]
+    );
+    assert_eq!(
+        Status::MACRO_CASE_NAMES,
+        ["IN_PROGRESS", "DONE", "HTTP_ERROR"]
+    );
+    assert_eq!(
+        Status::PASCAL_CASE_NAMES,
+        ["InProgress", "Done", "HttpError"]
+    );
+    assert_eq!(
+        Status::SNAKE_CASE_NAMES,
+        ["in_progress", "done", "http_error"]
+    );
+    assert_eq!(
+        Status::TITLE_CASE_NAMES,
+        ["In Progress", "Done", "Http Error"]
+    );
+    assert_eq!(
+        Status::TRAIN_CASE_NAMES,
+        ["In-Progress", "Done", "Http-Error"]
+    );
+
+    for &case in Case::ALL {
+        let names: Vec<String> = ["InProgress", "Done", "HTTPError"]
+            .iter()
+            .map(|n| stringcase::convert(n, case, &Default::default()))
+            .collect();
+        assert_eq!(Status::case_names(case), names);
+    }
+}
+
+#[test]
+fn it_should_return_name_of_variant() {
+    assert_eq!(Status::InProgress.as_ada_case(), "In_Progress");
+    assert_eq!(Status::InProgress.as_camel_case(), "inProgress");
+    assert_eq!(Status::InProgress.as_cobol_case(), "IN-PROGRESS");
+    assert_eq!(Status::InProgress.as_kebab_case(), "in-progress");
+    assert_eq!(Status::InProgress.as_macro_case(), "IN_PROGRESS");
+    assert_eq!(Status::InProgress.as_pascal_case(), "InProgress");
+    assert_eq!(Status::InProgress.as_snake_case(), "in_progress");
+    assert_eq!(Status::InProgress.as_title_case(), "In Progress");
+    assert_eq!(Status::InProgress.as_train_case(), "In-Progress");
+    assert_eq!(Status::HTTPError.as_case(Case::Snake), "http_error");
+
+    const DONE: &str = Status::Done.as_macro_case();
+    assert_eq!(DONE, "DONE");
+}
+
+#[test]
+fn it_should_return_name_of_variant_with_fields() {
+    assert_eq!(Event::RetryAfter(3).as_kebab_case(), "retry-after");
+    assert_eq!(
+        Event::FailedWith { error_code: 1 }.as_case(Case::Macro),
+        "FAILED_WITH"
+    );
+    assert_eq!(Event::Started.as_title_case(), "Started");
+}
+
+#[test]
+fn it_should_parse_names_in_any_case() {
+    for status in [Status::InProgress, Status::Done, Status::HTTPError] {
+        for &case in Case::ALL {
+            assert_eq!(status.as_case(case).parse(), Ok(status));
+        }
+    }
+    assert_eq!("HTTPError".parse(), Ok(Status::HTTPError));
+
+    let err = "in progress".parse::<Status>().unwrap_err();
+    assert_eq!(err, ParseVariantError::new("Status", "in progress"));
+    assert_eq!(
+        err.to_string(),
+        "unknown variant of Status: \"in progress\""
+    );
+}
+
+#[test]
+fn it_should_generate_names_of_fields() {
+    assert_eq!(Config::<u8>::SNAKE_CASE_NAMES, ["max_retry_count", "type"]);
+    assert_eq!(Config::<u8>::PASCAL_CASE_NAMES, ["MaxRetryCount", "Type"]);
+    assert_eq!(
+        Config::<u8>::case_names(Case::Train),
+        ["Max-Retry-Count", "Type"]
+    );
+}
+
+#[test]
+fn it_should_generate_names_of_empty_enum() {
+    assert_eq!(Empty::SNAKE_CASE_NAMES.len(), 0);
+    assert!(Empty::case_names(Case::Snake).is_empty());
+    assert!("".parse::<Empty>().is_err());
+}
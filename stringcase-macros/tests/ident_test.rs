@@ -0,0 +1,58 @@
+use stringcase_macros::{ident, with_idents};
+
+macro_rules! entity {
+    ($name:ident { $($field:ident: $ty:ty),* }) => {
+        with_idents! {
+            #[derive(Default)]
+            struct ident!(pascal, $name) {
+                $($field: $ty,)*
+            }
+
+            impl ident!(pascal, $name) {
+                $(
+                    fn ident!(snake, get_, $field)(&self) -> $ty {
+                        self.$field
+                    }
+                )*
+            }
+
+            const ident!(macro, $name, _FIELD_COUNT): usize = [$(stringify!($field)),*].len();
+        }
+    };
+}
+
+entity!(user_account {
+    user_id: u64,
+    is_active: bool
+});
+
+#[test]
+fn it_should_define_items_with_converted_names() {
+    let account = UserAccount {
+        user_id: 3,
+        is_active: true,
+    };
+    assert_eq!(account.get_user_id(), 3);
+    assert!(account.get_is_active());
+    assert_eq!(USER_ACCOUNT_FIELD_COUNT, 2);
+}
+
+#[test]
+fn it_should_escape_keywords() {
+    let r#type = "t";
+    let r#match = "m";
+    assert_eq!(ident!(snake, Type), "t");
+    assert_eq!(ident!(camel, "MATCH"), "m");
+}
+
+#[test]
+fn it_should_use_identifiers_in_expressions_and_types() {
+    macro_rules! default_of {
+        ($name:ident) => {
+            <ident!(pascal, $name)>::default()
+        };
+    }
+
+    let account = default_of!(user_account);
+    assert_eq!(account.get_user_id(), 0);
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{ada_case, ada_case_with_options, Options};
 
 #[test]
@@ -0,0 +1,56 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{all_cases, convert, Case, Options};
+
+#[test]
+fn it_should_convert_to_all_cases() {
+    let opts = Options::default();
+    let cases = all_cases("foo_bar100%BAZQux", &opts);
+    assert_eq!(cases.ada_case(), "Foo_Bar100_Baz_Qux");
+    assert_eq!(cases.camel_case(), "fooBar100BazQux");
+    assert_eq!(cases.cobol_case(), "FOO-BAR100-BAZ-QUX");
+    assert_eq!(cases.kebab_case(), "foo-bar100-baz-qux");
+    assert_eq!(cases.macro_case(), "FOO_BAR100_BAZ_QUX");
+    assert_eq!(cases.pascal_case(), "FooBar100BazQux");
+    assert_eq!(cases.snake_case(), "foo_bar100_baz_qux");
+    assert_eq!(cases.title_case(), "Foo Bar100 Baz Qux");
+    assert_eq!(cases.train_case(), "Foo-Bar100-Baz-Qux");
+}
+
+#[test]
+fn it_should_convert_to_all_cases_with_options() {
+    let opts = Options::new(true, true, "", "%");
+    let input = "foo_bar100%BAZQux";
+    let cases = all_cases(input, &opts);
+    assert_eq!(cases.snake_case(), "foo_bar_100%_baz_qux");
+    for (case, s) in cases.iter() {
+        assert_eq!(s, convert(input, case, &opts));
+    }
+}
+
+#[test]
+fn it_should_iterate_over_all_cases() {
+    let cases = all_cases("fooBar", &Options::default());
+    let names: Vec<String> = cases
+        .into_iter()
+        .map(|(case, s)| format!("{}: {}", case, s))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "Ada_Case: Foo_Bar",
+            "camelCase: fooBar",
+            "COBOL-CASE: FOO-BAR",
+            "kebab-case: foo-bar",
+            "MACRO_CASE: FOO_BAR",
+            "PascalCase: FooBar",
+            "snake_case: foo_bar",
+            "Title Case: Foo Bar",
+            "Train-Case: Foo-Bar",
+        ]
+    );
+    assert_eq!(
+        all_cases("fooBar", &Options::default()).get(Case::Camel),
+        "fooBar"
+    );
+}
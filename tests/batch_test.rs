@@ -0,0 +1,40 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{convert_batch, Case, Options, Suffix};
+
+#[test]
+fn it_should_detect_collisions_of_converted_names() {
+    let opts = Options::default();
+    let columns = ["userID", "user_id", "createdAt", "a-b", "a_b", "name"];
+    let batch = convert_batch(&columns, Case::Snake, &opts);
+    assert_eq!(
+        batch.outputs(),
+        ["user_id", "user_id", "created_at", "a_b", "a_b", "name"]
+    );
+    let messages: Vec<String> = batch.collisions().iter().map(|c| c.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "\"a_b\" is converted from \"a-b\" and \"a_b\"",
+            "\"user_id\" is converted from \"userID\" and \"user_id\"",
+        ]
+    );
+}
+
+#[test]
+fn it_should_resolve_collisions_with_suffixes() {
+    let opts = Options::default();
+    let columns = vec!["userID", "user_id", "createdAt", "a-b", "a_b", "name"];
+    let batch = convert_batch(&columns, Case::Pascal, &opts);
+    assert_eq!(
+        batch.resolve(Suffix::Number),
+        ["UserId", "UserId2", "CreatedAt", "AB", "AB2", "Name"]
+    );
+
+    let resolved = batch.resolve(Suffix::Hash);
+    assert_eq!(resolved.len(), 6);
+    assert_eq!(resolved[0], "UserId");
+    assert!(resolved[1].starts_with("UserId"));
+    assert_eq!(resolved[1].len(), "UserId".len() + 6);
+    assert_eq!(resolved, batch.resolve(Suffix::Hash));
+}
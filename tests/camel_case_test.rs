@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{camel_case, camel_case_with_options, Options};
 
 #[allow(deprecated)]
@@ -75,3 +77,29 @@ fn it_should_convert_to_camel_case_with_keep() {
     let converted = camel_case_with_keep("foo_bar100%BAZQux", "%");
     assert_eq!(converted, "fooBar100%BazQux");
 }
+
+#[cfg(test)]
+mod tests_of_camel_case_with_acronyms {
+    use super::*;
+
+    #[test]
+    fn capitalize_acronyms() {
+        let opts = Options {
+            acronyms: &["HTTP", "ID", "XML"],
+            ..Default::default()
+        };
+        let converted = camel_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "xmlHttpRequestId");
+    }
+
+    #[test]
+    fn uppercase_acronyms() {
+        let opts = Options {
+            acronyms: &["HTTP", "ID", "XML"],
+            uppercase_acronyms: true,
+            ..Default::default()
+        };
+        let converted = camel_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "xmlHTTPRequestID");
+    }
+}
@@ -0,0 +1,39 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{eq_ignore_case_style, CaseKey, Options};
+
+#[test]
+fn it_should_compare_strings_ignoring_case_styles() {
+    let opts = Options::default();
+    assert!(eq_ignore_case_style("userId", "user_id", &opts));
+    assert!(eq_ignore_case_style("userId", "USER-ID", &opts));
+    assert!(!eq_ignore_case_style("userId", "userIds", &opts));
+}
+
+#[test]
+fn it_should_use_case_keys_in_a_btree_map() {
+    use std::collections::BTreeMap;
+
+    let mut map = BTreeMap::new();
+    map.insert(CaseKey::new("userName"), "alice");
+    map.insert(CaseKey::new("USER_ID"), "42");
+    assert_eq!(map.get(&CaseKey::new("user-id")), Some(&"42"));
+    assert_eq!(map.get(&CaseKey::new("User Name")), Some(&"alice"));
+
+    let keys: Vec<String> = map.keys().map(|k| k.to_string()).collect();
+    assert_eq!(keys, ["USER_ID", "userName"]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn it_should_use_a_case_insensitive_map() {
+    use stringcase::CaseInsensitiveMap;
+
+    let map: CaseInsensitiveMap<i32> = vec![("userId", 1), ("user_name", 2), ("USER-ID", 3)]
+        .into_iter()
+        .collect();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get("UserId"), Some(&3));
+    assert_eq!(map["userName"], 2);
+    assert_eq!(map.get_key_value("user id").unwrap().0.as_str(), "userId");
+}
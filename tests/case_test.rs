@@ -0,0 +1,157 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{convert, detect_case, detect_case_with_options, Case, Caser, Options};
+
+#[test]
+fn it_should_detect_case() {
+    assert_eq!(detect_case("foo_bar100_baz_qux"), Some(Case::Snake));
+    assert_eq!(detect_case("fooBar100BazQux"), Some(Case::Camel));
+    assert_eq!(detect_case("FOO-BAR100-BAZ-QUX"), Some(Case::Cobol));
+    assert_eq!(detect_case("Foo Bar100 Baz Qux"), Some(Case::Title));
+    assert_eq!(detect_case("foo_bar100%BAZQux"), None);
+}
+
+#[cfg(test)]
+mod tests_of_detect_case_with_options {
+    use super::*;
+
+    #[test]
+    fn separate_before_non_alphabets() {
+        let opts = Options::new(true, false, "", "%");
+        assert_eq!(
+            detect_case_with_options("foo_bar_100%baz_qux", &opts),
+            Some(Case::Snake)
+        );
+        assert_eq!(detect_case_with_options("foo_bar100%baz_qux", &opts), None);
+    }
+
+    #[test]
+    fn separate_after_non_alphabets() {
+        let opts = Options::new(false, true, "", "%");
+        assert_eq!(
+            detect_case_with_options("foo_bar100%_baz_qux", &opts),
+            Some(Case::Snake)
+        );
+        assert_eq!(detect_case_with_options("foo_bar100%baz_qux", &opts), None);
+    }
+}
+
+#[cfg(test)]
+mod tests_of_is_case_by_method_of_string {
+    use super::*;
+
+    #[test]
+    fn it_should_check_case_of_str_and_string() {
+        assert!("fooBar100BazQux".is_camel_case());
+        assert!(!"fooBar100BazQux".is_pascal_case());
+        assert!(String::from("FooBar100BazQux").is_pascal_case());
+        assert_eq!(
+            String::from("FOO_BAR100_BAZ_QUX").detect_case(),
+            Some(Case::Macro)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_of_convert {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_to_case_parsed_from_name() {
+        let opts = Options::default();
+        let names = [
+            ("Ada_Case", "Foo_Bar100_Baz_Qux"),
+            ("camel", "fooBar100BazQux"),
+            ("COBOL-CASE", "FOO-BAR100-BAZ-QUX"),
+            ("kebab", "foo-bar100-baz-qux"),
+            ("SCREAMING_SNAKE", "FOO_BAR100_BAZ_QUX"),
+            ("PascalCase", "FooBar100BazQux"),
+            ("snake_case", "foo_bar100_baz_qux"),
+            ("Title Case", "Foo Bar100 Baz Qux"),
+            ("train", "Foo-Bar100-Baz-Qux"),
+        ];
+        for (name, expected) in names.iter() {
+            let case: Case = name.parse().unwrap();
+            assert_eq!(convert("foo_bar100%BAZQux", case, &opts), *expected);
+            assert_eq!("foo_bar100%BAZQux".to_case(case), *expected);
+        }
+    }
+
+    #[test]
+    fn it_should_convert_to_all_cases() {
+        let opts = Options::new(true, true, "", "%");
+        let results: Vec<String> = Case::ALL
+            .iter()
+            .map(|case| convert("foo_bar100%BAZQux", *case, &opts))
+            .collect();
+        assert_eq!(
+            results,
+            [
+                "Foo_Bar_100%_Baz_Qux",
+                "fooBar100%BazQux",
+                "FOO-BAR-100%-BAZ-QUX",
+                "foo-bar-100%-baz-qux",
+                "FOO_BAR_100%_BAZ_QUX",
+                "FooBar100%BazQux",
+                "foo_bar_100%_baz_qux",
+                "Foo Bar 100% Baz Qux",
+                "Foo-Bar-100%-Baz-Qux",
+            ]
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests_of_writing_without_allocation {
+    use super::*;
+    use std::fmt::Write;
+    use stringcase::{convert_into, kebab_case_into, KebabCase, SnakeCase, TitleCase};
+
+    #[test]
+    fn it_should_write_into_a_string() {
+        let opts = Options::new(true, true, "", "%");
+        let mut out = String::new();
+        kebab_case_into("foo_bar100%BAZQux", &opts, &mut out).unwrap();
+        out.push(' ');
+        convert_into("foo_bar100%BAZQux", Case::Macro, &opts, &mut out).unwrap();
+        assert_eq!(out, "foo-bar-100%-baz-qux FOO_BAR_100%_BAZ_QUX");
+    }
+
+    #[test]
+    fn it_should_write_display_wrappers() {
+        let mut out = String::new();
+        write!(
+            out,
+            "{} {} {}",
+            SnakeCase("foo_bar100%BAZQux"),
+            KebabCase("foo_bar100%BAZQux"),
+            TitleCase("foo_bar100%BAZQux")
+        )
+        .unwrap();
+        assert_eq!(
+            out,
+            "foo_bar100_baz_qux foo-bar100-baz-qux Foo Bar100 Baz Qux"
+        );
+    }
+
+    #[test]
+    fn it_should_write_display_as() {
+        let mut out = String::new();
+        write!(out, "{}", "foo_bar100%BAZQux".display_as(Case::Pascal)).unwrap();
+        assert_eq!(out, "FooBar100BazQux");
+    }
+
+    #[test]
+    fn it_should_pad_and_truncate_display_wrappers() {
+        assert_eq!(format!("{:>10}", SnakeCase("fooBar")), "   foo_bar");
+        assert_eq!(format!("{:<10}|", KebabCase("fooBar")), "foo-bar   |");
+        assert_eq!(format!("{:*^11}", TitleCase("fooBar")), "**Foo Bar**");
+        assert_eq!(format!("{:.5}", SnakeCase("fooBar")), "foo_b");
+        assert_eq!(format!("{:>6.3}", SnakeCase("fooBar")), "   foo");
+        assert_eq!(format!("{:3}", SnakeCase("fooBar")), "foo_bar");
+        assert_eq!(
+            format!("{:-<12}", "fooBar".display_as(Case::Macro)),
+            "FOO_BAR-----"
+        );
+    }
+}
@@ -1,5 +1,70 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{Caser, Options};
 
+#[cfg(test)]
+mod test_of_to_ada_case {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_to_ada_case_by_method_of_string() {
+        let converted = "foo_bar100%BAZQux".to_ada_case();
+        assert_eq!(converted, "Foo_Bar100_Baz_Qux");
+    }
+
+    mod tests_of_ada_case_with_options_by_method_of_string {
+        use super::*;
+
+        #[test]
+        fn separate_before_non_alphabets() {
+            let opts = Options::new(true, false, "", "%");
+            let converted = "foo_bar100%BAZQux".to_ada_case_with_options(&opts);
+            assert_eq!(converted, "Foo_Bar_100%baz_Qux");
+        }
+
+        #[test]
+        fn separate_after_non_alphabets() {
+            let opts = Options::new(false, true, "", "%");
+            let converted = "foo_bar100%BAZQux".to_ada_case_with_options(&opts);
+            assert_eq!(converted, "Foo_Bar100%_Baz_Qux");
+        }
+
+        #[test]
+        fn separate_before_and_after_non_alphabets() {
+            let opts = Options::new(true, true, "", "%");
+            let converted = "foo_bar100%BAZQux".to_ada_case_with_options(&opts);
+            assert_eq!(converted, "Foo_Bar_100%_Baz_Qux");
+        }
+
+        #[test]
+        fn not_separate_before_and_after_non_alphabets() {
+            let opts = Options::new(false, false, "", "%");
+            let converted = "foo_bar100%BAZQux".to_ada_case_with_options(&opts);
+            assert_eq!(converted, "Foo_Bar100%baz_Qux");
+        }
+
+        #[test]
+        fn specify_separators() {
+            let opts = Options {
+                separators: "-_",
+                ..Default::default()
+            };
+            let converted = "foo_bar100%BAZQux".to_ada_case_with_options(&opts);
+            assert_eq!(converted, "Foo_Bar100%_Baz_Qux");
+        }
+
+        #[test]
+        fn specify_kept_characters() {
+            let opts = Options {
+                keep: "_$",
+                ..Default::default()
+            };
+            let converted = "foo_bar100%BAZQux".to_ada_case_with_options(&opts);
+            assert_eq!(converted, "Foo__Bar100_Baz_Qux");
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_of_to_camel_case {
     use super::*;
@@ -438,6 +503,69 @@ mod test_of_to_snake_case {
     }
 }
 
+#[cfg(test)]
+mod test_of_to_title_case {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_to_title_case_by_method_of_string() {
+        let converted = "foo_bar100%BAZQux".to_title_case();
+        assert_eq!(converted, "Foo Bar100 Baz Qux");
+    }
+
+    mod tests_of_title_case_with_options_by_method_of_string {
+        use super::*;
+
+        #[test]
+        fn separate_before_non_alphabets() {
+            let opts = Options::new(true, false, "", "%");
+            let converted = "foo_bar100%BAZQux".to_title_case_with_options(&opts);
+            assert_eq!(converted, "Foo Bar 100%baz Qux");
+        }
+
+        #[test]
+        fn separate_after_non_alphabets() {
+            let opts = Options::new(false, true, "", "%");
+            let converted = "foo_bar100%BAZQux".to_title_case_with_options(&opts);
+            assert_eq!(converted, "Foo Bar100% Baz Qux");
+        }
+
+        #[test]
+        fn separate_before_and_after_non_alphabets() {
+            let opts = Options::new(true, true, "", "%");
+            let converted = "foo_bar100%BAZQux".to_title_case_with_options(&opts);
+            assert_eq!(converted, "Foo Bar 100% Baz Qux");
+        }
+
+        #[test]
+        fn not_separate_before_and_after_non_alphabets() {
+            let opts = Options::new(false, false, "", "%");
+            let converted = "foo_bar100%BAZQux".to_title_case_with_options(&opts);
+            assert_eq!(converted, "Foo Bar100%baz Qux");
+        }
+
+        #[test]
+        fn specify_separators() {
+            let opts = Options {
+                separators: "-_",
+                ..Default::default()
+            };
+            let converted = "foo_bar100%BAZQux".to_title_case_with_options(&opts);
+            assert_eq!(converted, "Foo Bar100% Baz Qux");
+        }
+
+        #[test]
+        fn specify_kept_characters() {
+            let opts = Options {
+                keep: "_$",
+                ..Default::default()
+            };
+            let converted = "foo_bar100%BAZQux".to_title_case_with_options(&opts);
+            assert_eq!(converted, "Foo_ Bar100 Baz Qux");
+        }
+    }
+}
+
 #[cfg(test)]
 mod test_of_to_train_case {
     use super::*;
@@ -521,3 +649,123 @@ mod test_of_to_train_case {
         assert_eq!(converted, "Foo_-Bar100-Baz-Qux");
     }
 }
+
+#[cfg(test)]
+mod test_of_joined_words {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_to_capitalized_words_by_method_of_string() {
+        let opts = Options::new(true, false, "", "%");
+        let converted = "foo_bar100%BAZQux".to_capitalized::<'.'>(&opts);
+        assert_eq!(converted, "Foo.Bar.100%baz.Qux");
+    }
+
+    #[test]
+    fn it_should_convert_to_lowerized_words_by_method_of_string() {
+        let opts = Options::new(false, true, "", "%");
+        let converted = "foo_bar100%BAZQux".to_lowerized::<'.'>(&opts);
+        assert_eq!(converted, "foo.bar100%.baz.qux");
+    }
+
+    #[test]
+    fn it_should_convert_to_upperized_words_by_method_of_string() {
+        let opts = Options::new(true, true, "", "%");
+        let converted = "foo_bar100%BAZQux".to_upperized::<'.'>(&opts);
+        assert_eq!(converted, "FOO.BAR.100%.BAZ.QUX");
+    }
+}
+
+#[cfg(test)]
+mod test_of_string_like_types {
+    use super::*;
+    use std::borrow::Cow;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[test]
+    fn it_should_convert_cow_str() {
+        let borrowed: Cow<str> = Cow::Borrowed("foo_bar100%BAZQux");
+        assert_eq!(borrowed.to_camel_case(), "fooBar100BazQux");
+        assert_eq!(borrowed.to_title_case(), "Foo Bar100 Baz Qux");
+
+        let owned: Cow<str> = Cow::Owned(String::from("foo_bar100%BAZQux"));
+        assert_eq!(owned.to_ada_case(), "Foo_Bar100_Baz_Qux");
+        assert!(owned.to_snake_case().is_snake_case());
+    }
+
+    #[test]
+    fn it_should_convert_box_str() {
+        let boxed: Box<str> = Box::from("foo_bar100%BAZQux");
+        assert_eq!(boxed.to_kebab_case(), "foo-bar100-baz-qux");
+        let opts = Options::new(true, true, "", "%");
+        assert_eq!(
+            boxed.to_macro_case_with_options(&opts),
+            "FOO_BAR_100%_BAZ_QUX"
+        );
+    }
+
+    #[test]
+    fn it_should_convert_rc_str() {
+        let rc: Rc<str> = Rc::from("foo_bar100%BAZQux");
+        assert_eq!(rc.to_pascal_case(), "FooBar100BazQux");
+        let opts = Options::default();
+        assert_eq!(rc.to_lowerized::<'.'>(&opts), "foo.bar100.baz.qux");
+    }
+
+    #[test]
+    fn it_should_convert_arc_str() {
+        let arc: Arc<str> = Arc::from("foo_bar100%BAZQux");
+        assert_eq!(arc.to_train_case(), "Foo-Bar100-Baz-Qux");
+        assert_eq!(arc.to_cobol_case(), "FOO-BAR100-BAZ-QUX");
+        let words: Vec<&str> = arc.words().map(|w| w.text).collect();
+        assert_eq!(words, ["foo", "bar100", "BAZ", "Qux"]);
+    }
+}
+
+#[cfg(test)]
+mod test_of_unicode_letters {
+    use super::*;
+
+    #[test]
+    fn it_should_convert_non_ascii_letters_by_methods_of_string() {
+        let opts = Options {
+            unicode: true,
+            ..Default::default()
+        };
+        let input = "straßeÜberweisung";
+        assert_eq!(input.to_camel_case_with_options(&opts), "straßeÜberweisung");
+        assert_eq!(
+            input.to_cobol_case_with_options(&opts),
+            "STRASSE-ÜBERWEISUNG"
+        );
+        assert_eq!(
+            input.to_kebab_case_with_options(&opts),
+            "straße-überweisung"
+        );
+        assert_eq!(
+            input.to_macro_case_with_options(&opts),
+            "STRASSE_ÜBERWEISUNG"
+        );
+        assert_eq!(
+            input.to_pascal_case_with_options(&opts),
+            "StraßeÜberweisung"
+        );
+        assert_eq!(
+            input.to_snake_case_with_options(&opts),
+            "straße_überweisung"
+        );
+        assert_eq!(
+            input.to_train_case_with_options(&opts),
+            "Straße-Überweisung"
+        );
+    }
+
+    #[test]
+    fn it_should_not_convert_non_ascii_letters_by_default() {
+        let input = "ÉtatCivil";
+        assert_eq!(input.to_camel_case(), "tatCivil");
+        assert_eq!(input.to_snake_case(), "tat_civil");
+        assert_eq!(input.to_macro_case(), "TAT_CIVIL");
+    }
+}
@@ -0,0 +1,332 @@
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_stringcase"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> &str {
+    std::str::from_utf8(&output.stdout).unwrap()
+}
+
+#[test]
+fn it_should_convert_arguments() {
+    let output = run(&["convert", "kebab", "fooBarBaz", "HTTP Server"], "");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "foo-bar-baz\nhttp-server\n");
+}
+
+#[test]
+fn it_should_convert_lines_of_stdin() {
+    let output = run(&["convert", "MACRO_CASE"], "fooBar\r\nfoo-bar-baz\n\nlast");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "FOO_BAR\nFOO_BAR_BAZ\n\nLAST\n");
+}
+
+#[test]
+fn it_should_convert_with_options() {
+    let output = run(
+        &[
+            "convert",
+            "pascal",
+            "--separate-before-non-alphabets",
+            "--acronyms=HTTP,ID",
+            "--uppercase-acronyms",
+            "httpServer2Id",
+        ],
+        "",
+    );
+    assert_eq!(stdout(&output), "HTTPServer2ID\n");
+
+    let output = run(&["convert", "snake", "--keep", ".", "foo.barBaz"], "");
+    assert_eq!(stdout(&output), "foo._bar_baz\n");
+
+    let output = run(
+        &["convert", "snake", "--separators", "-", "foo-bar.baz"],
+        "",
+    );
+    assert_eq!(stdout(&output), "foo_bar._baz\n");
+}
+
+#[test]
+fn it_should_print_variants() {
+    let output = run(&["variants", "fooBar"], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Ada_Case    Foo_Bar\ncamelCase   fooBar\n"));
+    assert!(stdout(&output).ends_with("Train-Case  Foo-Bar\n"));
+
+    let output = run(&["variants", "--json"], "fooBar\n");
+    assert!(stdout(&output).starts_with("[\n  {\"input\": \"fooBar\", \"variants\": {"));
+    assert!(stdout(&output).contains("\"snake_case\": \"foo_bar\""));
+    assert!(stdout(&output).ends_with("}}\n]\n"));
+}
+
+#[test]
+fn it_should_detect_cases() {
+    let output = run(&["detect"], "foo_bar\nFOO-BAR\nFoo Bar\n");
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "snake_case\nCOBOL-CASE\nTitle Case\n");
+
+    let output = run(&["detect", "--json", "fooBar", "foo_Bar"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        stdout(&output),
+        "[\n  {\"input\": \"fooBar\", \"case\": \"camelCase\"},\n  \
+{\"input\": \"foo_Bar\", \"case\": null}\n]\n"
+    );
+}
+
+#[test]
+fn it_should_print_help_and_version() {
+    let output = run(&["--help"], "");
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("stringcase convert <CASE>"));
+
+    let output = run(&["--version"], "");
+    assert_eq!(
+        stdout(&output),
+        format!("stringcase {}\n", env!("CARGO_PKG_VERSION"))
+    );
+}
+
+#[test]
+fn fail_with_invalid_arguments() {
+    let output = run(&["convert", "upper"], "");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        std::str::from_utf8(&output.stderr).unwrap(),
+        "stringcase: unknown case style: \"upper\"\n\
+Try `stringcase --help` for more information.\n"
+    );
+}
+
+mod rename {
+    use super::*;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("stringcase-cli-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn touch(path: &Path) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, b"").unwrap();
+    }
+
+    fn names(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn it_should_print_the_plan_in_dry_run() {
+        let dir = temp_dir("dry-run");
+        touch(&dir.join("My Images/Blue Sky.PNG"));
+
+        let output = run(&["rename", "kebab", "--dry-run", dir.to_str().unwrap()], "");
+        assert!(output.status.success());
+        assert_eq!(
+            stdout(&output),
+            format!(
+                "{} -> {}\n{} -> {}\n",
+                dir.join("My Images/Blue Sky.PNG").display(),
+                dir.join("My Images/blue-sky.PNG").display(),
+                dir.join("My Images").display(),
+                dir.join("my-images").display(),
+            )
+        );
+        assert_eq!(names(&dir), ["My Images"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn it_should_rename_files_and_write_undo_script() {
+        let dir = temp_dir("rename");
+        touch(&dir.join("My Images/Blue Sky.PNG"));
+        touch(&dir.join("Read Me.md"));
+        let undo = dir.join("undo.sh");
+
+        let output = run(
+            &[
+                "rename",
+                "snake",
+                "--files",
+                "--undo",
+                undo.to_str().unwrap(),
+                dir.to_str().unwrap(),
+            ],
+            "",
+        );
+        assert!(output.status.success());
+        assert_eq!(names(&dir), ["My Images", "read_me.md", "undo.sh"]);
+        assert_eq!(names(&dir.join("My Images")), ["blue_sky.PNG"]);
+
+        #[cfg(unix)]
+        {
+            let status = Command::new("sh").arg(&undo).status().unwrap();
+            assert!(status.success());
+            assert_eq!(names(&dir), ["My Images", "Read Me.md", "undo.sh"]);
+            assert_eq!(names(&dir.join("My Images")), ["Blue Sky.PNG"]);
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fail_to_rename_if_undo_script_cannot_be_created() {
+        let dir = temp_dir("undo");
+        touch(&dir.join("Read Me.md"));
+        let undo = dir.join("no-such-dir/undo.sh");
+
+        let output = run(
+            &[
+                "rename",
+                "snake",
+                "--files",
+                "--undo",
+                undo.to_str().unwrap(),
+                dir.to_str().unwrap(),
+            ],
+            "",
+        );
+        assert!(!output.status.success());
+        assert_eq!(names(&dir), ["Read Me.md"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fail_to_rename_with_collisions() {
+        let dir = temp_dir("collisions");
+        touch(&dir.join("Foo Bar.txt"));
+        touch(&dir.join("foo-bar.txt"));
+        touch(&dir.join("Baz Qux.txt"));
+
+        let output = run(&["rename", "kebab", dir.to_str().unwrap()], "");
+        assert_eq!(output.status.code(), Some(1));
+        assert!(output.stdout.is_empty());
+        assert_eq!(
+            std::str::from_utf8(&output.stderr).unwrap(),
+            format!(
+                "stringcase: {:?} would be the new name of {:?}, but {:?} exists\n\
+stringcase: nothing is renamed because of the collisions\n",
+                dir.join("foo-bar.txt"),
+                dir.join("Foo Bar.txt"),
+                dir.join("foo-bar.txt"),
+            )
+        );
+        assert_eq!(names(&dir), ["Baz Qux.txt", "Foo Bar.txt", "foo-bar.txt"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+mod codemod {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, contents: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("stringcase-cli-{}-codemod", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join(name);
+        fs::write(&file, contents).unwrap();
+        file
+    }
+
+    #[test]
+    fn it_should_print_a_unified_diff() {
+        let file = temp_file("user.js", "const userName = 'userName';\n");
+        let path = file.to_str().unwrap();
+
+        let output = run(&["codemod", "snake", "--pattern", "user*", path], "");
+        assert!(output.status.success());
+        assert_eq!(
+            stdout(&output),
+            format!(
+                "--- a/{0}\n+++ b/{0}\n@@ -1 +1 @@\n\
+-const userName = 'userName';\n+const user_name = 'userName';\n",
+                path
+            )
+        );
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "const userName = 'userName';\n"
+        );
+
+        fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn it_should_write_changes_into_files() {
+        let file = temp_file("user.txt", "user_name = 'user_name'\n");
+        let path = file.to_str().unwrap();
+
+        let output = run(
+            &[
+                "codemod",
+                "camel",
+                "--lang",
+                "python",
+                "--from",
+                "snake",
+                "--strings",
+                "--write",
+                path,
+            ],
+            "",
+        );
+        assert!(output.status.success());
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "userName = 'userName'\n"
+        );
+
+        fs::remove_file(&file).unwrap();
+    }
+
+    #[test]
+    fn fail_to_detect_the_language() {
+        let file = temp_file("user.md", "userName\n");
+        let path = file.to_str().unwrap();
+
+        let output = run(&["codemod", "snake", path], "");
+        assert_eq!(output.status.code(), Some(1));
+        assert_eq!(
+            std::str::from_utf8(&output.stderr).unwrap(),
+            format!(
+                "stringcase: cannot detect the language of {:?}, so specify it with --lang\n",
+                path
+            )
+        );
+
+        fs::remove_file(&file).unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{cobol_case, cobol_case_with_options, Options};
 
 #[allow(deprecated)]
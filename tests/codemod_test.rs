@@ -0,0 +1,113 @@
+#![cfg(feature = "codemod")]
+
+use stringcase::codemod::{Codemod, Language};
+use stringcase::{Case, Options};
+
+#[test]
+fn it_should_rename_rust_identifiers() {
+    let source = r#"struct User {
+    userName: String,
+    r#type: u8,
+}
+
+// Returns userName.
+fn getUserName(user: &User) -> &str {
+    println!("userName = {}", user.userName);
+    &user.userName
+}
+"#;
+    let rewrite = Codemod::new(Language::Rust, Case::Snake)
+        .from_case(Case::Camel)
+        .rewrite(source);
+    assert_eq!(
+        rewrite.output(),
+        r#"struct User {
+    user_name: String,
+    r#type: u8,
+}
+
+// Returns userName.
+fn get_user_name(user: &User) -> &str {
+    println!("userName = {}", user.user_name);
+    &user.user_name
+}
+"#
+    );
+    assert_eq!(rewrite.edits().len(), 4);
+
+    let rewrite = Codemod::new(Language::Rust, Case::Pascal)
+        .pattern("*e*")
+        .rewrite("let r#type = ref_count;");
+    assert_eq!(rewrite.output(), "let Type = RefCount;");
+}
+
+#[test]
+fn it_should_rename_javascript_identifiers() {
+    let source = "const { firstName } = user; // firstName\n\
+        const label = `${firstName} (${'firstName'})`;\n";
+    let rewrite = Codemod::new(Language::JavaScript, Case::Snake)
+        .pattern("firstName")
+        .rewrite(source);
+    assert_eq!(
+        rewrite.output(),
+        "const { first_name } = user; // firstName\n\
+        const label = `${first_name} (${'firstName'})`;\n"
+    );
+
+    let rewrite = Codemod::new(Language::TypeScript, Case::Snake)
+        .pattern("firstName")
+        .strings(true)
+        .comments(true)
+        .rewrite(source);
+    assert_eq!(
+        rewrite.output(),
+        "const { first_name } = user; // first_name\n\
+        const label = `${first_name} (${'first_name'})`;\n"
+    );
+}
+
+#[test]
+fn it_should_rename_python_identifiers() {
+    let source = "def getName(self, userId):\n    \"\"\"Returns the name of userId.\"\"\"\n    \
+        return f\"{self.prefix}{userId}\"\n";
+    let rewrite = Codemod::new(Language::Python, Case::Snake).rewrite(source);
+    assert_eq!(
+        rewrite.output(),
+        "def get_name(self, user_id):\n    \"\"\"Returns the name of userId.\"\"\"\n    \
+        return f\"{self.prefix}{user_id}\"\n"
+    );
+}
+
+#[test]
+fn it_should_rename_go_identifiers() {
+    let source = "func parse_header(raw_line string) error {\n\treturn fmt.Errorf(`raw_line: %s`, raw_line)\n}\n";
+    let rewrite = Codemod::new(Language::Go, Case::Camel)
+        .from_case(Case::Snake)
+        .rewrite(source);
+    assert_eq!(
+        rewrite.output(),
+        "func parseHeader(rawLine string) error {\n\treturn fmt.Errorf(`raw_line: %s`, rawLine)\n}\n"
+    );
+    assert_eq!(
+        rewrite.unified_diff("header.go"),
+        "--- a/header.go\n+++ b/header.go\n@@ -1,3 +1,3 @@\n\
+        -func parse_header(raw_line string) error {\n\
+        -\treturn fmt.Errorf(`raw_line: %s`, raw_line)\n\
+        +func parseHeader(rawLine string) error {\n\
+        +\treturn fmt.Errorf(`raw_line: %s`, rawLine)\n \
+        }\n"
+    );
+}
+
+#[test]
+fn it_should_rename_with_options() {
+    let acronyms = ["HTTP", "ID"];
+    let opts = Options {
+        acronyms: &acronyms,
+        uppercase_acronyms: true,
+        ..Default::default()
+    };
+    let rewrite = Codemod::with_options(Language::Go, Case::Pascal, &opts)
+        .rewrite("func http_handler(user_id int) {}");
+    assert_eq!(rewrite.output(), "func HTTPHandler(UserID int) {}");
+}
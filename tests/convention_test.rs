@@ -0,0 +1,197 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{convert, Case, Convention, ItemKind, Options, Rule};
+
+#[test]
+fn it_should_name_items_in_each_convention() {
+    let input = "user_account";
+    let expected = [
+        // Type, Interface, Function, Constant, Field, EnumVariant, Module, Package
+        (
+            Convention::RUST,
+            [
+                "UserAccount",
+                "UserAccount",
+                "user_account",
+                "USER_ACCOUNT",
+                "user_account",
+                "UserAccount",
+                "user_account",
+                "user_account",
+            ],
+        ),
+        (
+            Convention::GO,
+            [
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+                "useraccount",
+                "useraccount",
+            ],
+        ),
+        (
+            Convention::JAVA,
+            [
+                "UserAccount",
+                "UserAccount",
+                "userAccount",
+                "USER_ACCOUNT",
+                "userAccount",
+                "USER_ACCOUNT",
+                "useraccount",
+                "useraccount",
+            ],
+        ),
+        (
+            Convention::CSHARP,
+            [
+                "UserAccount",
+                "IUserAccount",
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+                "UserAccount",
+            ],
+        ),
+        (
+            Convention::PYTHON,
+            [
+                "UserAccount",
+                "UserAccount",
+                "user_account",
+                "USER_ACCOUNT",
+                "user_account",
+                "USER_ACCOUNT",
+                "user_account",
+                "useraccount",
+            ],
+        ),
+        (
+            Convention::TYPESCRIPT,
+            [
+                "UserAccount",
+                "UserAccount",
+                "userAccount",
+                "USER_ACCOUNT",
+                "userAccount",
+                "UserAccount",
+                "user-account",
+                "user-account",
+            ],
+        ),
+        (
+            Convention::KOTLIN,
+            [
+                "UserAccount",
+                "UserAccount",
+                "userAccount",
+                "USER_ACCOUNT",
+                "userAccount",
+                "USER_ACCOUNT",
+                "useraccount",
+                "useraccount",
+            ],
+        ),
+    ];
+    assert_eq!(expected.len(), Convention::ALL.len());
+    for (conv, names) in expected.iter() {
+        for (kind, name) in ItemKind::ALL.iter().zip(names.iter()) {
+            assert_eq!(conv.convert(input, *kind), *name, "{} {:?}", conv, kind);
+        }
+    }
+}
+
+#[test]
+fn it_should_be_same_as_convert_without_acronyms_prefixes_and_keywords() {
+    let opts = Options::new(true, false, "", "");
+    for conv in Convention::ALL.iter().filter(|c| c.acronyms().is_empty()) {
+        for kind in ItemKind::ALL.iter() {
+            let rule = conv.rule(*kind);
+            if !rule.prefix.is_empty() || rule.lowercase {
+                continue;
+            }
+            assert_eq!(
+                conv.convert_with_options("fooBar100%Baz", *kind, &opts),
+                convert("fooBar100%Baz", rule.case, &opts)
+            );
+        }
+    }
+}
+
+#[test]
+fn it_should_uppercase_go_initialisms() {
+    let go = Convention::GO;
+    assert_eq!(go.convert("userId", ItemKind::Field), "UserID");
+    assert_eq!(
+        go.convert("xml_http_request", ItemKind::Type),
+        "XMLHTTPRequest"
+    );
+    assert_eq!(go.convert("serveHTTP", ItemKind::Function), "ServeHTTP");
+    assert_eq!(go.convert("api_url", ItemKind::Constant), "APIURL");
+    assert_eq!(go.convert("idle_timeout", ItemKind::Field), "IdleTimeout");
+    assert!(go.acronyms().contains(&"URL"));
+
+    assert_eq!(
+        Convention::JAVA.convert("userId", ItemKind::Field),
+        "userId"
+    );
+}
+
+#[test]
+fn it_should_escape_keywords() {
+    assert!(Convention::RUST.is_keyword("fn"));
+    assert!(!Convention::RUST.is_keyword("func"));
+    assert!(Convention::GO.is_keyword("func"));
+
+    assert_eq!(
+        Convention::RUST.convert("match", ItemKind::Function),
+        "r#match"
+    );
+    assert_eq!(Convention::RUST.convert("crate", ItemKind::Field), "crate_");
+    assert_eq!(
+        Convention::CSHARP.convert("IClass", ItemKind::Interface),
+        "IClass"
+    );
+    assert_eq!(
+        Convention::KOTLIN.convert("when", ItemKind::Function),
+        "`when`"
+    );
+    assert_eq!(Convention::PYTHON.convert("from", ItemKind::Field), "from_");
+}
+
+#[test]
+fn it_should_replace_rules() {
+    let conv = Convention::GO
+        .with_rule(ItemKind::Function, Rule::new(Case::Camel))
+        .with_rule(
+            ItemKind::Interface,
+            Rule::new(Case::Pascal).with_prefix("I"),
+        );
+    assert_eq!(conv.convert("get_user_id", ItemKind::Function), "getUserID");
+    assert_eq!(conv.convert("reader", ItemKind::Interface), "IReader");
+    assert_eq!(conv.convert("get_user_id", ItemKind::Type), "GetUserID");
+    assert_eq!(
+        Convention::GO.convert("get_user_id", ItemKind::Function),
+        "GetUserID"
+    );
+}
+
+#[test]
+fn it_should_parse_names_of_languages() {
+    assert_eq!("Rust".parse(), Ok(Convention::RUST));
+    assert_eq!("GO".parse(), Ok(Convention::GO));
+    assert_eq!("java".parse(), Ok(Convention::JAVA));
+    assert_eq!("csharp".parse(), Ok(Convention::CSHARP));
+    assert_eq!("cs".parse(), Ok(Convention::CSHARP));
+    assert_eq!("python".parse(), Ok(Convention::PYTHON));
+    assert_eq!("ts".parse(), Ok(Convention::TYPESCRIPT));
+    assert_eq!("Kotlin".parse(), Ok(Convention::KOTLIN));
+    assert!("".parse::<Convention>().is_err());
+    assert_eq!(Convention::CSHARP.name(), "C#");
+}
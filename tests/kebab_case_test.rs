@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{kebab_case, kebab_case_with_options, Options};
 
 #[allow(deprecated)]
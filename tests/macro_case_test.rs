@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{macro_case, macro_case_with_options, Options};
 
 #[allow(deprecated)]
@@ -1,4 +1,9 @@
-use stringcase::{pascal_case, pascal_case_with_options, Options};
+#![cfg(feature = "alloc")]
+
+use stringcase::{
+    camel_case_with_options, pascal_case, pascal_case_with_options, title_case_with_options, Case,
+    Options,
+};
 
 #[allow(deprecated)]
 use stringcase::{pascal_case_with_keep, pascal_case_with_sep};
@@ -75,3 +80,51 @@ fn it_should_convert_to_pascal_case_with_keep() {
     let converted = pascal_case_with_keep("foo_bar100%BAZQux", "%");
     assert_eq!(converted, "FooBar100%BazQux");
 }
+
+#[cfg(test)]
+mod tests_of_pascal_case_with_acronyms {
+    use super::*;
+
+    #[test]
+    fn capitalize_acronyms() {
+        let opts = Options {
+            acronyms: &["HTTP", "ID", "XML"],
+            ..Default::default()
+        };
+        let converted = pascal_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "XmlHttpRequestId");
+    }
+
+    #[test]
+    fn uppercase_acronyms() {
+        let opts = Options {
+            acronyms: &["HTTP", "ID", "XML"],
+            uppercase_acronyms: true,
+            ..Default::default()
+        };
+        let converted = pascal_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "XMLHTTPRequestID");
+    }
+
+    #[test]
+    fn uppercase_acronyms_only_in_specified_case_styles() {
+        let opts = Options::default()
+            .with_acronyms(&["HTTP", "ID", "XML"])
+            .with_uppercase_acronyms(true)
+            .with_uppercase_acronyms_in(&[Case::Pascal]);
+        let converted = pascal_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "XMLHTTPRequestID");
+        let converted = title_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "Xml Http Request Id");
+        let converted = camel_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "xmlHttpRequestId");
+
+        let opts = opts.with_uppercase_acronyms_in(&[Case::Camel, Case::Title]);
+        let converted = pascal_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "XmlHttpRequestId");
+        let converted = title_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "XML HTTP Request ID");
+        let converted = camel_case_with_options("XMLHTTPRequest_id", &opts);
+        assert_eq!(converted, "xmlHTTPRequestID");
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{replace_all_variants, Case, Options};
+
+#[test]
+fn it_should_replace_all_case_variants() {
+    let opts = Options::default();
+    let text = "\
+struct UserAccount;
+const MAX_USER_ACCOUNTS: usize = 10;
+fn find_user_account(userAccountId: u32) {} // see user-account.md
+/// Creates a User Account.";
+    let (result, replacements) =
+        replace_all_variants(text, "user account", "customer profile", &opts);
+    assert_eq!(
+        result,
+        "\
+struct CustomerProfile;
+const MAX_USER_ACCOUNTS: usize = 10;
+fn find_customer_profile(customerProfileId: u32) {} // see customer-profile.md
+/// Creates a Customer Profile."
+    );
+    let cases: Vec<Case> = replacements.iter().map(|r| r.case).collect();
+    assert_eq!(
+        cases,
+        [
+            Case::Pascal,
+            Case::Snake,
+            Case::Camel,
+            Case::Kebab,
+            Case::Title
+        ]
+    );
+    for r in replacements.iter() {
+        assert_eq!(&text[r.range.clone()], r.from);
+    }
+}
+
+#[test]
+fn it_should_replace_with_options() {
+    let opts = Options {
+        separate_before_non_alphabets: true,
+        ..Default::default()
+    };
+    let (result, _) = replace_all_variants("v_2_api apiV2 API_V_2", "api", "rpc", &opts);
+    assert_eq!(result, "v_2_rpc rpcV2 RPC_V_2");
+}
+
+#[test]
+fn it_should_replace_a_single_word_in_the_case_of_the_identifier() {
+    let (result, _) = replace_all_variants(
+        "userName = 1; USER_NAME",
+        "user",
+        "customer profile",
+        &Options::default(),
+    );
+    assert_eq!(result, "customerProfileName = 1; CUSTOMER_PROFILE_NAME");
+}
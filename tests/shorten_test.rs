@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{convert, shorten, shorten_with_options, Case, Options};
+
+#[test]
+fn it_should_shorten_to_max_len() {
+    let input = "tenantSubscriptionInvoiceLineItemAdjustmentReason";
+    for max_len in 0..=60 {
+        for case in Case::ALL.iter() {
+            let name = shorten(input, *case, max_len);
+            match name {
+                Some(ref name) => assert!(name.len() <= max_len, "{} {} {:?}", max_len, case, name),
+                None => assert!(max_len < 8, "{} {}", max_len, case),
+            }
+            assert_eq!(name, shorten(input, *case, max_len));
+        }
+    }
+    assert_eq!(
+        shorten(input, Case::Snake, 63),
+        Some(convert(input, Case::Snake, &Options::default()))
+    );
+    assert_eq!(
+        shorten(input, Case::Snake, 30).as_deref(),
+        Some("tnn_sb_in_ln_it_adj_rsn_431aa1")
+    );
+}
+
+#[test]
+fn it_should_avoid_collisions_of_truncated_names() {
+    let a = shorten(
+        "tenant_subscription_invoice_line_item_adjustment_reason",
+        Case::Snake,
+        30,
+    );
+    let b = shorten(
+        "tenant_subscription_invoice_line_item_adjustment_reasons",
+        Case::Snake,
+        30,
+    );
+    assert_ne!(a, b);
+}
+
+#[test]
+fn it_should_shorten_with_options() {
+    let opts = Options::new(true, true, "", "");
+    assert_eq!(
+        shorten_with_options("address_line2_postal_code", Case::Snake, 24, &opts).as_deref(),
+        Some("addr_ln_2_pstl_cd_8e00a0")
+    );
+}
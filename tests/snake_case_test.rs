@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{snake_case, snake_case_with_options, Options};
 
 #[allow(deprecated)]
@@ -0,0 +1,50 @@
+#![cfg(feature = "syn")]
+
+use proc_macro2::{Ident, Span};
+use stringcase::syn::{convert_ident, ident_name, IdentCaser};
+use stringcase::{Case, Options};
+
+#[test]
+fn it_should_convert_idents() {
+    let ident = Ident::new("HTTPServerId", Span::call_site());
+    assert_eq!(ident.to_ada_case_ident().unwrap(), "Http_Server_Id");
+    assert_eq!(ident.to_camel_case_ident().unwrap(), "httpServerId");
+    assert_eq!(ident.to_macro_case_ident().unwrap(), "HTTP_SERVER_ID");
+    assert_eq!(ident.to_pascal_case_ident().unwrap(), "HttpServerId");
+    assert_eq!(ident.to_snake_case_ident().unwrap(), "http_server_id");
+}
+
+#[test]
+fn it_should_convert_raw_idents_and_keywords() {
+    let ident = Ident::new_raw("match", Span::call_site());
+    assert_eq!(ident_name(&ident), "match");
+    assert_eq!(ident.to_macro_case_ident().unwrap(), "MATCH");
+    assert_eq!(ident.to_snake_case_ident().unwrap().to_string(), "r#match");
+
+    let ident = Ident::new("Async", Span::call_site());
+    assert_eq!(ident.to_camel_case_ident().unwrap().to_string(), "r#async");
+
+    let ident = Ident::new("Self_", Span::call_site());
+    let err = ident.to_snake_case_ident().unwrap_err();
+    assert_eq!(err.to_string(), "`self` cannot be a raw identifier");
+}
+
+#[test]
+fn it_should_convert_idents_with_options() {
+    let opts = Options {
+        separate_before_non_alphabets: true,
+        ..Default::default()
+    };
+    let ident = Ident::new("apiV2", Span::call_site());
+    assert_eq!(
+        convert_ident(&ident, Case::Snake, &opts).unwrap(),
+        "api_v_2"
+    );
+    assert_eq!(
+        ident
+            .to_case_ident_with_options(Case::Macro, &opts)
+            .unwrap(),
+        "API_V_2"
+    );
+    assert!(ident.to_case_ident(Case::Title).is_err());
+}
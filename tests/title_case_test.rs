@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{title_case, title_case_with_options, Options};
 
 #[test]
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use stringcase::{train_case, train_case_with_options, Options};
 
 #[allow(deprecated)]
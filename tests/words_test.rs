@@ -0,0 +1,76 @@
+#![cfg(feature = "alloc")]
+
+use stringcase::{snake_case_with_options, words, Caser, Options};
+
+#[test]
+fn it_should_split_into_words() {
+    let opts = Options::default();
+    let result: Vec<&str> = words("fooBar100%BAZQux", &opts).map(|w| w.text).collect();
+    assert_eq!(result, ["foo", "Bar100", "BAZ", "Qux"]);
+}
+
+#[test]
+fn it_should_get_byte_ranges_of_words() {
+    let opts = Options::default();
+    let input = "fooBar100%BAZQux";
+    let result: Vec<_> = words(input, &opts).map(|w| w.range()).collect();
+    assert_eq!(result, [0..3, 3..9, 10..13, 13..16]);
+}
+
+#[cfg(test)]
+mod tests_of_words_with_options {
+    use super::*;
+
+    #[test]
+    fn separate_before_non_alphabets() {
+        let opts = Options::new(true, false, "", "%");
+        let result: Vec<&str> = words("foo_bar100%BAZQux", &opts).map(|w| w.text).collect();
+        assert_eq!(result, ["foo", "bar", "100%BAZ", "Qux"]);
+    }
+
+    #[test]
+    fn separate_after_non_alphabets() {
+        let opts = Options::new(false, true, "", "%");
+        let result: Vec<&str> = words("foo_bar100%BAZQux", &opts).map(|w| w.text).collect();
+        assert_eq!(result, ["foo", "bar100%", "BAZ", "Qux"]);
+    }
+
+    #[test]
+    fn separate_before_and_after_non_alphabets() {
+        let opts = Options::new(true, true, "", "%");
+        let result: Vec<&str> = words("foo_bar100%BAZQux", &opts).map(|w| w.text).collect();
+        assert_eq!(result, ["foo", "bar", "100%", "BAZ", "Qux"]);
+    }
+
+    #[test]
+    fn not_separate_before_and_after_non_alphabets() {
+        let opts = Options::new(false, false, "", "%");
+        let result: Vec<&str> = words("foo_bar100%BAZQux", &opts).map(|w| w.text).collect();
+        assert_eq!(result, ["foo", "bar100%BAZ", "Qux"]);
+    }
+
+    #[test]
+    fn consistent_with_conversion() {
+        let opts = Options::new(true, true, "", "%");
+        let joined: Vec<String> = words("foo_bar100%BAZQux", &opts)
+            .map(|w| w.text.to_lowercase())
+            .collect();
+        assert_eq!(
+            joined.join("_"),
+            snake_case_with_options("foo_bar100%BAZQux", &opts)
+        );
+    }
+}
+
+#[test]
+fn it_should_split_into_words_by_method_of_string() {
+    let result: Vec<&str> = "fooBar100%BAZQux".words().map(|w| w.text).collect();
+    assert_eq!(result, ["foo", "Bar100", "BAZ", "Qux"]);
+
+    let opts = Options::new(true, true, "", "");
+    let result: Vec<&str> = "fooBar100%BAZQux"
+        .words_with_options(&opts)
+        .map(|w| w.text)
+        .collect();
+    assert_eq!(result, ["foo", "Bar", "100", "BAZ", "Qux"]);
+}